@@ -157,13 +157,13 @@ if "x86":
 
 
 if "unknown":
-    vecs = ["u8x16", "i8x16", "u16x8", "i16x8", "u32x4", "i32x4", "f32x4", "u64x2", "i64x2", "f64x2"]
+    vecs = ["u8x64", "u8x32", "u8x16", "i8x64", "i8x32", "i8x16", "u16x32", "u16x16", "u16x8", "i16x32", "i16x16", "i16x8", "u32x16", "u32x8", "u32x4", "i32x16", "i32x8", "i32x4", "f32x16", "f32x8", "f32x4", "u64x8", "u64x4", "u64x2", "i64x8", "i64x4", "i64x2", "f64x8", "f64x4", "f64x2"]
     lens = [int(v.split("x")[1]) for v in vecs]
     els = [v.split("x")[0] for v in vecs]
     elsz = [int(el[1:]) for el in els]
     masks = ["u" + el[1:] for el in els]
-    feats = [{128: "__undefined"}[l * e] for l, e in zip(lens, elsz)]
-    blends = [{128: "__undefined"}[l * e] for l, e in zip(lens, elsz)]
+    feats = ["__undefined" for _ in vecs]
+    blends = ["__undefined" for _ in vecs]
 
     # Generate file
     generate_vec_patterns("unknown", [], els, vecs, lens, feats, blends, elsz, masks)
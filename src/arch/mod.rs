@@ -1,7 +1,8 @@
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub mod x86;
 
-#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+// The polyfills are built on every target, as `portability` replays kernels
+// with them.
 pub mod unknown;
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -6,8 +6,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::intrin::abs::Abs;
-use crate::arch::current::vecs::*;
+use crate::arch::unknown::vecs::*;
 use crate::std::mem::transmute;
+use crate::vecs::*;
 
 impl Abs for f32x4 {
     type Out = f32x4;
@@ -229,3 +230,4 @@ impl Abs for i64x8 {
     }
 }
 
+impl_abs_float_fallback!(f32x16, f64x8);
@@ -0,0 +1,33 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::vecs::*;
+use crate::intrin::abs_diff::*;
+
+impl_abs_diff_fallback!(int, u8x16, u8x16, u8, i8x16, u8x16, u8, u16x8, u16x8, u16, i16x8, u16x8, u16,
+                        u32x4, u32x4, u32, i32x4, u32x4, u32, u64x2, u64x2, u64, i64x2, u64x2, u64,
+                        u8x32, u8x32, u8, i8x32, u8x32, u8, u16x16, u16x16, u16, i16x16, u16x16, u16,
+                        u32x8, u32x8, u32, i32x8, u32x8, u32, u64x4, u64x4, u64, i64x4, u64x4, u64,
+                        u8x64, u8x64, u8, i8x64, u8x64, u8, u16x32, u16x32, u16, i16x32, u16x32, u16,
+                        u32x16, u32x16, u32, i32x16, u32x16, u32, u64x8, u64x8, u64, i64x8, u64x8, u64);
+impl_abs_diff_fallback!(float, f32x4, f32, 0x7FFF_FFFF, f64x2, f64, 0x7FFF_FFFF_FFFF_FFFF,
+                        f32x8, f32, 0x7FFF_FFFF, f64x4, f64, 0x7FFF_FFFF_FFFF_FFFF,
+                        f32x16, f32, 0x7FFF_FFFF, f64x8, f64, 0x7FFF_FFFF_FFFF_FFFF);
+impl_sad_fallback!(u8x16, u64x2, u8x32, u64x4, u8x64, u64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_abs_diff!(int, (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2),
+                   (u8, i8, u16, i16, u32, i32, u64, i64),
+                   (abs_diff_u8x16, abs_diff_i8x16, abs_diff_u16x8, abs_diff_i16x8, abs_diff_u32x4, abs_diff_i32x4, abs_diff_u64x2, abs_diff_i64x2));
+    test_abs_diff!(float, (f32x4, f64x2), (f32, f64), (abs_diff_f32x4, abs_diff_f64x2));
+    test_sad!((u8x16), (u64x2), (sad_u8x16));
+}
@@ -0,0 +1,36 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::intrin::bitmask::*;
+
+impl_bitmask_fallback!(u8x16, u8x16, i8x16);
+impl_bitmask_fallback!(u16x8, u16x8, i16x8);
+impl_bitmask_fallback!(u32x4, u32x4, i32x4, f32x4);
+impl_bitmask_fallback!(u64x2, u64x2, i64x2, f64x2);
+impl_bitmask_fallback!(u8x32, u8x32, i8x32);
+impl_bitmask_fallback!(u16x16, u16x16, i16x16);
+impl_bitmask_fallback!(u32x8, u32x8, i32x8, f32x8);
+impl_bitmask_fallback!(u64x4, u64x4, i64x4, f64x4);
+impl_bitmask_fallback!(u8x64, u8x64, i8x64);
+impl_bitmask_fallback!(u16x32, u16x32, i16x32);
+impl_bitmask_fallback!(u32x16, u32x16, i32x16, f32x16);
+impl_bitmask_fallback!(u64x8, u64x8, i64x8, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::intrin::bitmask::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_packed_bitmask!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2),
+                         (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                         (bitmask_u8x16, bitmask_i8x16, bitmask_u16x8, bitmask_i16x8, bitmask_u32x4, bitmask_i32x4, bitmask_f32x4, bitmask_u64x2, bitmask_i64x2, bitmask_f64x2));
+    test_packed_bitmask!((u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+                         (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                         (bitmask_u8x64, bitmask_i8x64, bitmask_u16x32, bitmask_i16x32, bitmask_u32x16, bitmask_i32x16, bitmask_f32x16, bitmask_u64x8, bitmask_i64x8, bitmask_f64x8));
+}
@@ -0,0 +1,72 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::vecs::*;
+use crate::intrin::cast::*;
+
+impl_cast_fallback!(int, Asi8s, as_i8s, saturating_as_i8s, u8x16, i8x16, i8, u8x32, i8x32, i8, u8x64, i8x64, i8);
+impl_cast_fallback!(int, Asu8s, as_u8s, saturating_as_u8s, i8x16, u8x16, u8, i8x32, u8x32, u8, i8x64, u8x64, u8);
+impl_cast_fallback!(int, Asi16s, as_i16s, saturating_as_i16s, u16x8, i16x8, i16, u16x16, i16x16, i16, u16x32, i16x32, i16);
+impl_cast_fallback!(int, Asu16s, as_u16s, saturating_as_u16s, i16x8, u16x8, u16, i16x16, u16x16, u16, i16x32, u16x32, u16);
+impl_cast_fallback!(int, Asi32s, as_i32s, saturating_as_i32s, u32x4, i32x4, i32, u32x8, i32x8, i32, u32x16, i32x16, i32);
+impl_cast_fallback!(int, Asu32s, as_u32s, saturating_as_u32s, i32x4, u32x4, u32, i32x8, u32x8, u32, i32x16, u32x16, u32);
+impl_cast_fallback!(int, Asi64s, as_i64s, saturating_as_i64s, u64x2, i64x2, i64, u64x4, i64x4, i64, u64x8, i64x8, i64);
+impl_cast_fallback!(int, Asu64s, as_u64s, saturating_as_u64s, i64x2, u64x2, u64, i64x4, u64x4, u64, i64x8, u64x8, u64);
+
+impl_cast_fallback!(trunc, Asi32s, as_i32s, saturating_as_i32s,
+                    f32x4, i32x4, i32, -2147483648.0, 2147483648.0,
+                    f32x8, i32x8, i32, -2147483648.0, 2147483648.0,
+                    f32x16, i32x16, i32, -2147483648.0, 2147483648.0);
+impl_cast_fallback!(trunc, Asu32s, as_u32s, saturating_as_u32s,
+                    f32x4, u32x4, u32, 0.0, 4294967296.0,
+                    f32x8, u32x8, u32, 0.0, 4294967296.0,
+                    f32x16, u32x16, u32, 0.0, 4294967296.0);
+impl_cast_fallback!(trunc, Asi64s, as_i64s, saturating_as_i64s,
+                    f64x2, i64x2, i64, -9223372036854775808.0, 9223372036854775808.0,
+                    f64x4, i64x4, i64, -9223372036854775808.0, 9223372036854775808.0,
+                    f64x8, i64x8, i64, -9223372036854775808.0, 9223372036854775808.0);
+impl_cast_fallback!(trunc, Asu64s, as_u64s, saturating_as_u64s,
+                    f64x2, u64x2, u64, 0.0, 18446744073709551616.0,
+                    f64x4, u64x4, u64, 0.0, 18446744073709551616.0,
+                    f64x8, u64x8, u64, 0.0, 18446744073709551616.0);
+
+impl_cast_fallback!(round, f32x4, i32x4, f32, i32, 8388608.0, 0x8000_0000,
+                    f32x8, i32x8, f32, i32, 8388608.0, 0x8000_0000,
+                    f32x16, i32x16, f32, i32, 8388608.0, 0x8000_0000,
+                    f64x2, i64x2, f64, i64, 4503599627370496.0, 0x8000_0000_0000_0000,
+                    f64x4, i64x4, f64, i64, 4503599627370496.0, 0x8000_0000_0000_0000,
+                    f64x8, i64x8, f64, i64, 4503599627370496.0, 0x8000_0000_0000_0000);
+
+impl_cast_fallback!(float, Asf32s, as_f32s, i32x4, f32x4, f32, u32x4, f32x4, f32, i32x8, f32x8, f32,
+                    u32x8, f32x8, f32, i32x16, f32x16, f32, u32x16, f32x16, f32);
+impl_cast_fallback!(float, Asf64s, as_f64s, i64x2, f64x2, f64, u64x2, f64x2, f64, i64x4, f64x4, f64,
+                    u64x4, f64x4, f64, i64x8, f64x8, f64, u64x8, f64x8, f64);
+
+impl_cross_cast_fallback!(f64_i32, f64x2, i32x4, f64x4, i32x8, f64x8, i32x16);
+impl_cross_cast_fallback!(f32_i64, f32x4, i64x2, f32x8, i64x4, f32x16, i64x8);
+impl_cross_cast_fallback!(i64_f32, i64x2, f32x4, i64x4, f32x8, i64x8, f32x16);
+impl_cross_cast_fallback!(i16_f32, i16x8, f32x4, i16x16, f32x8, i16x32, f32x16);
+impl_cross_cast_fallback!(u8_f32, u8x16, f32x4, u8x32, f32x8, u8x64, f32x16);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_cast!((u8x16), (sat_i8s_u8x16), saturating_as_i8s, [(0u8, 0i8), (127, 127), (128, 127), (255, 127)]);
+    test_cast!((i16x8), (sat_u16s_i16x8), saturating_as_u16s, [(0i16, 0u16), (-1, 0), (32767, 32767)]);
+    test_cast!((u64x2), (as_i64s_u64x2), as_i64s, [(5u64, 5i64), (u64::max_value(), -1)]);
+    test_cast!((f32x4), (as_i32s_f32x4), as_i32s,
+               [(1.5f32, 1i32), (-1.5, -1), (3e9, i32::max_value()), (-3e9, i32::min_value()), (0.0 / 0.0, 0)]);
+    test_cast!((f32x4), (as_u32s_f32x4), as_u32s,
+               [(1.5f32, 1u32), (-1.0, 0), (3e9, 3000000000), (1.0 / 0.0, u32::max_value()), (0.0 / 0.0, 0)]);
+    test_cast!((f64x2), (round_f64x2), round_as_ints, [(0.5f64, 0i64), (1.5, 2), (-2.5, -2), (1e300, i64::max_value())]);
+    test_cast!((u32x4), (as_f32s_u32x4), as_f32s, [(3u32, 3.0f32), (u32::max_value(), 4294967296.0)]);
+    test_cross_cast!((f64x2, i32x4, f32x4, i64x2, i16x8, u8x16, i8x16, f64x8, i32x16, f32x16, i64x8, i16x32, u8x64, i8x64),
+                     (cross_cast_128, cross_cast_512));
+}
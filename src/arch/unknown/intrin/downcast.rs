@@ -5,7 +5,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::arch::current::vecs::*;
+use crate::arch::unknown::vecs::*;
+use crate::intrin::cast::*;
 use crate::intrin::downcast::*;
 
 impl Downcast<i16x8> for i32x4 {
@@ -271,3 +272,15 @@ impl Downcast<f32x8> for f64x4 {
                    other.extract(3) as f32)
     }
 }
+
+impl_cross_cast_fallback!(i32_i8, i32x4, i8x16, i8, i32x4, u8x16, u8, i32x8, i8x32, i8, i32x8, u8x32, u8,
+                          i32x16, i8x64, i8, i32x16, u8x64, u8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_downcast!((i32x4, i16x8, u32x4, u16x8), (i16x8, i8x16, u16x8, u8x16), (i16, i8, u16, u8),
+                   (downcast_i32x4, downcast_i16x8, downcast_u32x4, downcast_u16x8));
+}
@@ -6,7 +6,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::intrin::eq::*;
-use crate::arch::current::vecs::*;
+use crate::arch::unknown::vecs::*;
 use crate::vecs::*;
 
 rust_fallback_eq! {
@@ -69,39 +69,44 @@ rust_fallback_eq! {
     }
 }
 
+impl_eq_fallback!(u8x32, u8x32, i8x32, u8x32, u16x16, u16x16, i16x16, u16x16, u32x8, u32x8, i32x8, u32x8,
+                  f32x8, u32x8, u64x4, u64x4, i64x4, u64x4, f64x4, u64x4);
+impl_eq_fallback!(u8x64, u8x64, i8x64, u8x64, u16x32, u16x32, i16x32, u16x32, u32x16, u32x16, i32x16, u32x16,
+                  f32x16, u32x16, u64x8, u64x8, i64x8, u64x8, f64x8, u64x8);
+
 mod tests {
     #![allow(unused_imports)]
     use crate::prelude::*;
-    use crate::arch::current::vecs::*;
+    use crate::arch::unknown::vecs::*;
 
-    // test_packed_eq!(u8x64, u8, u8x64, u8, test_eq_u8x64);
-    // test_packed_eq!(u8x32, u8, u8x32, u8, test_eq_u8x32);
+    test_packed_eq!(u8x64, u8, u8x64, u8, test_eq_u8x64);
+    test_packed_eq!(u8x32, u8, u8x32, u8, test_eq_u8x32);
     test_packed_eq!(u8x16, u8, u8x16, u8, test_eq_u8x16);
-    // test_packed_eq!(i8x64, i8, u8x64, u8, test_eq_i8x64);
-    // test_packed_eq!(i8x32, i8, u8x32, u8, test_eq_i8x32);
+    test_packed_eq!(i8x64, i8, u8x64, u8, test_eq_i8x64);
+    test_packed_eq!(i8x32, i8, u8x32, u8, test_eq_i8x32);
     test_packed_eq!(i8x16, i8, u8x16, u8, test_eq_i8x16);
-    // test_packed_eq!(u16x32, u16, u16x32, u16, test_eq_u16x32);
-    // test_packed_eq!(u16x16, u16, u16x16, u16, test_eq_u16x16);
+    test_packed_eq!(u16x32, u16, u16x32, u16, test_eq_u16x32);
+    test_packed_eq!(u16x16, u16, u16x16, u16, test_eq_u16x16);
     test_packed_eq!(u16x8, u16, u16x8, u16, test_eq_u16x8);
-    // test_packed_eq!(i16x32, i16, u16x32, u16, test_eq_i16x32);
-    // test_packed_eq!(i16x16, i16, u16x16, u16, test_eq_i16x16);
+    test_packed_eq!(i16x32, i16, u16x32, u16, test_eq_i16x32);
+    test_packed_eq!(i16x16, i16, u16x16, u16, test_eq_i16x16);
     test_packed_eq!(i16x8, i16, u16x8, u16, test_eq_i16x8);
-    // test_packed_eq!(u32x16, u32, u32x16, u32, test_eq_u32x16);
-    // test_packed_eq!(u32x8, u32, u32x8, u32, test_eq_u32x8);
+    test_packed_eq!(u32x16, u32, u32x16, u32, test_eq_u32x16);
+    test_packed_eq!(u32x8, u32, u32x8, u32, test_eq_u32x8);
     test_packed_eq!(u32x4, u32, u32x4, u32, test_eq_u32x4);
-    // test_packed_eq!(i32x16, i32, u32x16, u32, test_eq_i32x16);
-    // test_packed_eq!(i32x8, i32, u32x8, u32, test_eq_i32x8);
+    test_packed_eq!(i32x16, i32, u32x16, u32, test_eq_i32x16);
+    test_packed_eq!(i32x8, i32, u32x8, u32, test_eq_i32x8);
     test_packed_eq!(i32x4, i32, u32x4, u32, test_eq_i32x4);
-    // test_packed_eq!(f32x16, f32, u32x16, u32, test_eq_f32x16);
-    // test_packed_eq!(f32x8, f32, u32x8, u32, test_eq_f32x8);
+    test_packed_eq!(f32x16, f32, u32x16, u32, test_eq_f32x16);
+    test_packed_eq!(f32x8, f32, u32x8, u32, test_eq_f32x8);
     test_packed_eq!(f32x4, f32, u32x4, u32, test_eq_f32x4);
-    // test_packed_eq!(u64x8, u64, u64x8, u64, test_eq_u64x8);
-    // test_packed_eq!(u64x4, u64, u64x4, u64, test_eq_u64x4);
+    test_packed_eq!(u64x8, u64, u64x8, u64, test_eq_u64x8);
+    test_packed_eq!(u64x4, u64, u64x4, u64, test_eq_u64x4);
     test_packed_eq!(u64x2, u64, u64x2, u64, test_eq_u64x2);
-    // test_packed_eq!(i64x8, i64, u64x8, u64, test_eq_i64x8);
-    // test_packed_eq!(i64x4, i64, u64x4, u64, test_eq_i64x4);
+    test_packed_eq!(i64x8, i64, u64x8, u64, test_eq_i64x8);
+    test_packed_eq!(i64x4, i64, u64x4, u64, test_eq_i64x4);
     test_packed_eq!(i64x2, i64, u64x2, u64, test_eq_i64x2);
-    // test_packed_eq!(f64x8, f64, u64x8, u64, test_eq_f64x8);
-    // test_packed_eq!(f64x4, f64, u64x4, u64, test_eq_f64x4);
+    test_packed_eq!(f64x8, f64, u64x8, u64, test_eq_f64x8);
+    test_packed_eq!(f64x4, f64, u64x4, u64, test_eq_f64x4);
     test_packed_eq!(f64x2, f64, u64x2, u64, test_eq_f64x2);
 }
@@ -0,0 +1,23 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::vecs::*;
+use crate::intrin::float::*;
+
+impl_classify_fallback!(f32x4, f32, u32x4, 0x8000_0000, f64x2, f64, u64x2, 0x8000_0000_0000_0000,
+                        f32x8, f32, u32x8, 0x8000_0000, f64x4, f64, u64x4, 0x8000_0000_0000_0000,
+                        f32x16, f32, u32x16, 0x8000_0000, f64x8, f64, u64x8, 0x8000_0000_0000_0000);
+impl_clamp_fallback!(f32x4, f64x2, f32x8, f64x4, f32x16, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_float_classify!((f32x4, f64x2), (f32, f64), (classify_f32x4, classify_f64x2));
+}
@@ -7,7 +7,7 @@
 
 use crate::intrin::hadd::*;
 use crate::std::ops::Add;
-use crate::arch::current::vecs::*;
+use crate::arch::unknown::vecs::*;
 use crate::vecs::*;
 
 impl HAdd for u64x2 { hop!(hadd, Add::add, 0, 1); }
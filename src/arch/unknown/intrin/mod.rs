@@ -6,45 +6,85 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 mod abs;
+mod abs_diff;
+mod avg;
+mod argminmax;
+mod bitmask;
+mod bf16;
+mod cast;
 mod cmp;
+mod compact;
 mod destride;
+mod divide;
 mod downcast;
 mod endian;
 mod eq;
+mod float;
+mod half;
 mod hadd;
 mod hsub;
 mod merge;
+mod mul_add;
+mod mul_hi;
+mod ord;
+mod popcnt;
 mod recip;
+mod reduce;
 mod round;
 mod rsqrt;
 mod saturating_add;
 mod saturating_hadd;
 mod saturating_sub;
 mod saturating_hsub;
+mod scan;
+mod select;
+mod shift;
 mod sum;
 mod sqrt;
+mod swizzle;
 mod transmute;
 mod upcast;
+mod widening_mul;
 
 pub mod prelude {
     pub use super::abs::*;
+    pub use super::abs_diff::*;
+    pub use super::avg::*;
+    pub use super::argminmax::*;
+    pub use super::bitmask::*;
+    pub use super::bf16::*;
+    pub use super::cast::*;
     pub use super::cmp::*;
+    pub use super::compact::*;
     pub use super::destride::*;
+    pub use super::divide::*;
     pub use super::downcast::*;
     pub use super::endian::*;
     pub use super::eq::*;
+    pub use super::float::*;
+    pub use super::half::*;
     pub use super::hadd::*;
     pub use super::hsub::*;
     pub use super::merge::*;
+    pub use super::mul_add::*;
+    pub use super::mul_hi::*;
+    pub use super::ord::*;
+    pub use super::popcnt::*;
     pub use super::recip::*;
+    pub use super::reduce::*;
     pub use super::round::*;
     pub use super::rsqrt::*;
     pub use super::saturating_add::*;
     pub use super::saturating_hadd::*;
     pub use super::saturating_hsub::*;
     pub use super::saturating_sub::*;
+    pub use super::scan::*;
+    pub use super::select::*;
+    pub use super::shift::*;
     pub use super::sum::*;
     pub use super::sqrt::*;
+    pub use super::swizzle::*;
     pub use super::transmute::*;
     pub use super::upcast::*;
+    pub use super::widening_mul::*;
 }
@@ -0,0 +1,28 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::vecs::*;
+use crate::intrin::mul_hi::*;
+
+impl_mul_hi_fallback!(u8x16, u8, u64, i8x16, i8, i64, u16x8, u16, u64, i16x8, i16, i64, u32x4, u32, u64, i32x4, i32, i64,
+                      u8x32, u8, u64, i8x32, i8, i64, u16x16, u16, u64, i16x16, i16, i64, u32x8, u32, u64, i32x8, i32, i64,
+                      u8x64, u8, u64, i8x64, i8, i64, u16x32, u16, u64, i16x32, i16, i64, u32x16, u32, u64, i32x16, i32, i64);
+impl_mul_hi_round_fallback!(i8x16, i8, i16x8, i16, i32x4, i32, i8x32, i8, i16x16, i16, i32x8, i32,
+                            i8x64, i8, i16x32, i16, i32x16, i32);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_mul_hi!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4),
+                 (u8, i8, u16, i16, u32, i32),
+                 (mul_hi_u8x16, mul_hi_i8x16, mul_hi_u16x8, mul_hi_i16x8, mul_hi_u32x4, mul_hi_i32x4));
+    test_mul_hi_round!((i8x16, i16x8, i32x4), (i8, i16, i32),
+                       (mul_hi_round_i8x16, mul_hi_round_i16x8, mul_hi_round_i32x4));
+}
@@ -0,0 +1,28 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::vecs::*;
+use crate::intrin::popcnt::*;
+
+impl_bit_count_fallback!(u8x16, u8, u8, i8x16, i8, u8, u16x8, u16, u16, i16x8, i16, u16,
+                         u32x4, u32, u32, i32x4, i32, u32, u64x2, u64, u64, i64x2, i64, u64,
+                         u8x32, u8, u8, i8x32, i8, u8, u16x16, u16, u16, i16x16, i16, u16,
+                         u32x8, u32, u32, i32x8, i32, u32, u64x4, u64, u64, i64x4, i64, u64,
+                         u8x64, u8, u8, i8x64, i8, u8, u16x32, u16, u16, i16x32, i16, u16,
+                         u32x16, u32, u32, i32x16, i32, u32, u64x8, u64, u64, i64x8, i64, u64);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_bit_count!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2),
+                    (u8, i8, u16, i16, u32, i32, u64, i64),
+                    (bit_count_u8x16, bit_count_i8x16, bit_count_u16x8, bit_count_i16x8,
+                     bit_count_u32x4, bit_count_i32x4, bit_count_u64x2, bit_count_i64x2));
+}
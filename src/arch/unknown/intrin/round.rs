@@ -6,7 +6,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::intrin::round::Round;
-use crate::arch::current::vecs::*;
+use crate::arch::unknown::vecs::*;
 use crate::vecs::*;
 
 rust_fallback_impl! {
@@ -44,3 +44,5 @@ rust_fallback_impl! {
         trunc => __undefined(_MM_FROUND_TRUNC), [0, 1, 2, 3];
     }
 }
+
+impl_round_fallback!(f32x16, f64x8);
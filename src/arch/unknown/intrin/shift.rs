@@ -0,0 +1,32 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::vecs::*;
+use crate::intrin::shift::*;
+
+impl_shift_fallback!(u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2,
+                     u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4,
+                     u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8);
+impl_shift_var_fallback!(u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2,
+                         u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4,
+                         u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8);
+impl_rotate!(u8x16, u8, i8x16, i8, u16x8, u16, i16x8, i16, u32x4, u32, i32x4, i32, u64x2, u64, i64x2, i64,
+             u8x32, u8, i8x32, i8, u16x16, u16, i16x16, i16, u32x8, u32, i32x8, i32, u64x4, u64, i64x4, i64,
+             u8x64, u8, i8x64, i8, u16x32, u16, i16x32, i16, u32x16, u32, i32x16, i32, u64x8, u64, i64x8, i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_packed_shift!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2),
+                       (u8, i8, u16, i16, u32, i32, u64, i64),
+                       (u8, u8, u16, u16, u32, u32, u64, u64),
+                       (i8, i8, i16, i16, i32, i32, i64, i64),
+                       (shift_u8x16, shift_i8x16, shift_u16x8, shift_i16x8, shift_u32x4, shift_i32x4, shift_u64x2, shift_i64x2));
+}
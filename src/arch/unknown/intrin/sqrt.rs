@@ -6,7 +6,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::intrin::sqrt::*;
-use crate::arch::current::vecs::*;
+use crate::arch::unknown::vecs::*;
 use crate::vecs::*;
 
 rust_fallback_impl! {
@@ -20,3 +20,5 @@ rust_fallback_impl! {
         sqrt => __undefined(), [0, 1];
     }
 }
+
+impl_sqrt_fallback!(f32x8, f64x4, f32x16, f64x8);
@@ -0,0 +1,35 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::vecs::*;
+use crate::intrin::merge::*;
+use crate::intrin::swizzle::*;
+
+impl_swizzle_fallback!(u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2,
+                       u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4,
+                       u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8);
+impl_shuffle_fallback!(u8x16, u8x16, u8, 16, i8x16, u8x16, u8, 16, u16x8, u16x8, u16, 8, i16x8, u16x8, u16, 8,
+                       u32x4, u32x4, u32, 4, i32x4, u32x4, u32, 4, f32x4, u32x4, u32, 4,
+                       u64x2, u64x2, u64, 2, i64x2, u64x2, u64, 2, f64x2, u64x2, u64, 2,
+                       u8x32, u8x32, u8, 32, i8x32, u8x32, u8, 32, u16x16, u16x16, u16, 16, i16x16, u16x16, u16, 16,
+                       u32x8, u32x8, u32, 8, i32x8, u32x8, u32, 8, f32x8, u32x8, u32, 8,
+                       u64x4, u64x4, u64, 4, i64x4, u64x4, u64, 4, f64x4, u64x4, u64, 4,
+                       u8x64, u8x64, u8, 64, i8x64, u8x64, u8, 64, u16x32, u16x32, u16, 32, i16x32, u16x32, u16, 32,
+                       u32x16, u32x16, u32, 16, i32x16, u32x16, u32, 16, f32x16, u32x16, u32, 16,
+                       u64x8, u64x8, u64, 8, i64x8, u64x8, u64, 8, f64x8, u64x8, u64, 8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_shuffle!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2),
+                  (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                  (shuffle_u8x16, shuffle_i8x16, shuffle_u16x8, shuffle_i16x8, shuffle_u32x4,
+                   shuffle_i32x4, shuffle_f32x4, shuffle_u64x2, shuffle_i64x2, shuffle_f64x2));
+}
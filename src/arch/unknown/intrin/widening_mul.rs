@@ -0,0 +1,28 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::unknown::vecs::*;
+use crate::vecs::*;
+use crate::intrin::widening_mul::*;
+
+impl_widening_mul_fallback!(u8x16, u16x8, u16, i8x16, i16x8, i16, u16x8, u32x4, u32, i16x8, i32x4, i32,
+                            u32x4, u64x2, u64, i32x4, i64x2, i64,
+                            u8x32, u16x16, u16, i8x32, i16x16, i16, u16x16, u32x8, u32, i16x16, i32x8, i32,
+                            u32x8, u64x4, u64, i32x8, i64x4, i64,
+                            u8x64, u16x32, u16, i8x64, i16x32, i16, u16x32, u32x16, u32, i16x32, i32x16, i32,
+                            u32x16, u64x8, u64, i32x16, i64x8, i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::unknown::vecs::*;
+
+    test_widening_mul!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4),
+                       (u8, i8, u16, i16, u32, i32),
+                       (widening_mul_u8x16, widening_mul_i8x16, widening_mul_u16x8,
+                        widening_mul_i16x8, widening_mul_u32x4, widening_mul_i32x4));
+}
@@ -228,17 +228,73 @@ macro_rules! impl_cast {
 }
 
 // "undefined" is just a string that should not match any target-feature.
+// The 128-bit vectors are only the default vectors where these polyfills are
+// the current architecture.
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(u8, u8s, u8x16, 1, 16, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(i8, i8s, i8x16, 1, 16, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(u16, u16s, u16x8, 2, 8, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(i16, i16s, i16x8, 2, 8, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(u32, u32s, u32x4, 4, 4, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(i32, i32s, i32x4, 4, 4, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(f32, f32s, f32x4, 4, 4, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(u64, u64s, u64x2, 8, 2, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(i64, i64s, i64x2, 8, 2, [], ["undefined"]);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 impl_packed!(f64, f64s, f64x2, 8, 2, [], ["undefined"]);
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(u8, u8s, u8x16, 1, 16, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(i8, i8s, i8x16, 1, 16, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(u16, u16s, u16x8, 2, 8, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(i16, i16s, i16x8, 2, 8, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(u32, u32s, u32x4, 4, 4, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(i32, i32s, i32x4, 4, 4, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(f32, f32s, f32x4, 4, 4, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(u64, u64s, u64x2, 8, 2, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(i64, i64s, i64x2, 8, 2, ["undefined"], ["undefined"]);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_packed!(f64, f64s, f64x2, 8, 2, ["undefined"], ["undefined"]);
+
+// The wider vectors are never the default vector for their scalar type, but
+// may still be iterated over explicitly.
+impl_packed!(u8, u8s, u8x32, 1, 32, ["undefined"], ["undefined"]);
+impl_packed!(u8, u8s, u8x64, 1, 64, ["undefined"], ["undefined"]);
+impl_packed!(i8, i8s, i8x32, 1, 32, ["undefined"], ["undefined"]);
+impl_packed!(i8, i8s, i8x64, 1, 64, ["undefined"], ["undefined"]);
+impl_packed!(u16, u16s, u16x16, 2, 16, ["undefined"], ["undefined"]);
+impl_packed!(u16, u16s, u16x32, 2, 32, ["undefined"], ["undefined"]);
+impl_packed!(i16, i16s, i16x16, 2, 16, ["undefined"], ["undefined"]);
+impl_packed!(i16, i16s, i16x32, 2, 32, ["undefined"], ["undefined"]);
+impl_packed!(u32, u32s, u32x8, 4, 8, ["undefined"], ["undefined"]);
+impl_packed!(u32, u32s, u32x16, 4, 16, ["undefined"], ["undefined"]);
+impl_packed!(i32, i32s, i32x8, 4, 8, ["undefined"], ["undefined"]);
+impl_packed!(i32, i32s, i32x16, 4, 16, ["undefined"], ["undefined"]);
+impl_packed!(f32, f32s, f32x8, 4, 8, ["undefined"], ["undefined"]);
+impl_packed!(f32, f32s, f32x16, 4, 16, ["undefined"], ["undefined"]);
+impl_packed!(u64, u64s, u64x4, 8, 4, ["undefined"], ["undefined"]);
+impl_packed!(u64, u64s, u64x8, 8, 8, ["undefined"], ["undefined"]);
+impl_packed!(i64, i64s, i64x4, 8, 4, ["undefined"], ["undefined"]);
+impl_packed!(i64, i64s, i64x8, 8, 8, ["undefined"], ["undefined"]);
+impl_packed!(f64, f64s, f64x4, 8, 4, ["undefined"], ["undefined"]);
+impl_packed!(f64, f64s, f64x8, 8, 8, ["undefined"], ["undefined"]);
+
 impl_packed_type!(f64, f64s, f64x2, 2, [x0, x1]);
 impl_packed_type!(f64, f64s, f64x4, 4, [x0, x1, x2, x3]);
 impl_packed_type!(f64, f64s, f64x8, 8, [x0, x1, x2, x3, x4, x5, x6, x7]);
@@ -324,3 +324,4 @@ impl Abs for i64x8 {
     }
 }
 
+impl_abs_float_fallback!(f32x16, f64x8);
@@ -0,0 +1,140 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::abs::*;
+use crate::intrin::abs_diff::*;
+use crate::intrin::ord::*;
+use crate::intrin::select::*;
+use crate::intrin::transmute::*;
+use crate::std::mem::transmute;
+
+// Saturating subtraction leaves zero in whichever direction would go
+// negative, so or-ing both directions gives the distance. Signed vectors are
+// biased into unsigned ones first, which preserves their order.
+macro_rules! impl_abs_diff_subs {
+    ($feat:expr, $subs:ident, $bias:expr, $($vec:ty, $out:ident, $uel:ty),*) => {
+        $(
+            impl AbsDiff for $vec {
+                type Out = $out;
+
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn abs_diff(&self, other: Self) -> Self::Out {
+                    optimized!();
+                    let bias = $out::splat($bias);
+                    let a = unsafe { transmute::<_, $out>(*self) } ^ bias;
+                    let b = unsafe { transmute::<_, $out>(other) } ^ bias;
+                    unsafe {
+                        transmute::<_, $out>($subs(transmute(a), transmute(b)))
+                            | transmute::<_, $out>($subs(transmute(b), transmute(a)))
+                    }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn abs_diff(&self, other: Self) -> Self::Out {
+                    abs_diff_fallback(self, &other, |a, b| {
+                        if a > b { a.wrapping_sub(b) as $uel } else { b.wrapping_sub(a) as $uel }
+                    })
+                }
+            }
+        )*
+    }
+}
+
+// Wider lanes have no saturating subtraction, so the difference is taken in
+// whichever order is positive.
+macro_rules! impl_abs_diff_select {
+    ($($vec:ty, $out:ty, $be:ident),*) => {
+        $(
+            impl AbsDiff for $vec {
+                type Out = $out;
+
+                #[inline(always)]
+                fn abs_diff(&self, other: Self) -> Self::Out {
+                    self.gt_mask(other).select(*self - other, other - *self).$be()
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_abs_diff_float {
+    ($($vec:ty),*) => {
+        $(
+            impl AbsDiff for $vec {
+                type Out = $vec;
+
+                #[inline(always)]
+                fn abs_diff(&self, other: Self) -> Self::Out {
+                    (*self - other).abs()
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_sad {
+    ($feat:expr, $sad:ident, $vec:ty, $out:ty) => {
+        impl Sad for $vec {
+            type Out = $out;
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn sad(&self, other: Self) -> Self::Out {
+                optimized!();
+                unsafe { transmute($sad(transmute(*self), transmute(other))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn sad(&self, other: Self) -> Self::Out {
+                sad_fallback(self, &other)
+            }
+        }
+    }
+}
+
+impl_abs_diff_subs!("sse2", _mm_subs_epu8, 0, u8x16, u8x16, u8);
+impl_abs_diff_subs!("sse2", _mm_subs_epu8, 0x80, i8x16, u8x16, u8);
+impl_abs_diff_subs!("sse2", _mm_subs_epu16, 0, u16x8, u16x8, u16);
+impl_abs_diff_subs!("sse2", _mm_subs_epu16, 0x8000, i16x8, u16x8, u16);
+impl_abs_diff_subs!("avx2", _mm256_subs_epu8, 0, u8x32, u8x32, u8);
+impl_abs_diff_subs!("avx2", _mm256_subs_epu8, 0x80, i8x32, u8x32, u8);
+impl_abs_diff_subs!("avx2", _mm256_subs_epu16, 0, u16x16, u16x16, u16);
+impl_abs_diff_subs!("avx2", _mm256_subs_epu16, 0x8000, i16x16, u16x16, u16);
+impl_abs_diff_select!(u32x4, u32x4, be_u32s, i32x4, u32x4, be_u32s, u64x2, u64x2, be_u64s, i64x2, u64x2, be_u64s,
+                      u32x8, u32x8, be_u32s, i32x8, u32x8, be_u32s, u64x4, u64x4, be_u64s, i64x4, u64x4, be_u64s);
+impl_abs_diff_fallback!(int, u8x64, u8x64, u8, i8x64, u8x64, u8, u16x32, u16x32, u16, i16x32, u16x32, u16,
+                        u32x16, u32x16, u32, i32x16, u32x16, u32, u64x8, u64x8, u64, i64x8, u64x8, u64);
+impl_abs_diff_float!(f32x4, f64x2, f32x8, f64x4);
+impl_abs_diff_fallback!(float, f32x16, f32, 0x7FFF_FFFF, f64x8, f64, 0x7FFF_FFFF_FFFF_FFFF);
+
+impl_sad!("sse2", _mm_sad_epu8, u8x16, u64x2);
+impl_sad!("avx2", _mm256_sad_epu8, u8x32, u64x4);
+impl_sad_fallback!(u8x64, u64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_abs_diff!(int, (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2),
+                   (u8, i8, u16, i16, u32, i32, u64, i64),
+                   (abs_diff_u8x16, abs_diff_i8x16, abs_diff_u16x8, abs_diff_i16x8, abs_diff_u32x4, abs_diff_i32x4, abs_diff_u64x2, abs_diff_i64x2));
+    test_abs_diff!(int, (u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4),
+                   (u8, i8, u16, i16, u32, i32, u64, i64),
+                   (abs_diff_u8x32, abs_diff_i8x32, abs_diff_u16x16, abs_diff_i16x16, abs_diff_u32x8, abs_diff_i32x8, abs_diff_u64x4, abs_diff_i64x4));
+    test_abs_diff!(int, (i8x64), (i8), (abs_diff_i8x64));
+    test_abs_diff!(float, (f32x4, f64x2, f32x8, f64x4, f32x16), (f32, f64, f32, f64, f32),
+                   (abs_diff_f32x4, abs_diff_f64x2, abs_diff_f32x8, abs_diff_f64x4, abs_diff_f32x16));
+    test_sad!((u8x16, u8x32, u8x64), (u64x2, u64x4, u64x8), (sad_u8x16, sad_u8x32, sad_u8x64));
+}
@@ -0,0 +1,226 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::argminmax::*;
+use crate::intrin::ord::PackedOrd;
+use crate::intrin::select::Select;
+use crate::intrin::transmute::*;
+use crate::std::mem::transmute;
+
+// phminposuw returns the smallest unsigned word in the first lane, and the
+// lowest index at which it occurs in the second. Other orderings are mapped
+// onto unsigned minimums by flipping bits beforehand.
+macro_rules! impl_argminmax_minpos {
+    ($vec:ty, $minflip:expr, $maxflip:expr) => {
+        impl ArgMinMax for $vec {
+            type Indices = u16x8;
+
+            #[inline(always)]
+            #[cfg(target_feature = "sse4.1")]
+            fn hargmin(&self) -> usize {
+                optimized!();
+                let pos: u16x8 = unsafe { transmute(_mm_minpos_epu16(transmute(self.be_u16s() ^ u16x8::splat($minflip)))) };
+                pos.extract(1) as usize
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "sse4.1")]
+            fn hargmax(&self) -> usize {
+                optimized!();
+                let pos: u16x8 = unsafe { transmute(_mm_minpos_epu16(transmute(self.be_u16s() ^ u16x8::splat($maxflip)))) };
+                pos.extract(1) as usize
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = "sse4.1"))]
+            fn hargmin(&self) -> usize {
+                harg_fallback(self, replaces_min)
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = "sse4.1"))]
+            fn hargmax(&self) -> usize {
+                harg_fallback(self, replaces_max)
+            }
+        }
+    }
+}
+
+// The other vectors carry the index of each lane's best element beside the
+// values, so that ties between lanes can go to the lower index. Wider vectors
+// pit their halves against each other until 128 bits remain, which are then
+// folded like fold128! in reduce.rs.
+#[cfg(target_feature = "sse2")]
+trait HargFold : ArgMinMax {
+    fn harg_fold_min(self, idx: Self::Indices) -> usize;
+    fn harg_fold_max(self, idx: Self::Indices) -> usize;
+}
+
+/// Return a vector which holds the number of each lane.
+#[inline(always)]
+#[cfg(target_feature = "sse2")]
+fn lane_indices<T : Packed>() -> T where T::Scalar : From<u8> {
+    (0..T::WIDTH).fold(T::splat(0u8.into()), |mut acc, i| acc.replace(i, (i as u8).into()))
+}
+
+/// Return the winner of each lane of `(x, xi)` and `(best, bi)`, which are
+/// values and the indices they came from. Unequal values are decided by
+/// `beats`, and equal ones or NaNs by the lower index.
+#[inline(always)]
+#[cfg(target_feature = "sse2")]
+fn harg_step<T, F>(x: T, xi: T::Indices, best: T, bi: T::Indices, beats: F) -> (T, T::Indices)
+    where T : ArgMinMax + PackedOrd, T::Indices : PackedOrd<Out = T::Out>,
+          T::Out : Select<T> + Select<T::Indices>, F : Fn(&T, &T) -> T::Out {
+    let tie = x.eq_mask(best) | (x.unordered_mask(x) & best.unordered_mask(best));
+    let take = beats(&x, &best) | (tie & xi.lt_mask(bi));
+    (take.select(x, best), take.select(xi, bi))
+}
+
+macro_rules! harg_fold128 {
+    ($x:expr, $idx:expr, $beats:expr, [$($bytes:expr),*]) => {{
+        let mut best = ($x, $idx);
+        $(
+            best = {
+                let (r, ri) = unsafe {
+                    (transmute(_mm_srli_si128(transmute(best.0), $bytes)),
+                     transmute(_mm_srli_si128(transmute(best.1), $bytes)))
+                };
+                harg_step(r, ri, best.0, best.1, $beats)
+            };
+        )*
+        best.1.extract(0) as usize
+    }}
+}
+
+macro_rules! harg_halves {
+    ($x:expr, $idx:expr, $half:ty, $beats:expr) => {{
+        let halves: [$half; 2] = unsafe { transmute($x) };
+        let indices: [<$half as ArgMinMax>::Indices; 2] = unsafe { transmute($idx) };
+        harg_step(halves[1], indices[1], halves[0], indices[0], $beats)
+    }}
+}
+
+macro_rules! impl_harg_fold {
+    (128, $($vec:ty, $steps:tt),*) => {
+        $(
+            #[cfg(target_feature = "sse2")]
+            impl HargFold for $vec {
+                #[inline(always)]
+                fn harg_fold_min(self, idx: Self::Indices) -> usize {
+                    harg_fold128!(self, idx, beats_min, $steps)
+                }
+
+                #[inline(always)]
+                fn harg_fold_max(self, idx: Self::Indices) -> usize {
+                    harg_fold128!(self, idx, beats_max, $steps)
+                }
+            }
+        )*
+    };
+    (halves, $($vec:ty, $half:ty),*) => {
+        $(
+            #[cfg(target_feature = "sse2")]
+            impl HargFold for $vec {
+                #[inline(always)]
+                fn harg_fold_min(self, idx: Self::Indices) -> usize {
+                    let (x, xi) = harg_halves!(self, idx, $half, beats_min);
+                    x.harg_fold_min(xi)
+                }
+
+                #[inline(always)]
+                fn harg_fold_max(self, idx: Self::Indices) -> usize {
+                    let (x, xi) = harg_halves!(self, idx, $half, beats_max);
+                    x.harg_fold_max(xi)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_argminmax_fold {
+    ($($vec:ty, $idx:ty),*) => {
+        $(
+            impl ArgMinMax for $vec {
+                type Indices = $idx;
+
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn hargmin(&self) -> usize {
+                    optimized!();
+                    self.harg_fold_min(lane_indices())
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn hargmax(&self) -> usize {
+                    optimized!();
+                    self.harg_fold_max(lane_indices())
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn hargmin(&self) -> usize {
+                    harg_fallback(self, replaces_min)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn hargmax(&self) -> usize {
+                    harg_fallback(self, replaces_max)
+                }
+            }
+        )*
+    }
+}
+
+impl_argminmax_minpos!(u16x8, 0x0000, 0xFFFF);
+impl_argminmax_minpos!(i16x8, 0x8000, 0x7FFF);
+
+impl_argminmax_fold!(u8x16, u8x16, i8x16, u8x16, u32x4, u32x4, i32x4, u32x4, f32x4, u32x4, u64x2, u64x2,
+                     i64x2, u64x2, f64x2, u64x2);
+impl_argminmax_fold!(u8x32, u8x32, i8x32, u8x32, u16x16, u16x16, i16x16, u16x16, u32x8, u32x8,
+                     i32x8, u32x8, f32x8, u32x8, u64x4, u64x4, i64x4, u64x4, f64x4, u64x4);
+impl_argminmax_fold!(u8x64, u8x64, i8x64, u8x64, u16x32, u16x32, i16x32, u16x32, u32x16, u32x16,
+                     i32x16, u32x16, f32x16, u32x16, u64x8, u64x8, i64x8, u64x8, f64x8, u64x8);
+
+// The 16-bit vectors are folded too when they are halves of wider ones.
+impl_harg_fold!(128, u8x16, [8, 4, 2, 1], i8x16, [8, 4, 2, 1], u16x8, [8, 4, 2], i16x8, [8, 4, 2],
+                u32x4, [8, 4], i32x4, [8, 4], f32x4, [8, 4], u64x2, [8], i64x2, [8], f64x2, [8]);
+impl_harg_fold!(halves, u8x32, u8x16, i8x32, i8x16, u16x16, u16x8, i16x16, i16x8, u32x8, u32x4, i32x8, i32x4,
+                f32x8, f32x4, u64x4, u64x2, i64x4, i64x2, f64x4, f64x2, u8x64, u8x32, i8x64, i8x32,
+                u16x32, u16x16, i16x32, i16x16, u32x16, u32x8, i32x16, i32x8, f32x16, f32x8,
+                u64x8, u64x4, i64x8, i64x4, f64x8, f64x4);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_packed_argminmax!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2),
+                           (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                           (argminmax_u8x16, argminmax_i8x16, argminmax_u16x8, argminmax_i16x8, argminmax_u32x4, argminmax_i32x4, argminmax_f32x4, argminmax_u64x2, argminmax_i64x2, argminmax_f64x2));
+    test_packed_argminmax!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4),
+                           (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                           (argminmax_u8x32, argminmax_i8x32, argminmax_u16x16, argminmax_i16x16, argminmax_u32x8, argminmax_i32x8, argminmax_f32x8, argminmax_u64x4, argminmax_i64x4, argminmax_f64x4));
+    test_packed_argminmax!((u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+                           (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                           (argminmax_u8x64, argminmax_i8x64, argminmax_u16x32, argminmax_i16x32, argminmax_u32x16, argminmax_i32x16, argminmax_f32x16, argminmax_u64x8, argminmax_i64x8, argminmax_f64x8));
+    test_packed_argminmax_nan!((f32x4, f64x2, f32x8, f64x4, f32x16, f64x8), (f32, f64, f32, f64, f32, f64),
+                               (argminmax_nan_f32x4, argminmax_nan_f64x2, argminmax_nan_f32x8,
+                                argminmax_nan_f64x4, argminmax_nan_f32x16, argminmax_nan_f64x8));
+
+    #[test]
+    fn argminmax_i16x8_signed() {
+        let v = i16x8::new(5, -3, 7, -3, 32767, -32768, 0, 32767);
+        assert_eq!(v.hargmin(), 5);
+        assert_eq!(v.hargmax(), 4);
+    }
+}
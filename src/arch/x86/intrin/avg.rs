@@ -0,0 +1,81 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::avg::*;
+use crate::intrin::shift::*;
+use crate::std::mem::transmute;
+
+// Signed vectors are biased into unsigned ones, whose average is biased by
+// the same amount.
+macro_rules! impl_avg {
+    ($feat:expr, $avg:ident, $bias:expr, $($vec:ty, $el:ty, $uvec:ident),*) => {
+        $(
+            impl Avg for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn avg(&self, other: Self) -> Self {
+                    optimized!();
+                    let bias = $uvec::splat($bias);
+                    let a = unsafe { transmute::<_, $uvec>(*self) } ^ bias;
+                    let b = unsafe { transmute::<_, $uvec>(other) } ^ bias;
+                    unsafe { transmute(transmute::<_, $uvec>($avg(transmute(a), transmute(b))) ^ bias) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn avg(&self, other: Self) -> Self {
+                    avg_fallback(self, &other, |a, b| ((a as i64 + b as i64 + 1) >> 1) as $el)
+                }
+            }
+        )*
+    }
+}
+
+// Wider lanes have no average instruction, so it is taken as the bits in
+// either element, less half of the bits in exactly one of them.
+macro_rules! impl_avg_bits {
+    ($($vec:ty, $shr:ident),*) => {
+        $(
+            impl Avg for $vec {
+                #[inline(always)]
+                fn avg(&self, other: Self) -> Self {
+                    (*self | other) - (*self ^ other).$shr(1)
+                }
+            }
+        )*
+    }
+}
+
+impl_avg!("sse2", _mm_avg_epu8, 0, u8x16, u8, u8x16);
+impl_avg!("sse2", _mm_avg_epu8, 0x80, i8x16, i8, u8x16);
+impl_avg!("sse2", _mm_avg_epu16, 0, u16x8, u16, u16x8);
+impl_avg!("sse2", _mm_avg_epu16, 0x8000, i16x8, i16, u16x8);
+impl_avg!("avx2", _mm256_avg_epu8, 0, u8x32, u8, u8x32);
+impl_avg!("avx2", _mm256_avg_epu8, 0x80, i8x32, i8, u8x32);
+impl_avg!("avx2", _mm256_avg_epu16, 0, u16x16, u16, u16x16);
+impl_avg!("avx2", _mm256_avg_epu16, 0x8000, i16x16, i16, u16x16);
+impl_avg_bits!(u32x4, shift_right_logical, i32x4, shift_right_arithmetic,
+               u32x8, shift_right_logical, i32x8, shift_right_arithmetic);
+impl_avg_fallback!(u8x64, u8, i8x64, i8, u16x32, u16, i16x32, i16, u32x16, u32, i32x16, i32);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_avg!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4),
+              (u8, i8, u16, i16, u32, i32),
+              (avg_u8x16, avg_i8x16, avg_u16x8, avg_i16x8, avg_u32x4, avg_i32x4));
+    test_avg!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8),
+              (u8, i8, u16, i16, u32, i32),
+              (avg_u8x32, avg_i8x32, avg_u16x16, avg_i16x16, avg_u32x8, avg_i32x8));
+    test_avg!((i16x32), (i16), (avg_i16x32));
+}
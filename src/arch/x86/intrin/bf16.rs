@@ -0,0 +1,27 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::intrin::bf16::*;
+use crate::intrin::downcast::*;
+use crate::intrin::eq::*;
+use crate::intrin::select::*;
+use crate::intrin::shift::*;
+use crate::intrin::transmute::*;
+use crate::intrin::upcast::*;
+
+impl_bf16!(u16x8, f32x4, u32x4, u16x16, f32x8, u32x8);
+impl_bf16_fallback!(u16x32, f32x16);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_bf16!((u16x8, u16x16, u16x32), (f32x4, f32x8, f32x16), (bf16_u16x8, bf16_u16x16, bf16_u16x32));
+}
@@ -0,0 +1,197 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::bitmask::*;
+use crate::intrin::eq::*;
+use crate::std::mem::transmute;
+
+// Builds masks by testing a different bit of `bits` in every lane.
+macro_rules! from_bitmask_splat {
+    ($uvec:ident, $uel:ty, $bits:expr, [$($lane:expr),*]) => {{
+        let lanes = $uvec::new($($lane),*);
+        unsafe { transmute(($uvec::splat($bits as $uel) & lanes).eq_mask(lanes)) }
+    }}
+}
+
+macro_rules! impl_bitmask {
+    ($feat:tt, $movemask:expr, $uvec:ident, $uel:ty, [$($lane:expr),*], $($vec:ty),*) => {
+        $(
+            impl Bitmask for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn bitmask(&self) -> u64 {
+                    optimized!();
+                    unsafe { $movemask(transmute(*self)) as u32 as u64 }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn bitmask(&self) -> u64 {
+                    bitmask_fallback::<Self, $uvec>(self)
+                }
+
+                #[inline(always)]
+                fn from_bitmask(bits: u64) -> Self {
+                    from_bitmask_splat!($uvec, $uel, bits, [$($lane),*])
+                }
+            }
+        )*
+    }
+}
+
+// Bytes can't hold a bit per lane, so each byte of the bitmask is first
+// copied to the lanes it describes.
+macro_rules! impl_bitmask_bytes {
+    ($feat:tt, $movemask:ident, $sfeat:tt, $shuffle:ident, $splat:ident, $uvec:ident, [$($spread:expr),*], [$($lane:expr),*], $($vec:ty),*) => {
+        $(
+            impl Bitmask for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn bitmask(&self) -> u64 {
+                    optimized!();
+                    unsafe { $movemask(transmute(*self)) as u32 as u64 }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn bitmask(&self) -> u64 {
+                    bitmask_fallback::<Self, $uvec>(self)
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = $sfeat)]
+                fn from_bitmask(bits: u64) -> Self {
+                    optimized!();
+                    let lanes = $uvec::new($($lane),*);
+                    unsafe {
+                        let spread: $uvec = transmute($shuffle(transmute($splat::splat(bits)), transmute($uvec::new($($spread),*))));
+                        transmute((spread & lanes).eq_mask(lanes))
+                    }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $sfeat))]
+                fn from_bitmask(bits: u64) -> Self {
+                    from_bitmask_fallback::<Self, $uvec>(bits)
+                }
+            }
+        )*
+    }
+}
+
+// There is no movemask for words, so they are first narrowed to bytes with
+// signed saturation, which keeps their sign bits.
+macro_rules! impl_bitmask_words {
+    ($uvec:ident, [$($lane:expr),*], $($vec:ty),*) => {
+        $(
+            impl Bitmask for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn bitmask(&self) -> u64 {
+                    optimized!();
+                    unsafe {
+                        let (lo, hi) = split_words!($uvec, *self);
+                        _mm_movemask_epi8(_mm_packs_epi16(transmute(lo), transmute(hi))) as u32 as u64
+                    }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn bitmask(&self) -> u64 {
+                    bitmask_fallback::<Self, $uvec>(self)
+                }
+
+                #[inline(always)]
+                fn from_bitmask(bits: u64) -> Self {
+                    from_bitmask_splat!($uvec, u16, bits, [$($lane),*])
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! split_words {
+    (u16x8, $val:expr) => { (transmute::<_, i16x8>($val), i16x8::splat(0)) };
+    (u16x16, $val:expr) => {{ let [lo, hi]: [i16x8; 2] = transmute($val); (lo, hi) }};
+}
+
+// Vectors without a movemask of their own are split in two.
+macro_rules! impl_bitmask_halves {
+    ($half:ident, $($vec:ty),*) => {
+        $(
+            impl Bitmask for $vec {
+                #[inline(always)]
+                fn bitmask(&self) -> u64 {
+                    let [lo, hi]: [$half; 2] = unsafe { transmute(*self) };
+                    lo.bitmask() | (hi.bitmask() << $half::WIDTH)
+                }
+
+                #[inline(always)]
+                fn from_bitmask(bits: u64) -> Self {
+                    let halves = [$half::from_bitmask(bits), $half::from_bitmask(bits >> $half::WIDTH)];
+                    unsafe { transmute(halves) }
+                }
+            }
+        )*
+    }
+}
+
+impl_bitmask_bytes!("sse2", _mm_movemask_epi8, "ssse3", _mm_shuffle_epi8, u64x2, u8x16,
+                    [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1],
+                    [1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128],
+                    u8x16, i8x16);
+impl_bitmask_bytes!("avx2", _mm256_movemask_epi8, "avx2", _mm256_shuffle_epi8, u64x4, u8x32,
+                    [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3],
+                    [1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128],
+                    u8x32, i8x32);
+
+impl_bitmask_words!(u16x8, [1, 2, 4, 8, 16, 32, 64, 128], u16x8, i16x8);
+impl_bitmask_words!(u16x16, [1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768], u16x16, i16x16);
+
+impl_bitmask!("sse2", _mm_movemask_ps, u32x4, u32, [1, 2, 4, 8], u32x4, i32x4, f32x4);
+impl_bitmask!("sse2", _mm_movemask_pd, u64x2, u64, [1, 2], u64x2, i64x2, f64x2);
+impl_bitmask!("avx", _mm256_movemask_ps, u32x8, u32, [1, 2, 4, 8, 16, 32, 64, 128], u32x8, i32x8, f32x8);
+impl_bitmask!("avx", _mm256_movemask_pd, u64x4, u64, [1, 2, 4, 8], u64x4, i64x4, f64x4);
+
+impl_bitmask_halves!(u8x32, u8x64);
+impl_bitmask_halves!(i8x32, i8x64);
+impl_bitmask_halves!(u16x16, u16x32);
+impl_bitmask_halves!(i16x16, i16x32);
+impl_bitmask_halves!(u32x8, u32x16);
+impl_bitmask_halves!(i32x8, i32x16);
+impl_bitmask_halves!(f32x8, f32x16);
+impl_bitmask_halves!(u64x4, u64x8);
+impl_bitmask_halves!(i64x4, i64x8);
+impl_bitmask_halves!(f64x4, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::intrin::bitmask::*;
+    use crate::arch::current::vecs::*;
+
+    test_packed_bitmask!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2),
+                         (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                         (bitmask_u8x16, bitmask_i8x16, bitmask_u16x8, bitmask_i16x8, bitmask_u32x4, bitmask_i32x4, bitmask_f32x4, bitmask_u64x2, bitmask_i64x2, bitmask_f64x2));
+    test_packed_bitmask!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4),
+                         (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                         (bitmask_u8x32, bitmask_i8x32, bitmask_u16x16, bitmask_i16x16, bitmask_u32x8, bitmask_i32x8, bitmask_f32x8, bitmask_u64x4, bitmask_i64x4, bitmask_f64x4));
+    test_packed_bitmask!((u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+                         (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                         (bitmask_u8x64, bitmask_i8x64, bitmask_u16x32, bitmask_i16x32, bitmask_u32x16, bitmask_i32x16, bitmask_f32x16, bitmask_u64x8, bitmask_i64x8, bitmask_f64x8));
+
+    #[test]
+    fn bitmask_reads_sign_bits() {
+        assert_eq!(i32x4::new(-1, 0, -5, 7).bitmask(), 0b0101);
+        assert_eq!(f64x2::new(1.0, -0.0).bitmask(), 0b10);
+        assert_eq!(i16x8::new(-1, 1, 0x7FFF, -0x8000, 0, 0, 0, -2).bitmask(), 0b1000_1001);
+    }
+}
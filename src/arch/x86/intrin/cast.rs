@@ -1,60 +1,464 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
 
-// impl_cast!(Asu8s, i8x16, u8x16, as_u8s, as_u8x16);
-// impl_cast!(Asi8s, u8x16, i8x16, as_i8s, as_i8x16);
-
-// impl_cast!(Asu8s, i8x32, u8x32, as_u8s, as_u8x32);
-// impl_cast!(Asi8s, u8x32, i8x32, as_i8s, as_i8x32);
-
-// impl_cast!(Asu8s, i8x64, u8x64, as_u8s, as_u8x64);
-// impl_cast!(Asi8s, u8x64, i8x64, as_i8s, as_i8x64);
-
-// impl_cast!(Asu16s, i16x8, u16x8, as_u16s, as_u16x8);
-// impl_cast!(Asi16s, u16x8, i16x8, as_i16s, as_i16x8);
-
-// impl_cast!(Asu16s, i16x16, u16x16, as_u16s, as_u16x16);
-// impl_cast!(Asi16s, u16x16, i16x16, as_i16s, as_i16x16);
-
-// impl_cast!(Asu16s, i16x32, u16x32, as_u16s, as_u16x32);
-// impl_cast!(Asi16s, u16x32, i16x32, as_i16s, as_i16x32);
-
-// impl_cast!(Asu32s, i32x4, u32x4, as_u32s, as_u32x4);
-// impl_cast!(Asu32s, f32x4, u32x4, as_u32s, as_u32x4);
-// impl_cast!(Asi32s, f32x4, i32x4, as_i32s, as_i32x4);
-// impl_cast!(Asi32s, u32x4, i32x4, as_i32s, as_i32x4);
-// impl_cast!(Asf32s, u32x4, f32x4, as_f32s, as_f32x4);
-// impl_cast!(Asf32s, i32x4, f32x4, as_f32s, as_f32x4);
-
-// impl_cast!(Asu32s, i32x8, u32x8, as_u32s, as_u32x8);
-// impl_cast!(Asu32s, f32x8, u32x8, as_u32s, as_u32x8);
-// impl_cast!(Asi32s, f32x8, i32x8, as_i32s, as_i32x8);
-// impl_cast!(Asi32s, u32x8, i32x8, as_i32s, as_i32x8);
-// impl_cast!(Asf32s, u32x8, f32x8, as_f32s, as_f32x8);
-// impl_cast!(Asf32s, i32x8, f32x8, as_f32s, as_f32x8);
-
-// impl_cast!(Asu32s, i32x16, u32x16, as_u32s, as_u32x16);
-// impl_cast!(Asu32s, f32x16, u32x16, as_u32s, as_u32x16);
-// impl_cast!(Asi32s, f32x16, i32x16, as_i32s, as_i32x16);
-// impl_cast!(Asi32s, u32x16, i32x16, as_i32s, as_i32x16);
-// impl_cast!(Asf32s, u32x16, f32x16, as_f32s, as_f32x16);
-// impl_cast!(Asf32s, i32x16, f32x16, as_f32s, as_f32x16);
-
-// impl_cast!(Asu64s, i64x2, u64x2, as_u64s, as_u64x2);
-// impl_cast!(Asu64s, f64x2, u64x2, as_u64s, as_u64x2);
-// impl_cast!(Asi64s, f64x2, i64x2, as_i64s, as_i64x2);
-// impl_cast!(Asi64s, u64x2, i64x2, as_i64s, as_i64x2);
-// impl_cast!(Asf64s, u64x2, f64x2, as_f64s, as_f64x2);
-// impl_cast!(Asf64s, i64x2, f64x2, as_f64s, as_f64x2);
-
-// impl_cast!(Asu64s, i64x4, u64x4, as_u64s, as_u64x4);
-// impl_cast!(Asu64s, f64x4, u64x4, as_u64s, as_u64x4);
-// impl_cast!(Asi64s, f64x4, i64x4, as_i64s, as_i64x4);
-// impl_cast!(Asi64s, u64x4, i64x4, as_i64s, as_i64x4);
-// impl_cast!(Asf64s, u64x4, f64x4, as_f64s, as_f64x4);
-// impl_cast!(Asf64s, i64x4, f64x4, as_f64s, as_f64x4);
-
-// impl_cast!(Asu64s, i64x8, u64x8, as_u64s, as_u64x8);
-// impl_cast!(Asu64s, f64x8, u64x8, as_u64s, as_u64x8);
-// impl_cast!(Asi64s, f64x8, i64x8, as_i64s, as_i64x8);
-// impl_cast!(Asi64s, u64x8, i64x8, as_i64s, as_i64x8);
-// impl_cast!(Asf64s, u64x8, f64x8, as_f64s, as_f64x8);
-// impl_cast!(Asf64s, i64x8, f64x8, as_f64s, as_f64x8);
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::cast::*;
+use crate::intrin::eq::*;
+use crate::intrin::ord::*;
+use crate::intrin::select::*;
+use crate::intrin::shift::*;
+use crate::intrin::transmute::*;
+use crate::std::mem::transmute;
+
+// Integers of the same width share their bits. The sign bit of each element
+// is smeared over it to find the ones which are out of range.
+macro_rules! impl_cast_int {
+    (signed, $trait:ident, $as:ident, $sat:ident, $($from:ty, $to:ty, $be:ident, $bits:expr),*) => {
+        $(
+            impl $trait for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn $as(self) -> Self::Cast {
+                    self.$be()
+                }
+
+                #[inline(always)]
+                fn $sat(self) -> Self::Cast {
+                    let x = self.$be();
+                    let over = x.shift_right_arithmetic($bits - 1);
+                    (x & !over) | over.shift_right_logical(1)
+                }
+            }
+        )*
+    };
+    (unsigned, $trait:ident, $as:ident, $sat:ident, $($from:ty, $to:ty, $be:ident, $bits:expr),*) => {
+        $(
+            impl $trait for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn $as(self) -> Self::Cast {
+                    self.$be()
+                }
+
+                #[inline(always)]
+                fn $sat(self) -> Self::Cast {
+                    (self & !self.shift_right_arithmetic($bits - 1)).$be()
+                }
+            }
+        )*
+    };
+}
+
+// The conversion instructions return i32::min_value() wherever the result is
+// out of range or NaN, which is only right for large negative elements.
+macro_rules! fix_cvt_i32 {
+    ($x:expr, $r:expr, $fvec:ident) => {{
+        let over = $x.ge_mask($fvec::splat(2147483648.0)).into_inner();
+        let ordered = $x.eq_mask($x).into_inner();
+        (($r.be_u32s() ^ over) & ordered).be_i32s()
+    }}
+}
+
+macro_rules! impl_cast_f32 {
+    ($feat:expr, $cvtt:ident, $cvt:ident, $cvtf:ident, $fvec:ident, $ivec:ident, $uvec:ident) => {
+        impl Asi32s for $fvec {
+            type Cast = $ivec;
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn as_i32s(self) -> Self::Cast {
+                optimized!();
+                fix_cvt_i32!(self, unsafe { transmute::<_, $ivec>($cvtt(transmute(self))) }, $fvec)
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn as_i32s(self) -> Self::Cast {
+                cast_fallback(&self, |x| trunc_scalar!(x, i32, -2147483648.0, 2147483648.0))
+            }
+
+            #[inline(always)]
+            fn saturating_as_i32s(self) -> Self::Cast {
+                self.as_i32s()
+            }
+        }
+
+        // Elements past i32::max_value() are brought into range by
+        // subtracting 2^31, which is exact for them, and their top bit is
+        // set again afterwards.
+        impl Asu32s for $fvec {
+            type Cast = $uvec;
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn as_u32s(self) -> Self::Cast {
+                optimized!();
+                let half = $fvec::splat(2147483648.0);
+                let big = self.ge_mask(half);
+                let low = big.select(self - half, self);
+                let r = unsafe { transmute::<_, $uvec>($cvtt(transmute(low))) } ^ (big.into_inner() & $uvec::splat(0x8000_0000));
+                (r | self.ge_mask($fvec::splat(4294967296.0)).into_inner()) & self.gt_mask($fvec::splat(-1.0)).into_inner()
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn as_u32s(self) -> Self::Cast {
+                cast_fallback(&self, |x| trunc_scalar!(x, u32, 0.0, 4294967296.0))
+            }
+
+            #[inline(always)]
+            fn saturating_as_u32s(self) -> Self::Cast {
+                self.as_u32s()
+            }
+        }
+
+        impl RoundAsInts for $fvec {
+            type Cast = $ivec;
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn round_as_ints(self) -> Self::Cast {
+                optimized!();
+                fix_cvt_i32!(self, unsafe { transmute::<_, $ivec>($cvt(transmute(self))) }, $fvec)
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn round_as_ints(self) -> Self::Cast {
+                cast_fallback(&self, |x| round_scalar!(x, f32, i32, 8388608.0, 0x8000_0000))
+            }
+        }
+
+        impl Asf32s for $ivec {
+            type Cast = $fvec;
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn as_f32s(self) -> Self::Cast {
+                optimized!();
+                unsafe { transmute($cvtf(transmute(self))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn as_f32s(self) -> Self::Cast {
+                cast_fallback(&self, |x| x as f32)
+            }
+        }
+
+        // Each half of an element converts exactly, and scaling the upper
+        // half is exact too, so their sum is rounded once.
+        impl Asf32s for $uvec {
+            type Cast = $fvec;
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn as_f32s(self) -> Self::Cast {
+                optimized!();
+                let hi: $fvec = unsafe { transmute($cvtf(transmute(self.shift_right_logical(16)))) };
+                let lo: $fvec = unsafe { transmute($cvtf(transmute(self & $uvec::splat(0xFFFF)))) };
+                hi * $fvec::splat(65536.0) + lo
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn as_f32s(self) -> Self::Cast {
+                cast_fallback(&self, |x| x as f32)
+            }
+        }
+    }
+}
+
+// Doubles are clamped to the range of i32 before they are converted, which
+// is exact and leaves only NaN to be cleared beforehand. Each conversion
+// fills half of the result, and the halves are joined by $join.
+macro_rules! clamp_i32 {
+    ($x:expr, $fvec:ident) => {
+        $x.eq_mask($x).select($x, $fvec::splat(0.0))
+            .max($fvec::splat(-2147483648.0))
+            .min($fvec::splat(2147483647.0))
+    }
+}
+
+macro_rules! join128 {
+    ($lo:expr, $hi:expr) => { _mm_unpacklo_epi64(transmute($lo), transmute($hi)) }
+}
+
+macro_rules! join256 {
+    ($lo:expr, $hi:expr) => { _mm256_insertf128_si256(_mm256_castsi128_si256(transmute($lo)), transmute($hi), 1) }
+}
+
+macro_rules! impl_downcast_f64 {
+    ($feat:expr, $cvtt:ident, $cvt:ident, $join:ident, $fvec:ident, $ivec:ident) => {
+        impl DowncastAsInts for $fvec {
+            type Cast = $ivec;
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn downcast_as_ints(self, other: Self) -> Self::Cast {
+                optimized!();
+                let (a, b) = (clamp_i32!(self, $fvec), clamp_i32!(other, $fvec));
+                unsafe { transmute($join!($cvtt(transmute(a)), $cvtt(transmute(b)))) }
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn round_downcast_as_ints(self, other: Self) -> Self::Cast {
+                optimized!();
+                let (a, b) = (clamp_i32!(self, $fvec), clamp_i32!(other, $fvec));
+                unsafe { transmute($join!($cvt(transmute(a)), $cvt(transmute(b)))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn downcast_as_ints(self, other: Self) -> Self::Cast {
+                narrow_fallback(&[self, other], |x| trunc_scalar!(x, i32, -2147483648.0, 2147483648.0))
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn round_downcast_as_ints(self, other: Self) -> Self::Cast {
+                narrow_fallback(&[self, other], |x| round_scalar!(x, f64, i32, 4503599627370496.0, 0x8000_0000_0000_0000))
+            }
+        }
+    }
+}
+
+impl_cast_int!(signed, Asi8s, as_i8s, saturating_as_i8s, u8x16, i8x16, be_i8s, 8, u8x32, i8x32, be_i8s, 8);
+impl_cast_int!(unsigned, Asu8s, as_u8s, saturating_as_u8s, i8x16, u8x16, be_u8s, 8, i8x32, u8x32, be_u8s, 8);
+impl_cast_int!(signed, Asi16s, as_i16s, saturating_as_i16s, u16x8, i16x8, be_i16s, 16, u16x16, i16x16, be_i16s, 16);
+impl_cast_int!(unsigned, Asu16s, as_u16s, saturating_as_u16s, i16x8, u16x8, be_u16s, 16, i16x16, u16x16, be_u16s, 16);
+impl_cast_int!(signed, Asi32s, as_i32s, saturating_as_i32s, u32x4, i32x4, be_i32s, 32, u32x8, i32x8, be_i32s, 32);
+impl_cast_int!(unsigned, Asu32s, as_u32s, saturating_as_u32s, i32x4, u32x4, be_u32s, 32, i32x8, u32x8, be_u32s, 32);
+impl_cast_int!(signed, Asi64s, as_i64s, saturating_as_i64s, u64x2, i64x2, be_i64s, 64, u64x4, i64x4, be_i64s, 64);
+impl_cast_int!(unsigned, Asu64s, as_u64s, saturating_as_u64s, i64x2, u64x2, be_u64s, 64, i64x4, u64x4, be_u64s, 64);
+impl_cast_fallback!(int, Asi8s, as_i8s, saturating_as_i8s, u8x64, i8x64, i8);
+impl_cast_fallback!(int, Asu8s, as_u8s, saturating_as_u8s, i8x64, u8x64, u8);
+impl_cast_fallback!(int, Asi16s, as_i16s, saturating_as_i16s, u16x32, i16x32, i16);
+impl_cast_fallback!(int, Asu16s, as_u16s, saturating_as_u16s, i16x32, u16x32, u16);
+impl_cast_fallback!(int, Asi32s, as_i32s, saturating_as_i32s, u32x16, i32x16, i32);
+impl_cast_fallback!(int, Asu32s, as_u32s, saturating_as_u32s, i32x16, u32x16, u32);
+impl_cast_fallback!(int, Asi64s, as_i64s, saturating_as_i64s, u64x8, i64x8, i64);
+impl_cast_fallback!(int, Asu64s, as_u64s, saturating_as_u64s, i64x8, u64x8, u64);
+
+impl_cast_f32!("sse2", _mm_cvttps_epi32, _mm_cvtps_epi32, _mm_cvtepi32_ps, f32x4, i32x4, u32x4);
+impl_cast_f32!("avx", _mm256_cvttps_epi32, _mm256_cvtps_epi32, _mm256_cvtepi32_ps, f32x8, i32x8, u32x8);
+impl_cast_fallback!(trunc, Asi32s, as_i32s, saturating_as_i32s, f32x16, i32x16, i32, -2147483648.0, 2147483648.0);
+impl_cast_fallback!(trunc, Asu32s, as_u32s, saturating_as_u32s, f32x16, u32x16, u32, 0.0, 4294967296.0);
+impl_cast_fallback!(round, f32x16, i32x16, f32, i32, 8388608.0, 0x8000_0000);
+impl_cast_fallback!(float, Asf32s, as_f32s, i32x16, f32x16, f32, u32x16, f32x16, f32);
+
+impl_downcast_f64!("sse2", _mm_cvttpd_epi32, _mm_cvtpd_epi32, join128, f64x2, i32x4);
+impl_downcast_f64!("avx", _mm256_cvttpd_epi32, _mm256_cvtpd_epi32, join256, f64x4, i32x8);
+impl_cross_cast_fallback!(f64_i32, f64x8, i32x16);
+
+// There are no conversions between 64-bit integers and doubles before
+// AVX-512.
+impl_cast_fallback!(trunc, Asi64s, as_i64s, saturating_as_i64s,
+                    f64x2, i64x2, i64, -9223372036854775808.0, 9223372036854775808.0,
+                    f64x4, i64x4, i64, -9223372036854775808.0, 9223372036854775808.0,
+                    f64x8, i64x8, i64, -9223372036854775808.0, 9223372036854775808.0);
+impl_cast_fallback!(trunc, Asu64s, as_u64s, saturating_as_u64s,
+                    f64x2, u64x2, u64, 0.0, 18446744073709551616.0,
+                    f64x4, u64x4, u64, 0.0, 18446744073709551616.0,
+                    f64x8, u64x8, u64, 0.0, 18446744073709551616.0);
+impl_cast_fallback!(round, f64x2, i64x2, f64, i64, 4503599627370496.0, 0x8000_0000_0000_0000,
+                    f64x4, i64x4, f64, i64, 4503599627370496.0, 0x8000_0000_0000_0000,
+                    f64x8, i64x8, f64, i64, 4503599627370496.0, 0x8000_0000_0000_0000);
+impl_cast_fallback!(float, Asf64s, as_f64s, i64x2, f64x2, f64, u64x2, f64x2, f64, i64x4, f64x4, f64,
+                    u64x4, f64x4, f64, i64x8, f64x8, f64, u64x8, f64x8, f64);
+
+// Nor between 64-bit integers and floats.
+impl_cross_cast_fallback!(f32_i64, f32x4, i64x2, f32x8, i64x4, f32x16, i64x8);
+impl_cross_cast_fallback!(i64_f32, i64x2, f32x4, i64x4, f32x8, i64x8, f32x16);
+
+// Small integers are extended to i32s, which convert exactly.
+impl UpcastAsFloats for i16x8 {
+    type Cast = f32x4;
+
+    #[inline(always)]
+    #[cfg(target_feature = "sse4.1")]
+    fn upcast_as_floats(self) -> (f32x4, f32x4) {
+        optimized!();
+        unsafe {
+            (transmute(_mm_cvtepi32_ps(transmute(_mm_cvtepi16_epi32(transmute(self))))),
+             transmute(_mm_cvtepi32_ps(transmute(_mm_cvtepi16_epi32(transmute(_mm_srli_si128(transmute(self), 8)))))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "sse4.1"))]
+    fn upcast_as_floats(self) -> (f32x4, f32x4) {
+        (widen_fallback(&self, 0, |x| x as f32), widen_fallback(&self, 1, |x| x as f32))
+    }
+}
+
+impl UpcastAsFloats for i16x16 {
+    type Cast = f32x8;
+
+    #[inline(always)]
+    #[cfg(target_feature = "avx2")]
+    fn upcast_as_floats(self) -> (f32x8, f32x8) {
+        optimized!();
+        unsafe {
+            (transmute(_mm256_cvtepi32_ps(transmute(_mm256_cvtepi16_epi32(transmute(_mm256_castsi256_si128(transmute(self))))))),
+             transmute(_mm256_cvtepi32_ps(transmute(_mm256_cvtepi16_epi32(transmute(_mm256_extracti128_si256(transmute(self), 1)))))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx2"))]
+    fn upcast_as_floats(self) -> (f32x8, f32x8) {
+        (widen_fallback(&self, 0, |x| x as f32), widen_fallback(&self, 1, |x| x as f32))
+    }
+}
+
+impl QuadUpcastAsFloats for u8x16 {
+    type Cast = f32x4;
+
+    #[inline(always)]
+    #[cfg(target_feature = "sse4.1")]
+    fn quad_upcast_as_floats(self) -> (f32x4, f32x4, f32x4, f32x4) {
+        optimized!();
+        unsafe {
+            (transmute(_mm_cvtepi32_ps(transmute(_mm_cvtepu8_epi32(transmute(self))))),
+             transmute(_mm_cvtepi32_ps(transmute(_mm_cvtepu8_epi32(transmute(_mm_srli_si128(transmute(self), 4)))))),
+             transmute(_mm_cvtepi32_ps(transmute(_mm_cvtepu8_epi32(transmute(_mm_srli_si128(transmute(self), 8)))))),
+             transmute(_mm_cvtepi32_ps(transmute(_mm_cvtepu8_epi32(transmute(_mm_srli_si128(transmute(self), 12)))))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "sse4.1"))]
+    fn quad_upcast_as_floats(self) -> (f32x4, f32x4, f32x4, f32x4) {
+        (widen_fallback(&self, 0, |x| x as f32), widen_fallback(&self, 1, |x| x as f32),
+         widen_fallback(&self, 2, |x| x as f32), widen_fallback(&self, 3, |x| x as f32))
+    }
+}
+
+impl QuadUpcastAsFloats for u8x32 {
+    type Cast = f32x8;
+
+    #[inline(always)]
+    #[cfg(target_feature = "avx2")]
+    fn quad_upcast_as_floats(self) -> (f32x8, f32x8, f32x8, f32x8) {
+        optimized!();
+        unsafe {
+            let lo: u8x16 = transmute(_mm256_castsi256_si128(transmute(self)));
+            let hi: u8x16 = transmute(_mm256_extracti128_si256(transmute(self), 1));
+            (transmute(_mm256_cvtepi32_ps(transmute(_mm256_cvtepu8_epi32(transmute(lo))))),
+             transmute(_mm256_cvtepi32_ps(transmute(_mm256_cvtepu8_epi32(transmute(_mm_srli_si128(transmute(lo), 8)))))),
+             transmute(_mm256_cvtepi32_ps(transmute(_mm256_cvtepu8_epi32(transmute(hi))))),
+             transmute(_mm256_cvtepi32_ps(transmute(_mm256_cvtepu8_epi32(transmute(_mm_srli_si128(transmute(hi), 8)))))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx2"))]
+    fn quad_upcast_as_floats(self) -> (f32x8, f32x8, f32x8, f32x8) {
+        (widen_fallback(&self, 0, |x| x as f32), widen_fallback(&self, 1, |x| x as f32),
+         widen_fallback(&self, 2, |x| x as f32), widen_fallback(&self, 3, |x| x as f32))
+    }
+}
+
+impl_cross_cast_fallback!(i16_f32, i16x32, f32x16);
+impl_cross_cast_fallback!(u8_f32, u8x64, f32x16);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_cast!((u8x16, u8x32, u8x64), (as_i8s_u8x16, as_i8s_u8x32, as_i8s_u8x64), as_i8s,
+               [(0u8, 0i8), (127, 127), (128, -128), (255, -1)]);
+    test_cast!((u8x16, u8x32, u8x64), (sat_i8s_u8x16, sat_i8s_u8x32, sat_i8s_u8x64), saturating_as_i8s,
+               [(0u8, 0i8), (127, 127), (128, 127), (255, 127)]);
+    test_cast!((i8x16, i8x32), (sat_u8s_i8x16, sat_u8s_i8x32), saturating_as_u8s,
+               [(0i8, 0u8), (127, 127), (-128, 0), (-1, 0)]);
+    test_cast!((u16x8, u16x16), (sat_i16s_u16x8, sat_i16s_u16x16), saturating_as_i16s,
+               [(0u16, 0i16), (0x7FFF, 0x7FFF), (0x8000, 0x7FFF), (0xFFFF, 0x7FFF)]);
+    test_cast!((i16x8, i16x16), (as_u16s_i16x8, as_u16s_i16x16), as_u16s,
+               [(0i16, 0u16), (-1, 0xFFFF), (-32768, 0x8000)]);
+    test_cast!((i32x4, i32x8, i32x16), (sat_u32s_i32x4, sat_u32s_i32x8, sat_u32s_i32x16), saturating_as_u32s,
+               [(5i32, 5u32), (-1, 0), (i32::min_value(), 0), (i32::max_value(), 0x7FFF_FFFF)]);
+    test_cast!((u64x2, u64x4), (sat_i64s_u64x2, sat_i64s_u64x4), saturating_as_i64s,
+               [(5u64, 5i64), (u64::max_value(), i64::max_value()), (1 << 63, i64::max_value())]);
+    test_cast!((i64x2, i64x4), (sat_u64s_i64x2, sat_u64s_i64x4), saturating_as_u64s,
+               [(5i64, 5u64), (-1, 0), (i64::min_value(), 0)]);
+
+    test_cast!((f32x4, f32x8, f32x16), (as_i32s_f32x4, as_i32s_f32x8, as_i32s_f32x16), as_i32s,
+               [(1.5f32, 1i32), (-1.5, -1), (0.99, 0), (-0.0, 0), (2147483520.0, 2147483520), (2147483648.0, i32::max_value()),
+                (-2147483648.0, i32::min_value()), (-3e9, i32::min_value()), (1.0 / 0.0, i32::max_value()),
+                (-1.0 / 0.0, i32::min_value()), (0.0 / 0.0, 0)]);
+    test_cast!((f32x4, f32x8, f32x16), (as_u32s_f32x4, as_u32s_f32x8, as_u32s_f32x16), as_u32s,
+               [(1.5f32, 1u32), (-0.5, 0), (-1.0, 0), (-3e9, 0), (2147483648.0, 0x8000_0000), (4294967040.0, 4294967040),
+                (4294967296.0, u32::max_value()), (1.0 / 0.0, u32::max_value()), (0.0 / 0.0, 0)]);
+    test_cast!((f32x4, f32x8, f32x16), (round_f32x4, round_f32x8, round_f32x16), round_as_ints,
+               [(1.5f32, 2i32), (2.5, 2), (-2.5, -2), (-2.7, -3), (0.49999997, 0), (8388609.0, 8388609),
+                (3e9, i32::max_value()), (-3e9, i32::min_value()), (0.0 / 0.0, 0)]);
+    test_cast!((i32x4, i32x8, i32x16), (as_f32s_i32x4, as_f32s_i32x8, as_f32s_i32x16), as_f32s,
+               [(-3i32, -3.0f32), (16777217, 16777216.0), (i32::min_value(), -2147483648.0)]);
+    test_cast!((u32x4, u32x8, u32x16), (as_f32s_u32x4, as_f32s_u32x8, as_f32s_u32x16), as_f32s,
+               [(3u32, 3.0f32), (0x8000_0081, 2147483904.0), (0x8000_0080, 2147483648.0), (u32::max_value(), 4294967296.0)]);
+
+    test_cast!((f64x2, f64x4), (as_i64s_f64x2, as_i64s_f64x4), as_i64s,
+               [(-1.5f64, -1i64), (1e19, i64::max_value()), (-1e19, i64::min_value()), (0.0 / 0.0, 0)]);
+    test_cast!((f64x2, f64x4), (as_u64s_f64x2, as_u64s_f64x4), as_u64s,
+               [(1.5f64, 1u64), (-7.0, 0), (1e19, 10_000_000_000_000_000_000), (1e20, u64::max_value())]);
+    test_cast!((f64x2, f64x4), (round_f64x2, round_f64x4), round_as_ints,
+               [(0.5f64, 0i64), (1.5, 2), (-1e300, i64::min_value()), (0.0 / 0.0, 0)]);
+    test_cast!((u64x2, u64x4), (as_f64s_u64x2, as_f64s_u64x4), as_f64s,
+               [(3u64, 3.0f64), (u64::max_value(), 18446744073709551616.0)]);
+
+    test_cross_cast!((f64x2, i32x4, f32x4, i64x2, i16x8, u8x16, i8x16, f64x4, i32x8, f32x8, i64x4, i16x16, u8x32, i8x32,
+                      f64x8, i32x16, f32x16, i64x8, i16x32, u8x64, i8x64),
+                     (cross_cast_128, cross_cast_256, cross_cast_512));
+
+    // The direct conversions agree with compositions of casts wherever those
+    // are exact
+    #[test]
+    fn cross_width_compositions() {
+        let (a, b) = (f64x4::new(-2.5, 1e10, -0.5, 7.5), f64x4::new(0.0 / 0.0, 3.5, -3e9, 1e-300));
+        let (trunc, round): (i32x8, i32x8) = (a.as_i64s().saturating_downcast(b.as_i64s()),
+                                              a.round_as_ints().saturating_downcast(b.round_as_ints()));
+        assert_eq!(a.downcast_as_ints(b), trunc);
+        assert_eq!(a.round_downcast_as_ints(b), round);
+
+        let x = f32x8::new(-2.5, 1e19, 3.5, -1e30, 0.5, -7.5, 0.0 / 0.0, 16777216.0);
+        let (lo, hi): (f64x4, f64x4) = x.upcast();
+        assert_eq!(x.upcast_as_ints(), (lo.as_i64s(), hi.as_i64s()));
+        assert_eq!(x.round_upcast_as_ints(), (lo.round_as_ints(), hi.round_as_ints()));
+
+        let (a, b) = (i64x2::new(-3, 1 << 40), i64x2::new(0, -(1 << 52)));
+        let floats: f32x4 = a.as_f64s().saturating_downcast(b.as_f64s());
+        assert_eq!(a.downcast_as_floats(b), floats);
+
+        let words = [i32x4::new(-200, 100, 300, -1), i32x4::new(-70000, 70000, 0, 5),
+                     i32x4::new(128, -129, 255, 256), i32x4::new(i32::min_value(), i32::max_value(), 65536, -65536)];
+        let (ab, cd): (i16x8, i16x8) = (words[0].saturating_downcast(words[1]), words[2].saturating_downcast(words[3]));
+        let signed: i8x16 = ab.saturating_downcast(cd);
+        let unsigned: u8x16 = ab.saturating_as_u16s().saturating_downcast(cd.saturating_as_u16s());
+        let (s, u): (i8x16, u8x16) = (words[0].saturating_quad_downcast(words[1], words[2], words[3]),
+                                      words[0].saturating_quad_downcast(words[1], words[2], words[3]));
+        assert_eq!((s, u), (signed, unsigned));
+
+        let shorts = i16x16::splat(-32768).replace(2, 12).replace(9, 32767);
+        let (lo, hi): (i32x8, i32x8) = shorts.upcast();
+        assert_eq!(shorts.upcast_as_floats(), (lo.as_f32s(), hi.as_f32s()));
+
+        let bytes = u8x16::splat(255).replace(1, 7).replace(14, 128);
+        let (lo, hi): (u16x8, u16x8) = bytes.upcast();
+        let (q0, q1): (u32x4, u32x4) = lo.upcast();
+        let (q2, q3): (u32x4, u32x4) = hi.upcast();
+        assert_eq!(bytes.quad_upcast_as_floats(), (q0.as_f32s(), q1.as_f32s(), q2.as_f32s(), q3.as_f32s()));
+    }
+}
@@ -126,3 +126,6 @@ rust_fallback_impl_binary! {
         max => _mm256_max_pd(), [0, 1, 2, 3];
     }
 }
+
+impl_cmp_fallback!(u64x2, i64x2, u64x4, i64x4);
+impl_cmp_fallback!(u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8);
@@ -0,0 +1,81 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::intrin::divide::*;
+use crate::intrin::mul_hi::*;
+use crate::intrin::shift::*;
+
+// The quotients follow the same steps as `DivideLane`, with the high
+// multiply done on whole vectors.
+macro_rules! impl_divide_by {
+    (unsigned, $($vec:ty, $el:ty),*) => {
+        $(
+            impl DivideBy for $vec {
+                #[inline(always)]
+                fn div_by(&self, divider: &Divider<$el>) -> Self {
+                    let t = self.mul_hi(Self::splat(divider.magic));
+                    ((*self - t).shift_right_logical(divider.shift1) + t).shift_right_logical(divider.shift2)
+                }
+
+                #[inline(always)]
+                fn rem_by(&self, divider: &Divider<$el>) -> Self {
+                    *self - self.div_by(divider) * Self::splat(divider.divisor)
+                }
+
+                #[inline(always)]
+                fn div_exact_by(&self, divider: &Divider<$el>) -> Self {
+                    self.shift_right_logical(divider.trailing) * Self::splat(divider.inverse)
+                }
+            }
+        )*
+    };
+    (signed, $($vec:ty, $el:ty),*) => {
+        $(
+            impl DivideBy for $vec {
+                #[inline(always)]
+                fn div_by(&self, divider: &Divider<$el>) -> Self {
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                    let sign = Self::splat(divider.sign);
+                    let q = (*self + self.mul_hi(Self::splat(divider.magic))).shift_right_arithmetic(divider.shift2)
+                        - self.shift_right_arithmetic(bits - 1);
+                    (q ^ sign) - sign
+                }
+
+                #[inline(always)]
+                fn rem_by(&self, divider: &Divider<$el>) -> Self {
+                    *self - self.div_by(divider) * Self::splat(divider.divisor)
+                }
+
+                #[inline(always)]
+                fn div_exact_by(&self, divider: &Divider<$el>) -> Self {
+                    self.shift_right_arithmetic(divider.trailing) * Self::splat(divider.inverse)
+                }
+            }
+        )*
+    };
+}
+
+impl_divide_by!(unsigned, u16x8, u16, u32x4, u32, u16x16, u16, u32x8, u32);
+impl_divide_by!(signed, i16x8, i16, i32x4, i32, i16x16, i16, i32x8, i32);
+impl_divide_by_fallback!(u64x2, u64, i64x2, i64, u64x4, u64, i64x4, i64,
+                         u16x32, u16, i16x32, i16, u32x16, u32, i32x16, i32, u64x8, u64, i64x8, i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_divide_by!((u16x8, i16x8, u32x4, i32x4, u64x2, i64x2),
+                    (u16, i16, u32, i32, u64, i64),
+                    (divide_u16x8, divide_i16x8, divide_u32x4, divide_i32x4, divide_u64x2, divide_i64x2));
+    test_divide_by!((u16x16, i16x16, u32x8, i32x8, u64x4, i64x4),
+                    (u16, i16, u32, i32, u64, i64),
+                    (divide_u16x16, divide_i16x16, divide_u32x8, divide_i32x8, divide_u64x4, divide_i64x4));
+    test_divide_by!((i32x16), (i32), (divide_i32x16));
+}
@@ -9,6 +9,7 @@ use crate::arch::current::vecs::*;
 use crate::vecs::*;
 use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
+use crate::intrin::cast::*;
 use crate::intrin::downcast::*;
 use crate::intrin::transmute::*;
 use crate::std::mem::transmute;
@@ -154,12 +155,16 @@ impl Downcast<u8x16> for u16x8 {
     }
 }
 
+// The 256-bit packs work within each 128-bit lane, which leaves the quarters
+// of the result ordered as self, other, self, other. They are put back in
+// order by swapping the middle two.
+
 impl Downcast<i16x16> for i32x8 {
     #[inline(always)]
     #[cfg(target_feature = "avx2")]
     fn saturating_downcast(self, other: Self) -> i16x16 {
         optimized!();
-        unsafe { _mm256_packs_epi32(self, other) }
+        unsafe { _mm256_permute4x64_epi64(_mm256_packs_epi32(self, other).be_i64s(), 0xD8).be_i16s() }
     }
 
     #[inline(always)]
@@ -190,7 +195,7 @@ impl Downcast<i8x32> for i16x16 {
     #[cfg(target_feature = "avx2")]
     fn saturating_downcast(self, other: Self) -> i8x32 {
         optimized!();
-        unsafe { _mm256_packs_epi16(self.be_i16s(), other) }
+        unsafe { _mm256_permute4x64_epi64(_mm256_packs_epi16(self.be_i16s(), other).be_i64s(), 0xD8).be_i8s() }
     }
 
     #[inline(always)]
@@ -237,7 +242,7 @@ impl Downcast<u16x16> for u32x8 {
     #[cfg(target_feature = "avx2")]
     fn saturating_downcast(self, other: Self) -> u16x16 {
         optimized!();
-        unsafe { transmute(_mm256_packus_epi32(transmute(self), transmute(other))) }
+        unsafe { transmute(_mm256_permute4x64_epi64(transmute(_mm256_packus_epi32(transmute(self), transmute(other))), 0xD8)) }
     }
 
     #[inline(always)]
@@ -268,7 +273,7 @@ impl Downcast<u8x32> for u16x16 {
     #[cfg(target_feature = "avx2")]
     fn saturating_downcast(self, other: Self) -> u8x32 {
         optimized!();
-        unsafe { _mm256_packus_epi16(self.be_i16s(), other.be_i16s()).be_u8s() }
+        unsafe { _mm256_permute4x64_epi64(_mm256_packus_epi16(self.be_i16s(), other.be_i16s()).be_i64s(), 0xD8).be_u8s() }
     }
 
     #[inline(always)]
@@ -354,3 +359,68 @@ impl Downcast<f32x8> for f64x4 {
                    other.extract(3) as f32)
     }
 }
+
+// Both packs saturate, and the bounds of the second are within those of the
+// first, so every element saturates as if it were packed once.
+macro_rules! impl_quad_downcast {
+    ($($to:ident, $tel:ident, $pack:ident),*) => {
+        $(
+            impl QuadDowncast<$to> for i32x4 {
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn saturating_quad_downcast(self, b: Self, c: Self, d: Self) -> $to {
+                    optimized!();
+                    unsafe { transmute($pack(transmute(_mm_packs_epi32(self, b)), transmute(_mm_packs_epi32(c, d)))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn saturating_quad_downcast(self, b: Self, c: Self, d: Self) -> $to {
+                    narrow_fallback(&[self, b, c, d], |x| x.min($tel::max_value() as i32).max($tel::min_value() as i32) as $tel)
+                }
+            }
+        )*
+    }
+}
+
+// The packs work within each 128-bit lane, which leaves the quarters of each
+// vector in alternating lanes.
+macro_rules! impl_quad_downcast256 {
+    ($($to:ident, $tel:ident, $pack:ident),*) => {
+        $(
+            impl QuadDowncast<$to> for i32x8 {
+                #[inline(always)]
+                #[cfg(target_feature = "avx2")]
+                fn saturating_quad_downcast(self, b: Self, c: Self, d: Self) -> $to {
+                    optimized!();
+                    unsafe {
+                        let packed = $pack(transmute(_mm256_packs_epi32(self, b)), transmute(_mm256_packs_epi32(c, d)));
+                        transmute(_mm256_permutevar8x32_epi32(transmute(packed), transmute(i32x8::new(0, 4, 1, 5, 2, 6, 3, 7))))
+                    }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "avx2"))]
+                fn saturating_quad_downcast(self, b: Self, c: Self, d: Self) -> $to {
+                    narrow_fallback(&[self, b, c, d], |x| x.min($tel::max_value() as i32).max($tel::min_value() as i32) as $tel)
+                }
+            }
+        )*
+    }
+}
+
+impl_quad_downcast!(i8x16, i8, _mm_packs_epi16, u8x16, u8, _mm_packus_epi16);
+impl_quad_downcast256!(i8x32, i8, _mm256_packs_epi16, u8x32, u8, _mm256_packus_epi16);
+impl_cross_cast_fallback!(i32_i8, i32x16, i8x64, i8, i32x16, u8x64, u8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_downcast!((i32x4, i16x8, u32x4, u16x8, i32x8, i16x16, u32x8, u16x16),
+                   (i16x8, i8x16, u16x8, u8x16, i16x16, i8x32, u16x16, u8x32),
+                   (i16, i8, u16, u8, i16, i8, u16, u8),
+                   (downcast_i32x4, downcast_i16x8, downcast_u32x4, downcast_u16x8,
+                    downcast_i32x8, downcast_i16x16, downcast_u32x8, downcast_u16x16));
+}
@@ -0,0 +1,109 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::mask::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::eq::*;
+use crate::intrin::float::*;
+use crate::intrin::select::*;
+use crate::intrin::transmute::*;
+use crate::std::mem::transmute;
+
+// Every class is read off the exponent and sign bits, except for NaN, which
+// is the only value unequal to itself.
+macro_rules! impl_classify {
+    ($($vec:ty, $uvec:ident, $to_bits:ident, $from_bits:ident, $sign:expr, $exp:expr, $one:expr),*) => {
+        $(
+            impl Classify for $vec {
+                type Out = Mask<$uvec>;
+
+                #[inline(always)]
+                fn is_nan(&self) -> Self::Out {
+                    self.ne_mask(*self)
+                }
+
+                #[inline(always)]
+                fn is_infinite(&self) -> Self::Out {
+                    (self.$to_bits() & $uvec::splat(!$sign)).eq_mask($uvec::splat($exp))
+                }
+
+                #[inline(always)]
+                fn is_finite(&self) -> Self::Out {
+                    (self.$to_bits() & $uvec::splat($exp)).ne_mask($uvec::splat($exp))
+                }
+
+                #[inline(always)]
+                fn is_sign_negative(&self) -> Self::Out {
+                    (self.$to_bits() & $uvec::splat($sign)).eq_mask($uvec::splat($sign))
+                }
+            }
+
+            impl CopySign for $vec {
+                #[inline(always)]
+                fn copysign(&self, sign: Self) -> Self {
+                    // Every bit pattern is a valid float
+                    unsafe {
+                        ((self.$to_bits() & $uvec::splat(!$sign)) | (sign.$to_bits() & $uvec::splat($sign))).$from_bits()
+                    }
+                }
+
+                #[inline(always)]
+                fn signum(&self) -> Self {
+                    let one = unsafe { ($uvec::splat($one) | (self.$to_bits() & $uvec::splat($sign))).$from_bits() };
+                    self.is_nan().select(*self, one)
+                }
+            }
+        )*
+    }
+}
+
+// The maximum and minimum instructions return their second argument if
+// either is NaN, so a NaN in `self` is carried through both.
+macro_rules! impl_clamp {
+    ($feat:expr, $min:ident, $max:ident, $($vec:ty),*) => {
+        $(
+            impl Clamp for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn clamp(&self, min: Self, max: Self) -> Self {
+                    optimized!();
+                    unsafe { transmute($min(transmute(max), $max(transmute(min), transmute(*self)))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn clamp(&self, min: Self, max: Self) -> Self {
+                    float_fallback(self, &min, &max, |x, lo, hi| if x < lo { lo } else if x > hi { hi } else { x })
+                }
+            }
+        )*
+    }
+}
+
+impl_classify!(f32x4, u32x4, be_u32s, be_f32s_unchecked, 0x8000_0000, 0x7F80_0000, 0x3F80_0000,
+               f64x2, u64x2, be_u64s, be_f64s_unchecked, 0x8000_0000_0000_0000, 0x7FF0_0000_0000_0000, 0x3FF0_0000_0000_0000,
+               f32x8, u32x8, be_u32s, be_f32s_unchecked, 0x8000_0000, 0x7F80_0000, 0x3F80_0000,
+               f64x4, u64x4, be_u64s, be_f64s_unchecked, 0x8000_0000_0000_0000, 0x7FF0_0000_0000_0000, 0x3FF0_0000_0000_0000);
+impl_classify_fallback!(f32x16, f32, u32x16, 0x8000_0000, f64x8, f64, u64x8, 0x8000_0000_0000_0000);
+
+impl_clamp!("sse", _mm_min_ps, _mm_max_ps, f32x4);
+impl_clamp!("sse2", _mm_min_pd, _mm_max_pd, f64x2);
+impl_clamp!("avx", _mm256_min_ps, _mm256_max_ps, f32x8);
+impl_clamp!("avx", _mm256_min_pd, _mm256_max_pd, f64x4);
+impl_clamp_fallback!(f32x16, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_float_classify!((f32x4, f64x2, f32x8, f64x4, f32x16, f64x8), (f32, f64, f32, f64, f32, f64),
+                         (classify_f32x4, classify_f64x2, classify_f32x8, classify_f64x4, classify_f32x16, classify_f64x8));
+}
@@ -0,0 +1,154 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::half::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::half::*;
+use crate::std::mem::transmute;
+
+// The conversions take their rounding mode as an immediate, of which 0 rounds
+// to nearest even.
+
+impl LoadF16 for f32x4 {
+    #[inline(always)]
+    #[cfg(target_feature = "f16c")]
+    fn load_f16(data: &[f16], offset: usize) -> Self {
+        optimized!();
+        let bits = &f16_bits(data)[offset..offset + Self::WIDTH];
+        let halves = u16x8::new(bits[0], bits[1], bits[2], bits[3], 0, 0, 0, 0);
+        unsafe { transmute(_mm_cvtph_ps(transmute(halves))) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "f16c"))]
+    fn load_f16(data: &[f16], offset: usize) -> Self {
+        load_f16_fallback(data, offset)
+    }
+
+    #[inline(always)]
+    #[cfg(target_feature = "f16c")]
+    fn store_f16(self, data: &mut [f16], offset: usize) {
+        optimized!();
+        let bits = &mut f16_bits_mut(data)[offset..offset + Self::WIDTH];
+        let halves: u16x8 = unsafe { transmute(_mm_cvtps_ph(transmute(self), 0)) };
+        for i in 0..Self::WIDTH {
+            bits[i] = halves.extract(i);
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "f16c"))]
+    fn store_f16(self, data: &mut [f16], offset: usize) {
+        store_f16_fallback(&self, data, offset)
+    }
+
+    #[inline(always)]
+    #[cfg(target_feature = "f16c")]
+    unsafe fn load_f16_unchecked(data: &[f16], offset: usize) -> Self {
+        optimized!();
+        debug_assert!(offset + Self::WIDTH <= data.len());
+        let bits = f16_bits(data);
+        let halves = u16x8::new(*bits.get_unchecked(offset), *bits.get_unchecked(offset + 1),
+                                *bits.get_unchecked(offset + 2), *bits.get_unchecked(offset + 3), 0, 0, 0, 0);
+        transmute(_mm_cvtph_ps(transmute(halves)))
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "f16c"))]
+    unsafe fn load_f16_unchecked(data: &[f16], offset: usize) -> Self {
+        load_f16_unchecked_fallback(data, offset)
+    }
+
+    #[inline(always)]
+    #[cfg(target_feature = "f16c")]
+    unsafe fn store_f16_unchecked(self, data: &mut [f16], offset: usize) {
+        optimized!();
+        debug_assert!(offset + Self::WIDTH <= data.len());
+        let bits = f16_bits_mut(data);
+        let halves: u16x8 = transmute(_mm_cvtps_ph(transmute(self), 0));
+        for i in 0..Self::WIDTH {
+            *bits.get_unchecked_mut(offset + i) = halves.extract(i);
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "f16c"))]
+    unsafe fn store_f16_unchecked(self, data: &mut [f16], offset: usize) {
+        store_f16_unchecked_fallback(&self, data, offset)
+    }
+}
+
+impl LoadF16 for f32x8 {
+    #[inline(always)]
+    #[cfg(target_feature = "f16c")]
+    fn load_f16(data: &[f16], offset: usize) -> Self {
+        optimized!();
+        let halves = u16x8::load(f16_bits(data), offset);
+        unsafe { transmute(_mm256_cvtph_ps(transmute(halves))) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "f16c"))]
+    fn load_f16(data: &[f16], offset: usize) -> Self {
+        load_f16_fallback(data, offset)
+    }
+
+    #[inline(always)]
+    #[cfg(target_feature = "f16c")]
+    fn store_f16(self, data: &mut [f16], offset: usize) {
+        optimized!();
+        let halves: u16x8 = unsafe { transmute(_mm256_cvtps_ph(transmute(self), 0)) };
+        halves.store(f16_bits_mut(data), offset);
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "f16c"))]
+    fn store_f16(self, data: &mut [f16], offset: usize) {
+        store_f16_fallback(&self, data, offset)
+    }
+
+    #[inline(always)]
+    #[cfg(target_feature = "f16c")]
+    unsafe fn load_f16_unchecked(data: &[f16], offset: usize) -> Self {
+        optimized!();
+        let halves = u16x8::load_unchecked(f16_bits(data), offset);
+        transmute(_mm256_cvtph_ps(transmute(halves)))
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "f16c"))]
+    unsafe fn load_f16_unchecked(data: &[f16], offset: usize) -> Self {
+        load_f16_unchecked_fallback(data, offset)
+    }
+
+    #[inline(always)]
+    #[cfg(target_feature = "f16c")]
+    unsafe fn store_f16_unchecked(self, data: &mut [f16], offset: usize) {
+        optimized!();
+        let halves: u16x8 = transmute(_mm256_cvtps_ph(transmute(self), 0));
+        halves.store_unchecked(f16_bits_mut(data), offset);
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "f16c"))]
+    unsafe fn store_f16_unchecked(self, data: &mut [f16], offset: usize) {
+        store_f16_unchecked_fallback(&self, data, offset)
+    }
+}
+
+impl_load_f16_fallback!(f32x16);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_load_f16!((f32x4, f32x8, f32x16), (load_f16_f32x4, load_f16_f32x8, load_f16_f32x16));
+}
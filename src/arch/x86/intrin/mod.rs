@@ -8,17 +8,31 @@
 #![allow(unused_imports)]
 
 mod abs;
+mod abs_diff;
 mod addsub;
+mod avg;
+mod argminmax;
+mod bitmask;
+mod bf16;
+mod cast;
 mod cmp;
+mod compact;
 mod destride;
+mod divide;
 mod downcast;
 mod endian;
 mod eq;
+mod float;
+mod half;
 mod hadd;
 mod hsub;
 mod merge;
+mod mul_add;
+mod mul_hi;
+mod ord;
 mod popcnt;
 mod recip;
+mod reduce;
 mod round;
 mod sum;
 mod rsqrt;
@@ -26,25 +40,44 @@ mod saturating_add;
 mod saturating_hadd;
 mod saturating_sub;
 mod saturating_hsub;
+mod scan;
+mod select;
+mod shift;
 mod sqrt;
+mod swizzle;
 mod transmute;
 mod upcast;
+mod widening_mul;
 
 // We use an internal prelude not to clutter the namespace when we import
 // from actual prelude. 
 pub mod prelude {
     pub use super::abs::*;
+    pub use super::abs_diff::*;
     pub use super::addsub::*;
+    pub use super::avg::*;
+    pub use super::argminmax::*;
+    pub use super::bitmask::*;
+    pub use super::bf16::*;
+    pub use super::cast::*;
     pub use super::cmp::*;
+    pub use super::compact::*;
     pub use super::destride::*;
+    pub use super::divide::*;
     pub use super::downcast::*;
     pub use super::endian::*;
     pub use super::eq::*;
+    pub use super::float::*;
+    pub use super::half::*;
     pub use super::hadd::*;
     pub use super::hsub::*;
     pub use super::merge::*;
+    pub use super::mul_add::*;
+    pub use super::mul_hi::*;
+    pub use super::ord::*;
     pub use super::popcnt::*;
     pub use super::recip::*;
+    pub use super::reduce::*;
     pub use super::round::*;
     pub use super::rsqrt::*;
     pub use super::sum::*;
@@ -52,6 +85,11 @@ pub mod prelude {
     pub use super::saturating_hadd::*;
     pub use super::saturating_hsub::*;
     pub use super::saturating_sub::*;
+    pub use super::scan::*;
+    pub use super::select::*;
+    pub use super::shift::*;
+    pub use super::swizzle::*;
     pub use super::transmute::*;
     pub use super::upcast::*;
+    pub use super::widening_mul::*;
 }
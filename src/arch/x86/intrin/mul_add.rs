@@ -0,0 +1,76 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::mul_add::*;
+use crate::std::mem::transmute;
+
+macro_rules! impl_mul_add_fma {
+    ($fmadd:ident, $fmsub:ident, $fnmadd:ident, $($vec:ty),*) => {
+        $(
+            impl MulAdd for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = "fma")]
+                fn mul_add(&self, a: Self, b: Self) -> Self {
+                    optimized!();
+                    unsafe { transmute($fmadd(transmute(*self), transmute(a), transmute(b))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "fma"))]
+                fn mul_add(&self, a: Self, b: Self) -> Self {
+                    mul_add_fallback(self, a, b, |x, a, b| x.fused_mul_add(a, b))
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "fma")]
+                fn mul_sub(&self, a: Self, b: Self) -> Self {
+                    optimized!();
+                    unsafe { transmute($fmsub(transmute(*self), transmute(a), transmute(b))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "fma"))]
+                fn mul_sub(&self, a: Self, b: Self) -> Self {
+                    mul_add_fallback(self, a, b, |x, a, b| x.fused_mul_add(a, -b))
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "fma")]
+                fn neg_mul_add(&self, a: Self, b: Self) -> Self {
+                    optimized!();
+                    unsafe { transmute($fnmadd(transmute(*self), transmute(a), transmute(b))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "fma"))]
+                fn neg_mul_add(&self, a: Self, b: Self) -> Self {
+                    mul_add_fallback(self, a, b, |x, a, b| (-x).fused_mul_add(a, b))
+                }
+            }
+        )*
+    }
+}
+
+impl_mul_add_fma!(_mm_fmadd_ps, _mm_fmsub_ps, _mm_fnmadd_ps, f32x4);
+impl_mul_add_fma!(_mm_fmadd_pd, _mm_fmsub_pd, _mm_fnmadd_pd, f64x2);
+impl_mul_add_fma!(_mm256_fmadd_ps, _mm256_fmsub_ps, _mm256_fnmadd_ps, f32x8);
+impl_mul_add_fma!(_mm256_fmadd_pd, _mm256_fmsub_pd, _mm256_fnmadd_pd, f64x4);
+impl_mul_add_fallback!(f32x16, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_packed_mul_add!((f32x4, f64x2, f32x8, f64x4, f32x16, f64x8),
+                         (f32, f64, f32, f64, f32, f64),
+                         (mul_add_f32x4, mul_add_f64x2, mul_add_f32x8, mul_add_f64x4, mul_add_f32x16, mul_add_f64x8));
+}
@@ -0,0 +1,208 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::mul_hi::*;
+use crate::intrin::shift::*;
+use crate::intrin::transmute::*;
+use crate::std::mem::transmute;
+
+macro_rules! impl_mul_hi {
+    // There is no high multiply on 32-bit lanes, so the even and odd lanes
+    // are each multiplied into 64-bit products, and their high halves are
+    // merged.
+    (@widen $feat:expr, $vec:ty, $el:ty, $wide:ty, $mul:ident) => {
+        impl MulHi for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn mul_hi(&self, other: Self) -> Self {
+                optimized!();
+                let (a, b) = (self.be_u64s(), other.be_u64s());
+                let even = unsafe { transmute::<_, <Self as Transmute>::u64s>($mul(transmute(a), transmute(b))) };
+                let odd = unsafe {
+                    transmute::<_, <Self as Transmute>::u64s>($mul(transmute(a.shift_right_logical(32)),
+                                                                   transmute(b.shift_right_logical(32))))
+                };
+                let hi = <Self as Transmute>::u64s::splat(0xFFFF_FFFF_0000_0000);
+                (even.shift_right_logical(32) | (odd & hi)).be_u32s()
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn mul_hi(&self, other: Self) -> Self {
+                let bits = crate::std::mem::size_of::<$el>() * 8;
+                mul_hi_fallback(self, &other, |a, b| ((a as $wide * b as $wide) >> bits) as $el)
+            }
+        }
+    };
+    ($feat:expr, $vec:ty, $el:ty, $wide:ty, $mulhi:ident) => {
+        impl MulHi for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn mul_hi(&self, other: Self) -> Self {
+                optimized!();
+                unsafe { transmute($mulhi(transmute(*self), transmute(other))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn mul_hi(&self, other: Self) -> Self {
+                let bits = crate::std::mem::size_of::<$el>() * 8;
+                mul_hi_fallback(self, &other, |a, b| ((a as $wide * b as $wide) >> bits) as $el)
+            }
+        }
+    };
+}
+
+// The signed high product is the unsigned one, less each operand wherever
+// the other is negative.
+macro_rules! impl_mul_hi_signed {
+    ($($vec:ty, $uvec:ty),*) => {
+        $(
+            impl MulHi for $vec {
+                #[inline(always)]
+                fn mul_hi(&self, other: Self) -> Self {
+                    let hi: Self = unsafe { transmute(transmute::<_, $uvec>(*self).mul_hi(transmute(other))) };
+                    hi - (self.shift_right_arithmetic(31) & other) - (other.shift_right_arithmetic(31) & *self)
+                }
+            }
+        )*
+    }
+}
+
+// Bytes are extended into the 16-bit lanes they share, where their products
+// can't overflow. The even bytes are moved to the top of their lane and back
+// to extend them, and the odd bytes are just moved down.
+macro_rules! impl_mul_hi_bytes {
+    ($($vec:ty, $wvec:ty, $wel:ty, $shr:ident, $bew:ident, $be:ident),*) => {
+        $(
+            impl MulHi for $vec {
+                #[inline(always)]
+                fn mul_hi(&self, other: Self) -> Self {
+                    let (a, b) = (self.$bew(), other.$bew());
+                    let even = a.shift_left(8).$shr(8) * b.shift_left(8).$shr(8);
+                    let odd = a.$shr(8) * b.$shr(8);
+                    (even.shift_right_logical(8) | (odd & <$wvec>::splat(0xFF00u16 as $wel))).$be()
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_mul_hi_round {
+    ($feat:expr, $($vec:ty, $el:ty, $mulhrs:ident),*) => {
+        $(
+            impl MulHiRound for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn mul_hi_round(&self, other: Self) -> Self {
+                    optimized!();
+                    unsafe { transmute($mulhrs(transmute(*self), transmute(other))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn mul_hi_round(&self, other: Self) -> Self {
+                    mul_hi_fallback(self, &other, |a, b| ((a as i32 * b as i32 + 0x4000) >> 15) as $el)
+                }
+            }
+        )*
+    }
+}
+
+// Scaling the even bytes of one operand up by 256 lets the 16-bit rounding
+// multiply shift their products right by 7 rather than 15, and the odd bytes
+// are already scaled in place.
+macro_rules! impl_mul_hi_round_bytes {
+    ($($vec:ty, $wvec:ty),*) => {
+        $(
+            impl MulHiRound for $vec {
+                #[inline(always)]
+                fn mul_hi_round(&self, other: Self) -> Self {
+                    let (a, b) = (self.be_i16s(), other.be_i16s());
+                    let even = a.shift_left(8).mul_hi_round(b.shift_left(8).shift_right_arithmetic(8));
+                    let odd = (a & <$wvec>::splat(-0x100)).mul_hi_round(b.shift_right_arithmetic(8));
+                    ((even & <$wvec>::splat(0xFF)) | odd.shift_left(8)).be_i8s()
+                }
+            }
+        )*
+    }
+}
+
+// The even and odd lanes are multiplied into 64-bit products and rounded
+// there. Only bits 31 through 62 of each sum are kept, so it doesn't matter
+// that the shifts are logical.
+macro_rules! impl_mul_hi_round_widen {
+    ($feat:expr, $($vec:ty, $mul:ident),*) => {
+        $(
+            impl MulHiRound for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn mul_hi_round(&self, other: Self) -> Self {
+                    optimized!();
+                    let (a, b) = (self.be_u64s(), other.be_u64s());
+                    let round = <Self as Transmute>::u64s::splat(1 << 30);
+                    let even = unsafe { transmute::<_, <Self as Transmute>::u64s>($mul(transmute(a), transmute(b))) } + round;
+                    let odd = unsafe {
+                        transmute::<_, <Self as Transmute>::u64s>($mul(transmute(a.shift_right_logical(32)),
+                                                                       transmute(b.shift_right_logical(32))))
+                    } + round;
+                    let lo = <Self as Transmute>::u64s::splat(0xFFFF_FFFF);
+                    ((even.shift_right_logical(31) & lo) | (odd.shift_left(1) & !lo)).be_i32s()
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn mul_hi_round(&self, other: Self) -> Self {
+                    mul_hi_fallback(self, &other, |a, b| ((a as i64 * b as i64 + (1 << 30)) >> 31) as i32)
+                }
+            }
+        )*
+    }
+}
+
+impl_mul_hi!("sse2", u16x8, u16, u32, _mm_mulhi_epu16);
+impl_mul_hi!("sse2", i16x8, i16, i32, _mm_mulhi_epi16);
+impl_mul_hi!("avx2", u16x16, u16, u32, _mm256_mulhi_epu16);
+impl_mul_hi!("avx2", i16x16, i16, i32, _mm256_mulhi_epi16);
+impl_mul_hi!(@widen "sse2", u32x4, u32, u64, _mm_mul_epu32);
+impl_mul_hi!(@widen "avx2", u32x8, u32, u64, _mm256_mul_epu32);
+impl_mul_hi_signed!(i32x4, u32x4, i32x8, u32x8);
+impl_mul_hi_bytes!(u8x16, u16x8, u16, shift_right_logical, be_u16s, be_u8s,
+                   i8x16, i16x8, i16, shift_right_arithmetic, be_i16s, be_i8s,
+                   u8x32, u16x16, u16, shift_right_logical, be_u16s, be_u8s,
+                   i8x32, i16x16, i16, shift_right_arithmetic, be_i16s, be_i8s);
+impl_mul_hi_fallback!(u8x64, u8, u64, i8x64, i8, i64, u16x32, u16, u64, i16x32, i16, i64,
+                      u32x16, u32, u64, i32x16, i32, i64);
+
+impl_mul_hi_round!("ssse3", i16x8, i16, _mm_mulhrs_epi16);
+impl_mul_hi_round!("avx2", i16x16, i16, _mm256_mulhrs_epi16);
+impl_mul_hi_round_bytes!(i8x16, i16x8, i8x32, i16x16);
+impl_mul_hi_round_widen!("sse4.1", i32x4, _mm_mul_epi32);
+impl_mul_hi_round_widen!("avx2", i32x8, _mm256_mul_epi32);
+impl_mul_hi_round_fallback!(i8x64, i8, i16x32, i16, i32x16, i32);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_mul_hi!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4),
+                 (u8, i8, u16, i16, u32, i32),
+                 (mul_hi_u8x16, mul_hi_i8x16, mul_hi_u16x8, mul_hi_i16x8, mul_hi_u32x4, mul_hi_i32x4));
+    test_mul_hi!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8),
+                 (u8, i8, u16, i16, u32, i32),
+                 (mul_hi_u8x32, mul_hi_i8x32, mul_hi_u16x16, mul_hi_i16x16, mul_hi_u32x8, mul_hi_i32x8));
+    test_mul_hi!((i32x16), (i32), (mul_hi_i32x16));
+    test_mul_hi_round!((i8x16, i16x8, i32x4, i8x32, i16x16, i32x8, i16x32),
+                       (i8, i16, i32, i8, i16, i32, i16),
+                       (mul_hi_round_i8x16, mul_hi_round_i16x8, mul_hi_round_i32x4,
+                        mul_hi_round_i8x32, mul_hi_round_i16x16, mul_hi_round_i32x8, mul_hi_round_i16x32));
+}
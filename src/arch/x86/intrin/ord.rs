@@ -0,0 +1,137 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::ord::*;
+use crate::std::mem::transmute;
+
+// x86 only has signed integer comparisons, so unsigned vectors have their
+// sign bits flipped before comparing, which maps 0 to the smallest signed
+// value and the largest unsigned value to the largest signed value.
+macro_rules! impl_ord_int {
+    ($feat:expr, $cmpgt:ident, $uvec:ident, $bias:expr, $($vec:ty),*) => {
+        $(
+            impl PackedOrd for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn lt_mask(&self, other: Self) -> Self::Out {
+                    optimized!();
+                    unsafe {
+                        let bias = $uvec::splat($bias);
+                        let a = transmute::<_, $uvec>(*self) ^ bias;
+                        let b = transmute::<_, $uvec>(other) ^ bias;
+                        transmute($cmpgt(transmute(b), transmute(a)))
+                    }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn lt_mask(&self, other: Self) -> Self::Out {
+                    cmp_fallback(self, &other, |a, b| a < b)
+                }
+
+                #[inline(always)]
+                fn le_mask(&self, other: Self) -> Self::Out {
+                    !other.lt_mask(*self)
+                }
+            }
+        )*
+    }
+}
+
+// Takes the intrinsics for less-than, less-than-or-equal and unordered, with
+// the comparison predicates for the AVX versions.
+macro_rules! impl_ord_float {
+    ($feat:expr, $vec:ty, $lt:ident ($($ltargs:expr),*), $le:ident ($($leargs:expr),*), $unord:ident ($($unordargs:expr),*)) => {
+        impl PackedOrd for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn lt_mask(&self, other: Self) -> Self::Out {
+                optimized!();
+                unsafe { transmute($lt(transmute(*self), transmute(other), $($ltargs),*)) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn lt_mask(&self, other: Self) -> Self::Out {
+                cmp_fallback(self, &other, |a, b| a < b)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn le_mask(&self, other: Self) -> Self::Out {
+                optimized!();
+                unsafe { transmute($le(transmute(*self), transmute(other), $($leargs),*)) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn le_mask(&self, other: Self) -> Self::Out {
+                cmp_fallback(self, &other, |a, b| a <= b)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn unordered_mask(&self, other: Self) -> Self::Out {
+                optimized!();
+                unsafe { transmute($unord(transmute(*self), transmute(other), $($unordargs),*)) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn unordered_mask(&self, other: Self) -> Self::Out {
+                cmp_fallback(self, &other, |a, b| a.partial_cmp(&b).is_none())
+            }
+        }
+    }
+}
+
+impl_ord_int!("sse2", _mm_cmpgt_epi8, u8x16, 0x80, u8x16);
+impl_ord_int!("sse2", _mm_cmpgt_epi8, u8x16, 0, i8x16);
+impl_ord_int!("sse2", _mm_cmpgt_epi16, u16x8, 0x8000, u16x8);
+impl_ord_int!("sse2", _mm_cmpgt_epi16, u16x8, 0, i16x8);
+impl_ord_int!("sse2", _mm_cmpgt_epi32, u32x4, 0x8000_0000, u32x4);
+impl_ord_int!("sse2", _mm_cmpgt_epi32, u32x4, 0, i32x4);
+impl_ord_int!("sse4.2", _mm_cmpgt_epi64, u64x2, 0x8000_0000_0000_0000, u64x2);
+impl_ord_int!("sse4.2", _mm_cmpgt_epi64, u64x2, 0, i64x2);
+impl_ord_int!("avx2", _mm256_cmpgt_epi8, u8x32, 0x80, u8x32);
+impl_ord_int!("avx2", _mm256_cmpgt_epi8, u8x32, 0, i8x32);
+impl_ord_int!("avx2", _mm256_cmpgt_epi16, u16x16, 0x8000, u16x16);
+impl_ord_int!("avx2", _mm256_cmpgt_epi16, u16x16, 0, i16x16);
+impl_ord_int!("avx2", _mm256_cmpgt_epi32, u32x8, 0x8000_0000, u32x8);
+impl_ord_int!("avx2", _mm256_cmpgt_epi32, u32x8, 0, i32x8);
+impl_ord_int!("avx2", _mm256_cmpgt_epi64, u64x4, 0x8000_0000_0000_0000, u64x4);
+impl_ord_int!("avx2", _mm256_cmpgt_epi64, u64x4, 0, i64x4);
+
+impl_ord_float!("sse", f32x4, _mm_cmplt_ps(), _mm_cmple_ps(), _mm_cmpunord_ps());
+impl_ord_float!("sse2", f64x2, _mm_cmplt_pd(), _mm_cmple_pd(), _mm_cmpunord_pd());
+// _CMP_LT_OQ, _CMP_LE_OQ and _CMP_UNORD_Q
+impl_ord_float!("avx", f32x8, _mm256_cmp_ps(0x11), _mm256_cmp_ps(0x12), _mm256_cmp_ps(0x03));
+impl_ord_float!("avx", f64x4, _mm256_cmp_pd(0x11), _mm256_cmp_pd(0x12), _mm256_cmp_pd(0x03));
+
+impl_ord_fallback!(u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_packed_ord!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2),
+                     (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                     (ord_u8x16, ord_i8x16, ord_u16x8, ord_i16x8, ord_u32x4, ord_i32x4, ord_f32x4, ord_u64x2, ord_i64x2, ord_f64x2));
+    test_packed_ord!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4),
+                     (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                     (ord_u8x32, ord_i8x32, ord_u16x16, ord_i16x16, ord_u32x8, ord_i32x8, ord_f32x8, ord_u64x4, ord_i64x4, ord_f64x4));
+    test_packed_ord!((u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+                     (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                     (ord_u8x64, ord_i8x64, ord_u16x32, ord_i16x32, ord_u32x16, ord_i32x16, ord_f32x16, ord_u64x8, ord_i64x8, ord_f64x8));
+    test_packed_ord_nan!((f32x4, f64x2, f32x8, f64x4, f32x16, f64x8), (f32, f64, f32, f64, f32, f64),
+                         (ord_nan_f32x4, ord_nan_f64x2, ord_nan_f32x8, ord_nan_f64x4, ord_nan_f32x16, ord_nan_f64x8));
+}
@@ -10,23 +10,45 @@ use crate::vektor::x86::*;
 use crate::intrin::sum::*;
 use crate::intrin::transmute::*;
 use crate::intrin::popcnt::*;
+use crate::intrin::endian::*;
+use crate::intrin::shift::*;
 use crate::arch::current::intrin::upcast::*;
 use crate::intrin::sum::UpcastSum;
 use crate::arch::current::vecs::*;
 use crate::intrin::upcast::*;
 use crate::vecs::*;
+use crate::std::ops::Add;
 
+// The nibble lookups below give each byte's count or bit reversal. Lanes
+// wider than a byte are then put together from their bytes.
 #[inline(always)]
 #[cfg(target_feature = "ssse3")]
-unsafe fn popcnt128(v: u8x16) -> usize {
+fn popcnt_bytes128(v: u8x16) -> u8x16 {
     // SSE3 popcnt algorithm by Wojciech Muła
     // http://wm.ite.pl/articles/sse-popcount.html
-    optimized!();
     let lookup = i8x16::new(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
     let lo = v.be_i8s() & i8x16::splat(0x0f);
     let hi = v.be_i8s() >> 4 & i8x16::splat(0x0f);
-    (_mm_shuffle_epi8(lookup, hi) + _mm_shuffle_epi8(lookup, lo))
-        .sum_upcast() as usize
+    unsafe { (_mm_shuffle_epi8(lookup, hi) + _mm_shuffle_epi8(lookup, lo)).be_u8s() }
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+fn popcnt_bytes256(v: u8x32) -> u8x32 {
+    // AVX2 popcnt algorithm by Wojciech Muła, Nathan Kurz, and Daniel Lemire
+    // https://arxiv.org/abs/1611.07612
+    let lookup = i8x32::new(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+                            0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+    let lo = v.be_i8s() & i8x32::splat(0x0f);
+    let hi = (v.be_i8s() >> 4) & i8x32::splat(0x0f);
+    unsafe { (_mm256_shuffle_epi8(lookup, hi) + _mm256_shuffle_epi8(lookup, lo)).be_u8s() }
+}
+
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn popcnt128(v: u8x16) -> usize {
+    optimized!();
+    popcnt_bytes128(v).sum_upcast() as usize
 }
 
 #[inline(always)]
@@ -40,15 +62,8 @@ unsafe fn popcnt128(v: u8x16) -> usize {
 #[inline(always)]
 #[cfg(target_feature = "avx2")]
 unsafe fn popcnt256(v: u8x32) -> usize {
-    // AVX2 popcnt algorithm by Wojciech Muła, Nathan Kurz, and Daniel Lemire
-    // https://arxiv.org/abs/1611.07612
     optimized!();
-    let lookup = i8x32::new(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
-                            0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
-    let lo = v.be_i8s() & i8x32::splat(0x0f);
-    let hi = (v.be_i8s() >> 4) & i8x32::splat(0x0f);
-    (_mm256_shuffle_epi8(lookup, hi) + _mm256_shuffle_epi8(lookup, lo))
-        .sum_upcast() as usize
+    popcnt_bytes256(v).sum_upcast() as usize
 }
 
 #[inline(always)]
@@ -75,6 +90,129 @@ impl_popcnt!(i32x16, popcnt512, i32x8, popcnt256, i32x4, popcnt128);
 impl_popcnt!(u64x8, popcnt512, u64x4, popcnt256, u64x2, popcnt128);
 impl_popcnt!(i64x8, popcnt512, i64x4, popcnt256, i64x2, popcnt128);
 
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+fn reverse_bytes128(v: u8x16) -> u8x16 {
+    let rev_lo = u8x16::new(0x00, 0x80, 0x40, 0xC0, 0x20, 0xA0, 0x60, 0xE0,
+                            0x10, 0x90, 0x50, 0xD0, 0x30, 0xB0, 0x70, 0xF0).be_i8s();
+    let rev_hi = i8x16::new(0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15);
+    let lo = v.be_i8s() & i8x16::splat(0x0f);
+    let hi = v.be_i8s() >> 4 & i8x16::splat(0x0f);
+    unsafe { (_mm_shuffle_epi8(rev_lo, lo) | _mm_shuffle_epi8(rev_hi, hi)).be_u8s() }
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+fn reverse_bytes256(v: u8x32) -> u8x32 {
+    let rev_lo = u8x32::new(0x00, 0x80, 0x40, 0xC0, 0x20, 0xA0, 0x60, 0xE0,
+                            0x10, 0x90, 0x50, 0xD0, 0x30, 0xB0, 0x70, 0xF0,
+                            0x00, 0x80, 0x40, 0xC0, 0x20, 0xA0, 0x60, 0xE0,
+                            0x10, 0x90, 0x50, 0xD0, 0x30, 0xB0, 0x70, 0xF0).be_i8s();
+    let rev_hi = i8x32::new(0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15,
+                            0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15);
+    let lo = v.be_i8s() & i8x32::splat(0x0f);
+    let hi = (v.be_i8s() >> 4) & i8x32::splat(0x0f);
+    unsafe { (_mm256_shuffle_epi8(rev_lo, lo) | _mm256_shuffle_epi8(rev_hi, hi)).be_u8s() }
+}
+
+// Add the count in the upper half of each lane onto the lower half.
+#[inline(always)]
+#[allow(dead_code)]
+fn fold_halves<T>(c: T, half: u32) -> T where T : Shift + Add<Output = T> + Copy {
+    (c.shift_left(half) + c).shift_right_logical(half)
+}
+
+#[allow(unused_macros)]
+macro_rules! lane_bytes {
+    (popcnt 8, $c:expr) => { $c };
+    (popcnt 16, $c:expr) => { fold_halves($c.be_u16s(), 8) };
+    (popcnt 32, $c:expr) => { fold_halves(lane_bytes!(popcnt 16, $c).be_u32s(), 16) };
+    (popcnt 64, $c:expr) => { fold_halves(lane_bytes!(popcnt 32, $c).be_u64s(), 32) };
+    (reverse 8, $v:expr) => { $v };
+    (reverse $bits:tt, $v:expr) => { $v.swap_bytes() };
+}
+
+macro_rules! impl_bit_count {
+    ($feat:expr, $bytes:ident, $rev:ident, $($vec:ty, $el:ty, $uel:ty, $bits:tt, $be:ident),*) => {
+        $(
+            impl BitCount for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn popcnt(&self) -> Self {
+                    optimized!();
+                    lane_bytes!(popcnt $bits, $bytes(self.be_u8s())).$be()
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn leading_zeros(&self) -> Self {
+                    optimized!();
+                    // Smearing the highest one over every lower bit leaves
+                    // only the leading zeroes.
+                    let mut x = *self;
+                    let mut shift = 1;
+                    while shift < $bits {
+                        x = x | x.shift_right_logical(shift);
+                        shift *= 2;
+                    }
+                    (!x).popcnt()
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn trailing_zeros(&self) -> Self {
+                    optimized!();
+                    // Subtracting one turns the trailing zeroes into ones,
+                    // and the lowest one into a zero.
+                    (!*self & (*self - Self::splat(1 as $el))).popcnt()
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn reverse_bits(&self) -> Self {
+                    optimized!();
+                    lane_bytes!(reverse $bits, $rev(self.be_u8s()).$be())
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn popcnt(&self) -> Self {
+                    bit_count_fallback(self, |x| x.count_ones() as $el)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn leading_zeros(&self) -> Self {
+                    bit_count_fallback(self, |x| x.leading_zeros() as $el)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn trailing_zeros(&self) -> Self {
+                    bit_count_fallback(self, |x| x.trailing_zeros() as $el)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn reverse_bits(&self) -> Self {
+                    bit_count_fallback(self, |x| {
+                        (0..$bits).fold(0 as $uel, |r, i| r | (((x as $uel) >> i) & 1) << ($bits - 1 - i)) as $el
+                    })
+                }
+            }
+        )*
+    }
+}
+
+impl_bit_count!("ssse3", popcnt_bytes128, reverse_bytes128,
+                u8x16, u8, u8, 8, be_u8s, i8x16, i8, u8, 8, be_i8s, u16x8, u16, u16, 16, be_u16s, i16x8, i16, u16, 16, be_i16s,
+                u32x4, u32, u32, 32, be_u32s, i32x4, i32, u32, 32, be_i32s, u64x2, u64, u64, 64, be_u64s, i64x2, i64, u64, 64, be_i64s);
+impl_bit_count!("avx2", popcnt_bytes256, reverse_bytes256,
+                u8x32, u8, u8, 8, be_u8s, i8x32, i8, u8, 8, be_i8s, u16x16, u16, u16, 16, be_u16s, i16x16, i16, u16, 16, be_i16s,
+                u32x8, u32, u32, 32, be_u32s, i32x8, i32, u32, 32, be_i32s, u64x4, u64, u64, 64, be_u64s, i64x4, i64, u64, 64, be_i64s);
+impl_bit_count_fallback!(u8x64, u8, u8, i8x64, i8, u8, u16x32, u16, u16, i16x32, i16, u16,
+                         u32x16, u32, u32, i32x16, i32, u32, u64x8, u64, u64, i64x8, i64, u64);
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -83,4 +221,14 @@ mod tests {
     test_popcnt!((u8, u8, u8, i8, i8, i8, u16, u16, u16, i16, i16, i16, u32, u32, u32, i32, i32, i32, u64, u64, u64, i64, i64, i64),
                  (u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2),
                  (popcnt_u8x64, popcnt_u8x32, popcnt_u8x16, popcnt_i8x64, popcnt_i8x32, popcnt_i8x16, popcnt_u16x32, popcnt_u16x16, popcnt_u16x8, popcnt_i16x32, popcnt_i16x16, popcnt_i16x8, popcnt_u32x16, popcnt_u32x8, popcnt_u32x4, popcnt_i32x16, popcnt_i32x8, popcnt_i32x4, popcnt_u64x8, popcnt_u64x4, popcnt_u64x2, popcnt_i64x8, popcnt_i64x4, popcnt_i64x2));
+
+    test_bit_count!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2),
+                    (u8, i8, u16, i16, u32, i32, u64, i64),
+                    (bit_count_u8x16, bit_count_i8x16, bit_count_u16x8, bit_count_i16x8,
+                     bit_count_u32x4, bit_count_i32x4, bit_count_u64x2, bit_count_i64x2));
+    test_bit_count!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4),
+                    (u8, i8, u16, i16, u32, i32, u64, i64),
+                    (bit_count_u8x32, bit_count_i8x32, bit_count_u16x16, bit_count_i16x16,
+                     bit_count_u32x8, bit_count_i32x8, bit_count_u64x4, bit_count_i64x4));
+    test_bit_count!((u16x32), (u16), (bit_count_u16x32));
 }
@@ -0,0 +1,303 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::cmp::*;
+use crate::intrin::reduce::*;
+use crate::intrin::transmute::*;
+use crate::std::mem::transmute;
+
+// Each step combines the upper half of the remaining elements with the lower
+// half, which leaves the result in the first element after log2(width)
+// steps. The bytes shifted in are never read.
+macro_rules! fold128 {
+    ($x:expr, $op:expr, [$($bytes:expr),*]) => {{
+        let mut x = $x;
+        $(
+            x = $op(x, unsafe { transmute(_mm_srli_si128(transmute(x), $bytes)) });
+        )*
+        x.extract(0)
+    }}
+}
+
+macro_rules! impl_hreduce128 {
+    (minmax, $min:expr, $max:expr, $($vec:ty, $steps:tt),*) => {
+        $(
+            impl HMinMax for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn hmin(&self) -> Self::Scalar {
+                    optimized!();
+                    fold128!(*self, $min, $steps)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn hmin(&self) -> Self::Scalar {
+                    hreduce_fallback(self, min_num)
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn hmax(&self) -> Self::Scalar {
+                    optimized!();
+                    fold128!(*self, $max, $steps)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn hmax(&self) -> Self::Scalar {
+                    hreduce_fallback(self, max_num)
+                }
+            }
+        )*
+    };
+    (product, $op:expr, $($vec:ty, $steps:tt),*) => {
+        $(
+            impl HProduct for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn hproduct(&self) -> Self::Scalar {
+                    optimized!();
+                    fold128!(*self, |a: Self, b: Self| a * b, $steps)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn hproduct(&self) -> Self::Scalar {
+                    hreduce_fallback(self, $op)
+                }
+            }
+        )*
+    };
+    (bitwise, $($vec:ty, $steps:tt),*) => {
+        $(
+            impl HBitwise for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn hand(&self) -> Self::Scalar {
+                    optimized!();
+                    fold128!(*self, |a: Self, b: Self| a & b, $steps)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn hand(&self) -> Self::Scalar {
+                    hreduce_fallback(self, |a, b| a & b)
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn hor(&self) -> Self::Scalar {
+                    optimized!();
+                    fold128!(*self, |a: Self, b: Self| a | b, $steps)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn hor(&self) -> Self::Scalar {
+                    hreduce_fallback(self, |a, b| a | b)
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn hxor(&self) -> Self::Scalar {
+                    optimized!();
+                    fold128!(*self, |a: Self, b: Self| a ^ b, $steps)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn hxor(&self) -> Self::Scalar {
+                    hreduce_fallback(self, |a, b| a ^ b)
+                }
+            }
+        )*
+    };
+}
+
+// phminposuw finds the smallest unsigned word directly. The other extremes
+// are found by flipping the elements into an order where they are smallest.
+macro_rules! impl_hminmax_minpos {
+    ($($vec:ty, $el:ty, $min_flip:expr, $max_flip:expr),*) => {
+        $(
+            impl HMinMax for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = "sse4.1")]
+                fn hmin(&self) -> Self::Scalar {
+                    optimized!();
+                    let flipped = self.be_u16s() ^ u16x8::splat($min_flip);
+                    let min: u16x8 = unsafe { transmute(_mm_minpos_epu16(transmute(flipped))) };
+                    (min.extract(0) ^ $min_flip) as $el
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse4.1"))]
+                fn hmin(&self) -> Self::Scalar {
+                    hreduce_fallback(self, min_num)
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "sse4.1")]
+                fn hmax(&self) -> Self::Scalar {
+                    optimized!();
+                    let flipped = self.be_u16s() ^ u16x8::splat($max_flip);
+                    let min: u16x8 = unsafe { transmute(_mm_minpos_epu16(transmute(flipped))) };
+                    (min.extract(0) ^ $max_flip) as $el
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse4.1"))]
+                fn hmax(&self) -> Self::Scalar {
+                    hreduce_fallback(self, max_num)
+                }
+            }
+        )*
+    }
+}
+
+// Wider vectors are split into halves, which are combined elementwise before
+// being reduced themselves. The halves are read from the vector's memory, so
+// this costs an extract at most.
+macro_rules! impl_hreduce_halves {
+    (minmax, $($vec:ty, $half:ty),*) => {
+        $(
+            impl HMinMax for $vec {
+                #[inline(always)]
+                fn hmin(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    halves[0].min(halves[1]).hmin()
+                }
+
+                #[inline(always)]
+                fn hmax(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    halves[0].max(halves[1]).hmax()
+                }
+            }
+        )*
+    };
+    (minmax_float, $($vec:ty, $half:ty),*) => {
+        $(
+            impl HMinMax for $vec {
+                #[inline(always)]
+                fn hmin(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    packed_min_num(halves[0], halves[1]).hmin()
+                }
+
+                #[inline(always)]
+                fn hmax(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    packed_max_num(halves[0], halves[1]).hmax()
+                }
+            }
+        )*
+    };
+    // There are no elementwise extremes of 64-bit integers before AVX-512, so
+    // the halves are reduced separately.
+    (minmax64, $($vec:ty, $half:ty),*) => {
+        $(
+            impl HMinMax for $vec {
+                #[inline(always)]
+                fn hmin(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    halves[0].hmin().min(halves[1].hmin())
+                }
+
+                #[inline(always)]
+                fn hmax(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    halves[0].hmax().max(halves[1].hmax())
+                }
+            }
+        )*
+    };
+    (product, $($vec:ty, $half:ty),*) => {
+        $(
+            impl HProduct for $vec {
+                #[inline(always)]
+                fn hproduct(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    (halves[0] * halves[1]).hproduct()
+                }
+            }
+        )*
+    };
+    (bitwise, $($vec:ty, $half:ty),*) => {
+        $(
+            impl HBitwise for $vec {
+                #[inline(always)]
+                fn hand(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    (halves[0] & halves[1]).hand()
+                }
+
+                #[inline(always)]
+                fn hor(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    (halves[0] | halves[1]).hor()
+                }
+
+                #[inline(always)]
+                fn hxor(&self) -> Self::Scalar {
+                    let halves: [$half; 2] = unsafe { transmute(*self) };
+                    (halves[0] ^ halves[1]).hxor()
+                }
+            }
+        )*
+    };
+}
+
+impl_hreduce128!(minmax, |a: Self, b: Self| a.min(b), |a: Self, b: Self| a.max(b),
+                 u8x16, [8, 4, 2, 1], i8x16, [8, 4, 2, 1], u32x4, [8, 4], i32x4, [8, 4]);
+// minps and maxps return their second operand if either is NaN, so the float
+// folds mask out NaN themselves to match the fallback.
+impl_hreduce128!(minmax, packed_min_num, packed_max_num, f32x4, [8, 4], f64x2, [8]);
+impl_hminmax_minpos!(u16x8, u16, 0, 0xFFFF, i16x8, i16, 0x8000, 0x7FFF);
+impl_hminmax_fallback!(u64x2, i64x2);
+impl_hreduce128!(product, |a, b| a.wrapping_mul(b), u8x16, [8, 4, 2, 1], i8x16, [8, 4, 2, 1], u16x8, [8, 4, 2],
+                 i16x8, [8, 4, 2], u32x4, [8, 4], i32x4, [8, 4], u64x2, [8], i64x2, [8]);
+impl_hreduce128!(product, |a, b| a * b, f32x4, [8, 4], f64x2, [8]);
+impl_hreduce128!(bitwise, u8x16, [8, 4, 2, 1], i8x16, [8, 4, 2, 1], u16x8, [8, 4, 2], i16x8, [8, 4, 2],
+                 u32x4, [8, 4], i32x4, [8, 4], u64x2, [8], i64x2, [8]);
+
+impl_hreduce_halves!(minmax, u8x32, u8x16, i8x32, i8x16, u16x16, u16x8, i16x16, i16x8, u32x8, u32x4, i32x8, i32x4,
+                     u8x64, u8x32, i8x64, i8x32, u16x32, u16x16, i16x32, i16x16, u32x16, u32x8, i32x16, i32x8);
+impl_hreduce_halves!(minmax_float, f32x8, f32x4, f64x4, f64x2, f32x16, f32x8, f64x8, f64x4);
+impl_hreduce_halves!(minmax64, u64x4, u64x2, i64x4, i64x2, u64x8, u64x4, i64x8, i64x4);
+impl_hreduce_halves!(product, u8x32, u8x16, i8x32, i8x16, u16x16, u16x8, i16x16, i16x8, u32x8, u32x4, i32x8, i32x4,
+                     u64x4, u64x2, i64x4, i64x2, f32x8, f32x4, f64x4, f64x2, u8x64, u8x32, i8x64, i8x32,
+                     u16x32, u16x16, i16x32, i16x16, u32x16, u32x8, i32x16, i32x8, u64x8, u64x4, i64x8, i64x4,
+                     f32x16, f32x8, f64x8, f64x4);
+impl_hreduce_halves!(bitwise, u8x32, u8x16, i8x32, i8x16, u16x16, u16x8, i16x16, i16x8, u32x8, u32x4, i32x8, i32x4,
+                     u64x4, u64x2, i64x4, i64x2, u8x64, u8x32, i8x64, i8x32, u16x32, u16x16, i16x32, i16x16,
+                     u32x16, u32x8, i32x16, i32x8, u64x8, u64x4, i64x8, i64x4);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_hreduce_int!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2),
+                      (u8, i8, u16, i16, u32, i32, u64, i64),
+                      (hreduce_u8x16, hreduce_i8x16, hreduce_u16x8, hreduce_i16x8,
+                       hreduce_u32x4, hreduce_i32x4, hreduce_u64x2, hreduce_i64x2));
+    test_hreduce_int!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4),
+                      (u8, i8, u16, i16, u32, i32, u64, i64),
+                      (hreduce_u8x32, hreduce_i8x32, hreduce_u16x16, hreduce_i16x16,
+                       hreduce_u32x8, hreduce_i32x8, hreduce_u64x4, hreduce_i64x4));
+    test_hreduce_int!((u8x64, i16x32, u64x8), (u8, i16, u64), (hreduce_u8x64, hreduce_i16x32, hreduce_u64x8));
+    test_hreduce_float!((f32x4, f64x2, f32x8, f64x4, f32x16, f64x8), (f32, f64, f32, f64, f32, f64),
+                        (hreduce_f32x4, hreduce_f64x2, hreduce_f32x8, hreduce_f64x4, hreduce_f32x16, hreduce_f64x8));
+    test_hminmax_nan!((f32x4, f64x2, f32x8, f64x4, f32x16, f64x8), (f32, f64, f32, f64, f32, f64),
+                      (hminmax_nan_f32x4, hminmax_nan_f64x2, hminmax_nan_f32x8, hminmax_nan_f64x4,
+                       hminmax_nan_f32x16, hminmax_nan_f64x8));
+}
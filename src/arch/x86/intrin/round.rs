@@ -47,3 +47,5 @@ rust_fallback_impl! {
         trunc => _mm256_round_pd(_MM_FROUND_TRUNC), [0, 1, 2, 3];
     }
 }
+
+impl_round_fallback!(f32x16, f64x8);
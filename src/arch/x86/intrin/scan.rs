@@ -0,0 +1,124 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::scan::*;
+use crate::std::mem::transmute;
+
+// Each step adds the accumulator to itself, shifted up by twice as many bytes
+// as the last step.
+macro_rules! impl_scan_sse2 {
+    ($size:expr, ($($amt:expr),*), $($vec:ty),*) => {
+        $(
+            impl Scan for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn scan(&self) -> Self {
+                    optimized!();
+                    let mut acc = *self;
+                    $(acc = acc + unsafe { transmute::<_, Self>(_mm_slli_si128(transmute(acc), $amt)) };)*
+                    acc
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "sse2")]
+                fn scan_exclusive(&self) -> Self {
+                    unsafe { transmute::<_, Self>(_mm_slli_si128(transmute(*self), $size)) }.scan()
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn scan(&self) -> Self {
+                    scan_fallback(*self)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn scan_exclusive(&self) -> Self {
+                    scan_fallback(shift_lanes_up(*self, 1))
+                }
+            }
+        )*
+    }
+}
+
+// AVX2 can't shift bytes across the 128-bit halves of a register, so the low
+// half is moved into the high half (with zeroes below it) and the two are
+// realigned with vpalignr.
+macro_rules! shift_bytes_up_256 {
+    ($vec:ty, $val:expr, $amt:expr) => {{
+        let val = $val;
+        let lo = _mm256_permute2x128_si256(transmute(val), transmute(val), 0x08);
+        transmute::<_, $vec>(_mm256_alignr_epi8(transmute(val), transmute(lo), 16 - $amt))
+    }}
+}
+
+macro_rules! impl_scan_avx2 {
+    ($size:expr, ($($amt:expr),*), $($vec:ty),*) => {
+        $(
+            impl Scan for $vec {
+                #[inline(always)]
+                #[cfg(target_feature = "avx2")]
+                fn scan(&self) -> Self {
+                    optimized!();
+                    let mut acc = *self;
+                    $(acc = acc + unsafe { shift_bytes_up_256!(Self, acc, $amt) };)*
+                    acc
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = "avx2")]
+                fn scan_exclusive(&self) -> Self {
+                    unsafe { shift_bytes_up_256!(Self, *self, $size) }.scan()
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "avx2"))]
+                fn scan(&self) -> Self {
+                    scan_fallback(*self)
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "avx2"))]
+                fn scan_exclusive(&self) -> Self {
+                    scan_fallback(shift_lanes_up(*self, 1))
+                }
+            }
+        )*
+    }
+}
+
+impl_scan_sse2!(1, (1, 2, 4, 8), u8x16, i8x16);
+impl_scan_sse2!(2, (2, 4, 8), u16x8, i16x8);
+impl_scan_sse2!(4, (4, 8), u32x4, i32x4, f32x4);
+impl_scan_sse2!(8, (8), u64x2, i64x2, f64x2);
+
+impl_scan_avx2!(1, (1, 2, 4, 8, 16), u8x32, i8x32);
+impl_scan_avx2!(2, (2, 4, 8, 16), u16x16, i16x16);
+impl_scan_avx2!(4, (4, 8, 16), u32x8, i32x8, f32x8);
+impl_scan_avx2!(8, (8, 16), u64x4, i64x4, f64x4);
+
+impl_scan_fallback!(u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_packed_scan!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2),
+                      (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                      (scan_u8x16, scan_i8x16, scan_u16x8, scan_i16x8, scan_u32x4, scan_i32x4, scan_f32x4, scan_u64x2, scan_i64x2, scan_f64x2));
+    test_packed_scan!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4),
+                      (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                      (scan_u8x32, scan_i8x32, scan_u16x16, scan_i16x16, scan_u32x8, scan_i32x8, scan_f32x8, scan_u64x4, scan_i64x4, scan_f64x4));
+    test_packed_scan!((u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+                      (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                      (scan_u8x64, scan_i8x64, scan_u16x32, scan_i16x32, scan_u32x16, scan_i32x16, scan_f32x16, scan_u64x8, scan_i64x8, scan_f64x8));
+}
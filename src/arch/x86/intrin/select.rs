@@ -0,0 +1,107 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::mask::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::select::*;
+use crate::std::mem::transmute;
+
+// Blends take their second argument where the mask is set. Without SSE4.1,
+// the same result is built from the bitwise operations.
+macro_rules! impl_select_sse {
+    ($blend:ident, $and:ident, $andnot:ident, $or:ident, $mask:ty, $($vec:ty),*) => {
+        $(
+            impl Select<$vec> for $mask {
+                #[inline(always)]
+                #[cfg(target_feature = "sse4.1")]
+                fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                    optimized!();
+                    unsafe { transmute($blend(transmute(if_false), transmute(if_true), transmute(*self))) }
+                }
+
+                #[inline(always)]
+                #[cfg(all(target_feature = "sse2", not(target_feature = "sse4.1")))]
+                fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                    optimized!();
+                    unsafe {
+                        transmute($or($and(transmute(*self), transmute(if_true)),
+                                      $andnot(transmute(*self), transmute(if_false))))
+                    }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = "sse2"))]
+                fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                    select_fallback(self, if_true, if_false)
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_select {
+    ($feat:expr, $blend:ident, $mask:ty, $($vec:ty),*) => {
+        $(
+            impl Select<$vec> for $mask {
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                    optimized!();
+                    unsafe { transmute($blend(transmute(if_false), transmute(if_true), transmute(*self))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                    select_fallback(self, if_true, if_false)
+                }
+            }
+        )*
+    }
+}
+
+impl_select_sse!(_mm_blendv_epi8, _mm_and_si128, _mm_andnot_si128, _mm_or_si128, m8x16, u8x16, i8x16);
+impl_select_sse!(_mm_blendv_epi8, _mm_and_si128, _mm_andnot_si128, _mm_or_si128, m16x8, u16x8, i16x8);
+impl_select_sse!(_mm_blendv_epi8, _mm_and_si128, _mm_andnot_si128, _mm_or_si128, m32x4, u32x4, i32x4);
+impl_select_sse!(_mm_blendv_epi8, _mm_and_si128, _mm_andnot_si128, _mm_or_si128, m64x2, u64x2, i64x2);
+impl_select_sse!(_mm_blendv_ps, _mm_and_ps, _mm_andnot_ps, _mm_or_ps, m32x4, f32x4);
+impl_select_sse!(_mm_blendv_pd, _mm_and_pd, _mm_andnot_pd, _mm_or_pd, m64x2, f64x2);
+
+impl_select!("avx2", _mm256_blendv_epi8, m8x32, u8x32, i8x32);
+impl_select!("avx2", _mm256_blendv_epi8, m16x16, u16x16, i16x16);
+impl_select!("avx2", _mm256_blendv_epi8, m32x8, u32x8, i32x8);
+impl_select!("avx2", _mm256_blendv_epi8, m64x4, u64x4, i64x4);
+impl_select!("avx", _mm256_blendv_ps, m32x8, f32x8);
+impl_select!("avx", _mm256_blendv_pd, m64x4, f64x4);
+
+impl_select_fallback!(m8x64, u8x64, i8x64);
+impl_select_fallback!(m16x32, u16x32, i16x32);
+impl_select_fallback!(m32x16, u32x16, i32x16, f32x16);
+impl_select_fallback!(m64x8, u64x8, i64x8, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+    use crate::mask::*;
+
+    test_packed_select!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2),
+                        (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                        (select_u8x16, select_i8x16, select_u16x8, select_i16x8, select_u32x4, select_i32x4, select_f32x4, select_u64x2, select_i64x2, select_f64x2));
+    test_packed_select!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4),
+                        (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                        (select_u8x32, select_i8x32, select_u16x16, select_i16x16, select_u32x8, select_i32x8, select_f32x8, select_u64x4, select_i64x4, select_f64x4));
+
+    #[test]
+    fn select_512() {
+        let sel = f32x16::interleave(1.0, 2.0).eq_mask(f32x16::splat(1.0));
+        assert_eq!(sel.select(f32x16::splat(1.0), f32x16::splat(2.0)), f32x16::interleave(1.0, 2.0));
+    }
+}
@@ -0,0 +1,168 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::shift::*;
+use crate::intrin::transmute::*;
+use crate::std::mem::transmute;
+
+// The uniform shifts read their amount from the low 64 bits of a vector, so
+// any amount of at least the lane width shifts out every bit. There is no
+// arithmetic shift on 64-bit lanes, so it is done logically on the lanes with
+// their bits flipped wherever the sign bit is set.
+macro_rules! impl_shift {
+    (@native $feat:expr, $name:ident, $intrin:ident, $lane:ident) => {
+        #[inline(always)]
+        #[cfg(target_feature = $feat)]
+        fn $name(&self, amount: u32) -> Self {
+            optimized!();
+            unsafe { transmute($intrin(transmute(*self), transmute(u64x2::new(amount as u64, 0)))) }
+        }
+
+        #[inline(always)]
+        #[cfg(not(target_feature = $feat))]
+        fn $name(&self, amount: u32) -> Self {
+            shift_fallback(self, amount, ScalarShift::$lane)
+        }
+    };
+    ($feat:expr, $vec:ty, $sll:ident, $srl:ident, $sra:ident) => {
+        impl Shift for $vec {
+            impl_shift!(@native $feat, shift_left, $sll, shl_lane);
+            impl_shift!(@native $feat, shift_right_logical, $srl, shr_logical_lane);
+            impl_shift!(@native $feat, shift_right_arithmetic, $sra, shr_arithmetic_lane);
+        }
+    };
+    ($feat:expr, $vec:ty, $sll:ident, $srl:ident) => {
+        impl Shift for $vec {
+            impl_shift!(@native $feat, shift_left, $sll, shl_lane);
+            impl_shift!(@native $feat, shift_right_logical, $srl, shr_logical_lane);
+
+            #[inline(always)]
+            fn shift_right_arithmetic(&self, amount: u32) -> Self {
+                let sign = Self::splat(0) - self.shift_right_logical(63);
+                (*self ^ sign).shift_right_logical(amount) ^ sign
+            }
+        }
+    };
+}
+
+// There are no shifts on 8-bit lanes, so these shift 16-bit lanes and clear
+// the bits which crossed over from the neighbouring byte.
+macro_rules! impl_shift_bytes {
+    ($($vec:ty, $el:ty, $be:ident),*) => {
+        $(
+            impl Shift for $vec {
+                #[inline(always)]
+                fn shift_left(&self, amount: u32) -> Self {
+                    let keep = Self::splat((0xFFu16 << amount.min(8)) as $el);
+                    self.be_u16s().shift_left(amount).$be() & keep
+                }
+
+                #[inline(always)]
+                fn shift_right_logical(&self, amount: u32) -> Self {
+                    let keep = Self::splat((0xFFu16 >> amount.min(8)) as $el);
+                    self.be_u16s().shift_right_logical(amount).$be() & keep
+                }
+
+                #[inline(always)]
+                fn shift_right_arithmetic(&self, amount: u32) -> Self {
+                    let sign = Self::splat(0) - self.shift_right_logical(7);
+                    (*self ^ sign).shift_right_logical(amount) ^ sign
+                }
+            }
+        )*
+    }
+}
+
+// AVX2 has variable shifts on 32 and 64-bit lanes, which shift out every bit
+// once an amount reaches the lane width.
+macro_rules! impl_shift_var {
+    (@native $feat:expr, $name:ident, $intrin:ident, $lane:ident) => {
+        #[inline(always)]
+        #[cfg(target_feature = $feat)]
+        fn $name(&self, amounts: Self) -> Self {
+            optimized!();
+            unsafe { transmute($intrin(transmute(*self), transmute(amounts))) }
+        }
+
+        #[inline(always)]
+        #[cfg(not(target_feature = $feat))]
+        fn $name(&self, amounts: Self) -> Self {
+            shift_var_fallback(self, &amounts, ScalarShift::$lane)
+        }
+    };
+    ($feat:expr, $vec:ty, $sllv:ident, $srlv:ident, $srav:ident) => {
+        impl ShiftVar for $vec {
+            impl_shift_var!(@native $feat, shift_left_var, $sllv, shl_lane);
+            impl_shift_var!(@native $feat, shift_right_logical_var, $srlv, shr_logical_lane);
+            impl_shift_var!(@native $feat, shift_right_arithmetic_var, $srav, shr_arithmetic_lane);
+        }
+    };
+    ($feat:expr, $vec:ty, $sllv:ident, $srlv:ident) => {
+        impl ShiftVar for $vec {
+            impl_shift_var!(@native $feat, shift_left_var, $sllv, shl_lane);
+            impl_shift_var!(@native $feat, shift_right_logical_var, $srlv, shr_logical_lane);
+
+            #[inline(always)]
+            fn shift_right_arithmetic_var(&self, amounts: Self) -> Self {
+                let sign = Self::splat(0) - self.shift_right_logical(63);
+                (*self ^ sign).shift_right_logical_var(amounts) ^ sign
+            }
+        }
+    };
+}
+
+impl_shift_bytes!(u8x16, u8, be_u8s, i8x16, i8, be_i8s, u8x32, u8, be_u8s, i8x32, i8, be_i8s);
+impl_shift!("sse2", u16x8, _mm_sll_epi16, _mm_srl_epi16, _mm_sra_epi16);
+impl_shift!("sse2", i16x8, _mm_sll_epi16, _mm_srl_epi16, _mm_sra_epi16);
+impl_shift!("sse2", u32x4, _mm_sll_epi32, _mm_srl_epi32, _mm_sra_epi32);
+impl_shift!("sse2", i32x4, _mm_sll_epi32, _mm_srl_epi32, _mm_sra_epi32);
+impl_shift!("sse2", u64x2, _mm_sll_epi64, _mm_srl_epi64);
+impl_shift!("sse2", i64x2, _mm_sll_epi64, _mm_srl_epi64);
+impl_shift!("avx2", u16x16, _mm256_sll_epi16, _mm256_srl_epi16, _mm256_sra_epi16);
+impl_shift!("avx2", i16x16, _mm256_sll_epi16, _mm256_srl_epi16, _mm256_sra_epi16);
+impl_shift!("avx2", u32x8, _mm256_sll_epi32, _mm256_srl_epi32, _mm256_sra_epi32);
+impl_shift!("avx2", i32x8, _mm256_sll_epi32, _mm256_srl_epi32, _mm256_sra_epi32);
+impl_shift!("avx2", u64x4, _mm256_sll_epi64, _mm256_srl_epi64);
+impl_shift!("avx2", i64x4, _mm256_sll_epi64, _mm256_srl_epi64);
+impl_shift_fallback!(u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8);
+
+impl_shift_var!("avx2", u32x4, _mm_sllv_epi32, _mm_srlv_epi32, _mm_srav_epi32);
+impl_shift_var!("avx2", i32x4, _mm_sllv_epi32, _mm_srlv_epi32, _mm_srav_epi32);
+impl_shift_var!("avx2", u64x2, _mm_sllv_epi64, _mm_srlv_epi64);
+impl_shift_var!("avx2", i64x2, _mm_sllv_epi64, _mm_srlv_epi64);
+impl_shift_var!("avx2", u32x8, _mm256_sllv_epi32, _mm256_srlv_epi32, _mm256_srav_epi32);
+impl_shift_var!("avx2", i32x8, _mm256_sllv_epi32, _mm256_srlv_epi32, _mm256_srav_epi32);
+impl_shift_var!("avx2", u64x4, _mm256_sllv_epi64, _mm256_srlv_epi64);
+impl_shift_var!("avx2", i64x4, _mm256_sllv_epi64, _mm256_srlv_epi64);
+impl_shift_var_fallback!(u8x16, i8x16, u16x8, i16x8, u8x32, i8x32, u16x16, i16x16,
+                         u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8);
+
+impl_rotate!(u8x16, u8, i8x16, i8, u16x8, u16, i16x8, i16, u32x4, u32, i32x4, i32, u64x2, u64, i64x2, i64,
+             u8x32, u8, i8x32, i8, u16x16, u16, i16x16, i16, u32x8, u32, i32x8, i32, u64x4, u64, i64x4, i64,
+             u8x64, u8, i8x64, i8, u16x32, u16, i16x32, i16, u32x16, u32, i32x16, i32, u64x8, u64, i64x8, i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_packed_shift!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2),
+                       (u8, i8, u16, i16, u32, i32, u64, i64),
+                       (u8, u8, u16, u16, u32, u32, u64, u64),
+                       (i8, i8, i16, i16, i32, i32, i64, i64),
+                       (shift_u8x16, shift_i8x16, shift_u16x8, shift_i16x8, shift_u32x4, shift_i32x4, shift_u64x2, shift_i64x2));
+    test_packed_shift!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4),
+                       (u8, i8, u16, i16, u32, i32, u64, i64),
+                       (u8, u8, u16, u16, u32, u32, u64, u64),
+                       (i8, i8, i16, i16, i32, i32, i64, i64),
+                       (shift_u8x32, shift_i8x32, shift_u16x16, shift_i16x16, shift_u32x8, shift_i32x8, shift_u64x4, shift_i64x4));
+    test_packed_shift!((u8x64, i64x8), (u8, i64), (u8, u64), (i8, i64), (shift_u8x64, shift_i64x8));
+}
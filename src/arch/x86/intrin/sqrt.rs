@@ -34,3 +34,5 @@ rust_fallback_impl! {
         sqrt => _mm_sqrt_pd(), [0, 1];
     }
 }
+
+impl_sqrt_fallback!(f32x16, f64x8);
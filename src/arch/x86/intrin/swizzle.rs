@@ -1,9 +1,20 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
 use crate::arch::current::vecs::*;
 use crate::vecs::*;
 use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
+use crate::intrin::eq::*;
+use crate::intrin::merge::*;
+use crate::intrin::select::*;
+use crate::intrin::swizzle::*;
 use crate::intrin::transmute::*;
-use crate::std::mem::transmute;
+use crate::std::mem::{size_of, transmute};
 
 impl_packed_swizzle!(u8x64, u8x64, "avx512-butnotyet", _mm512_permutexvar_epi8,
                      (1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14, 17, 16, 19, 18, 21, 20, 23, 22, 25, 24, 27, 26, 29, 28, 31, 30, 33, 32, 35, 34, 37, 36, 39, 38, 41, 40, 43, 42, 45, 44, 47, 46, 49, 48, 51, 50, 53, 52, 55, 54, 57, 56, 59, 58, 61, 60, 63, 62),
@@ -96,8 +107,140 @@ impl_packed_swizzle!(f64x2, u8x16, "ssse3", _mm_shuffle_epi8,
                      (8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7),
                      (0, 1));
 
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+fn shuffle_bytes128(x: u8x16, bytes: u8x16) -> u8x16 {
+    unsafe { transmute(_mm_shuffle_epi8(transmute(x), transmute(bytes))) }
+}
+
+// pshufb only picks bytes from the same 128-bit lane, so the bytes are picked
+// from both the vector and a copy with its lanes swapped, and each is taken
+// from whichever has the lane its index points into.
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+fn shuffle_bytes256(x: u8x32, bytes: u8x32) -> u8x32 {
+    let (own, swapped): (u8x32, u8x32) = unsafe {
+        (transmute(_mm256_shuffle_epi8(transmute(x), transmute(bytes))),
+         transmute(_mm256_shuffle_epi8(_mm256_permute2x128_si256(transmute(x), transmute(x), 1), transmute(bytes))))
+    };
+    ((bytes ^ u8x32::halfs(0, 16)) & u8x32::splat(16)).eq_mask(u8x32::splat(0)).select(own, swapped)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+fn permute_dwords256(x: u32x8, idx: u32x8) -> u32x8 {
+    unsafe { transmute(_mm256_permutevar8x32_epi32(transmute(x), transmute(idx))) }
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+fn permute_floats256(x: f32x8, idx: u32x8) -> f32x8 {
+    unsafe { transmute(_mm256_permutevar8x32_ps(transmute(x), transmute(idx))) }
+}
+
+// palignr takes its shift as an immediate, so each shift gets its own arm.
+macro_rules! alignr_bytes {
+    ($alignr:ident, $hi:expr, $lo:expr, $bytes:expr, $($n:expr),*) => {
+        match $bytes {
+            $($n => transmute($alignr(transmute($hi), transmute($lo), $n)),)*
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Return the 16 bytes beginning at the `bytes`th byte of `x` followed by `y`.
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+fn alignr_bytes128(x: u8x16, y: u8x16, bytes: usize) -> u8x16 {
+    unsafe { alignr_bytes!(_mm_alignr_epi8, y, x, bytes, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16) }
+}
+
+// The AVX2 palignr works within 128-bit lanes, so each lane of the result is
+// aligned from the two lanes it straddles. Those are either lanes of `x`, the
+// upper lane of `x` and the lower lane of `y`, or lanes of `y`.
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+fn alignr_bytes256(x: u8x32, y: u8x32, bytes: usize) -> u8x32 {
+    unsafe {
+        let mid: u8x32 = transmute(_mm256_permute2x128_si256(transmute(x), transmute(y), 0x21));
+        if bytes < 16 {
+            alignr_bytes!(_mm256_alignr_epi8, mid, x, bytes, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)
+        } else {
+            alignr_bytes!(_mm256_alignr_epi8, y, mid, bytes - 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)
+        }
+    }
+}
+
+// The permutes pick bytes or dwords, so each index is wrapped to the width of
+// the vector and scaled to the first part of its element in every part, then
+// the offset of each part within the element is added.
+macro_rules! impl_shuffle {
+    ($feat:expr, $permute:ident, $alignr:ident, $($vec:ty, $idx:ident, $iel:ty, $n:expr, $spread:expr, $offsets:expr),*) => {
+        $(
+            impl Shuffle for $vec {
+                type Indices = $idx;
+
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn permute(&self, indices: Self::Indices) -> Self {
+                    optimized!();
+                    let spread = (indices & $idx::splat((Self::WIDTH - 1) as $iel)) * $idx::splat($spread)
+                        + $idx::splat($offsets);
+                    unsafe { transmute($permute(transmute(*self), transmute(spread))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn permute(&self, indices: Self::Indices) -> Self {
+                    permute_fallback(self, &indices, |i| i as usize)
+                }
+
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn alignr(&self, other: Self, amount: usize) -> Self {
+                    optimized!();
+                    assert!(amount <= Self::WIDTH);
+                    let bytes = amount * (size_of::<Self>() / Self::WIDTH);
+                    unsafe { transmute($alignr(transmute(*self), transmute(other), bytes)) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn alignr(&self, other: Self, amount: usize) -> Self {
+                    alignr_fallback(self, other, amount)
+                }
+
+                impl_shuffle_lanes!($idx, $iel, $n);
+            }
+        )*
+    }
+}
+
+impl_shuffle!("ssse3", shuffle_bytes128, alignr_bytes128,
+              u8x16, u8x16, u8, 16, 1, 0, i8x16, u8x16, u8, 16, 1, 0,
+              u16x8, u16x8, u16, 8, 0x0202, 0x0100, i16x8, u16x8, u16, 8, 0x0202, 0x0100,
+              u32x4, u32x4, u32, 4, 0x0404_0404, 0x0302_0100, i32x4, u32x4, u32, 4, 0x0404_0404, 0x0302_0100,
+              f32x4, u32x4, u32, 4, 0x0404_0404, 0x0302_0100,
+              u64x2, u64x2, u64, 2, 0x0808_0808_0808_0808, 0x0706_0504_0302_0100,
+              i64x2, u64x2, u64, 2, 0x0808_0808_0808_0808, 0x0706_0504_0302_0100,
+              f64x2, u64x2, u64, 2, 0x0808_0808_0808_0808, 0x0706_0504_0302_0100);
+impl_shuffle!("avx2", shuffle_bytes256, alignr_bytes256,
+              u8x32, u8x32, u8, 32, 1, 0, i8x32, u8x32, u8, 32, 1, 0,
+              u16x16, u16x16, u16, 16, 0x0202, 0x0100, i16x16, u16x16, u16, 16, 0x0202, 0x0100);
+impl_shuffle!("avx2", permute_dwords256, alignr_bytes256,
+              u32x8, u32x8, u32, 8, 1, 0, i32x8, u32x8, u32, 8, 1, 0,
+              u64x4, u64x4, u64, 4, 0x0000_0002_0000_0002, 0x0000_0001_0000_0000,
+              i64x4, u64x4, u64, 4, 0x0000_0002_0000_0002, 0x0000_0001_0000_0000,
+              f64x4, u64x4, u64, 4, 0x0000_0002_0000_0002, 0x0000_0001_0000_0000);
+impl_shuffle!("avx2", permute_floats256, alignr_bytes256, f32x8, u32x8, u32, 8, 1, 0);
+impl_shuffle_fallback!(u8x64, u8x64, u8, 64, i8x64, u8x64, u8, 64, u16x32, u16x32, u16, 32, i16x32, u16x32, u16, 32,
+                       u32x16, u32x16, u32, 16, i32x16, u32x16, u32, 16, f32x16, u32x16, u32, 16,
+                       u64x8, u64x8, u64, 8, i64x8, u64x8, u64, 8, f64x8, u64x8, u64, 8);
+
+#[cfg(test)]
 mod tests {
     use crate::prelude::*;
+    use crate::arch::current::vecs::*;
 
     macro_rules! test_packed_swizzle {
         (($($vec:tt),*), ($($fn:tt),*)) => {
@@ -117,4 +260,14 @@ mod tests {
     test_packed_swizzle!((u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2),
                          (flip_u8x64, flip_u8x32, flip_u8x16, flip_i8x64, flip_i8x32, flip_i8x16, flip_u16x32, flip_u16x16, flip_u16x8, flip_i16x32, flip_i16x16, flip_i16x8, flip_u32x16, flip_u32x8, flip_u32x4, flip_i32x16, flip_i32x8, flip_i32x4, flip_f32x16, flip_f32x8, flip_f32x4, flip_u64x8, flip_u64x4, flip_u64x2, flip_i64x8, flip_i64x4, flip_i64x2, flip_f64x8, flip_f64x4, flip_f64x2));
 
+    test_shuffle!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2),
+                  (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                  (shuffle_u8x16, shuffle_i8x16, shuffle_u16x8, shuffle_i16x8, shuffle_u32x4,
+                   shuffle_i32x4, shuffle_f32x4, shuffle_u64x2, shuffle_i64x2, shuffle_f64x2));
+    test_shuffle!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4),
+                  (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64),
+                  (shuffle_u8x32, shuffle_i8x32, shuffle_u16x16, shuffle_i16x16, shuffle_u32x8,
+                   shuffle_i32x8, shuffle_f32x8, shuffle_u64x4, shuffle_i64x4, shuffle_f64x4));
+    test_shuffle!((i8x64, u16x32, f32x16, u64x8), (i8, u16, f32, u64),
+                  (shuffle_i8x64, shuffle_u16x32, shuffle_f32x16, shuffle_u64x8));
 }
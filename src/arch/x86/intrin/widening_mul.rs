@@ -0,0 +1,116 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::mul_hi::*;
+use crate::intrin::upcast::*;
+use crate::intrin::widening_mul::*;
+use crate::std::mem::transmute;
+
+// Products of upcast elements always fit in their lanes.
+macro_rules! impl_widening_mul_upcast {
+    ($($vec:ty, $out:ty),*) => {
+        $(
+            impl WideningMul for $vec {
+                type Out = $out;
+
+                #[inline(always)]
+                fn widening_mul(&self, other: Self) -> (Self::Out, Self::Out) {
+                    let (a_lo, a_hi) = Upcast::<$out>::upcast(*self);
+                    let (b_lo, b_hi) = Upcast::<$out>::upcast(other);
+                    (a_lo * b_lo, a_hi * b_hi)
+                }
+            }
+        )*
+    }
+}
+
+// Without a 32-bit multiply, the low and high halves of each product are
+// computed separately and interleaved.
+macro_rules! impl_widening_mul_unpack {
+    ($feat:expr, $($vec:ty, $out:ty, $wel:ty),*) => {
+        $(
+            impl WideningMul for $vec {
+                type Out = $out;
+
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn widening_mul(&self, other: Self) -> (Self::Out, Self::Out) {
+                    optimized!();
+                    let (lo, hi) = (*self * other, self.mul_hi(other));
+                    unsafe {
+                        (transmute(_mm_unpacklo_epi16(transmute(lo), transmute(hi))),
+                         transmute(_mm_unpackhi_epi16(transmute(lo), transmute(hi))))
+                    }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn widening_mul(&self, other: Self) -> (Self::Out, Self::Out) {
+                    widening_mul_fallback(self, &other, |a, b| a as $wel * b as $wel)
+                }
+            }
+        )*
+    }
+}
+
+// The 32x32-bit multiplies read the low half of each 64-bit lane, so the
+// elements are upcast first to line them up.
+macro_rules! impl_widening_mul_even {
+    ($feat:expr, $($vec:ty, $out:ty, $wel:ty, $mul:ident),*) => {
+        $(
+            impl WideningMul for $vec {
+                type Out = $out;
+
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn widening_mul(&self, other: Self) -> (Self::Out, Self::Out) {
+                    optimized!();
+                    let (a_lo, a_hi) = Upcast::<$out>::upcast(*self);
+                    let (b_lo, b_hi) = Upcast::<$out>::upcast(other);
+                    unsafe {
+                        (transmute($mul(transmute(a_lo), transmute(b_lo))),
+                         transmute($mul(transmute(a_hi), transmute(b_hi))))
+                    }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn widening_mul(&self, other: Self) -> (Self::Out, Self::Out) {
+                    widening_mul_fallback(self, &other, |a, b| a as $wel * b as $wel)
+                }
+            }
+        )*
+    }
+}
+
+impl_widening_mul_upcast!(u8x16, u16x8, i8x16, i16x8, u8x32, u16x16, i8x32, i16x16, u16x16, u32x8, i16x16, i32x8);
+impl_widening_mul_unpack!("sse2", u16x8, u32x4, u32, i16x8, i32x4, i32);
+impl_widening_mul_even!("sse2", u32x4, u64x2, u64, _mm_mul_epu32);
+impl_widening_mul_even!("sse4.1", i32x4, i64x2, i64, _mm_mul_epi32);
+impl_widening_mul_even!("avx2", u32x8, u64x4, u64, _mm256_mul_epu32, i32x8, i64x4, i64, _mm256_mul_epi32);
+impl_widening_mul_fallback!(u8x64, u16x32, u16, i8x64, i16x32, i16, u16x32, u32x16, u32, i16x32, i32x16, i32,
+                            u32x16, u64x8, u64, i32x16, i64x8, i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_widening_mul!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4),
+                       (u8, i8, u16, i16, u32, i32),
+                       (widening_mul_u8x16, widening_mul_i8x16, widening_mul_u16x8,
+                        widening_mul_i16x8, widening_mul_u32x4, widening_mul_i32x4));
+    test_widening_mul!((u8x32, i8x32, u16x16, i16x16, u32x8, i32x8),
+                       (u8, i8, u16, i16, u32, i32),
+                       (widening_mul_u8x32, widening_mul_i8x32, widening_mul_u16x16,
+                        widening_mul_i16x16, widening_mul_u32x8, widening_mul_i32x8));
+    test_widening_mul!((u32x16), (u32), (widening_mul_u32x16));
+}
@@ -0,0 +1,312 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Runtime selection of instruction set tiers.
+//!
+//! The vector types exported by faster are chosen when the crate is compiled,
+//! so a binary built for the x86-64 baseline will only ever use SSE2. The
+//! [`dispatch!`] macro compiles a kernel once per [`Tier`], with that tier's
+//! extensions enabled for code generation, and picks the best clone the
+//! running CPU supports the first time it is called. Kernels which are
+//! generic over a [`Widths`] family are also given the widest vectors their
+//! tier supports, so a baseline binary runs 256-bit vectors on AVX2
+//! processors.
+//!
+//! [`dispatch!`]: ../macro.dispatch.html
+//! [`Tier`]: enum.Tier.html
+//! [`Widths`]: ../widths/trait.Widths.html
+
+use crate::std::sync::atomic::{AtomicUsize, Ordering};
+use crate::arch::current::vecs::*;
+
+/// A set of instruction set extensions which a kernel may be compiled for.
+/// Tiers are ordered, such that every tier implies all tiers below it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tier {
+    /// No SIMD extensions; every operation uses its polyfill.
+    Fallback,
+    /// SSE and SSE2, which every x86-64 processor supports.
+    Sse2,
+    /// SSE2 through SSE4.1, including SSSE3.
+    Sse41,
+    /// SSE2 through SSE4.2, AVX, and AVX2.
+    Avx2,
+}
+
+// Zero until the tier is detected; afterwards, the index of the tier plus one.
+static DETECTED: AtomicUsize = AtomicUsize::new(0);
+
+impl Tier {
+    const ALL: [Tier; 4] = [Tier::Fallback, Tier::Sse2, Tier::Sse41, Tier::Avx2];
+
+    /// Return the highest tier enabled at compile time via `target_feature`.
+    #[inline(always)]
+    pub fn compiled() -> Tier {
+        if cfg!(target_feature = "avx2") {
+            Tier::Avx2
+        } else if cfg!(target_feature = "sse4.1") {
+            Tier::Sse41
+        } else if cfg!(target_feature = "sse2") {
+            Tier::Sse2
+        } else {
+            Tier::Fallback
+        }
+    }
+
+    /// Return the highest tier supported by the running processor. The
+    /// processor is only queried on the first call; subsequent calls return
+    /// a cached result.
+    ///
+    /// Without the `std` feature, no runtime detection is available, and this
+    /// is equivalent to `Tier::compiled()`.
+    #[inline(always)]
+    pub fn detect() -> Tier {
+        match DETECTED.load(Ordering::Relaxed) {
+            0 => {
+                let tier = Tier::query();
+                DETECTED.store(tier as usize + 1, Ordering::Relaxed);
+                tier
+            },
+            n => Tier::ALL[n - 1]
+        }
+    }
+
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    fn query() -> Tier {
+        // Never report less than what the binary already assumes.
+        let runtime = if is_x86_feature_detected!("avx2") {
+            Tier::Avx2
+        } else if is_x86_feature_detected!("sse4.1") {
+            Tier::Sse41
+        } else if is_x86_feature_detected!("sse2") {
+            Tier::Sse2
+        } else {
+            Tier::Fallback
+        };
+        crate::std::cmp::max(runtime, Tier::compiled())
+    }
+
+    #[cfg(not(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64"))))]
+    fn query() -> Tier {
+        Tier::compiled()
+    }
+}
+
+/// The 128-bit vectors of the current architecture, which the `Fallback`,
+/// `Sse2` and `Sse41` tiers run generic kernels with.
+#[derive(Clone, Copy, Debug)]
+pub struct Native128;
+
+/// The 256-bit vectors of the current architecture, which the `Avx2` tier runs
+/// generic kernels with.
+#[derive(Clone, Copy, Debug)]
+pub struct Native256;
+
+impl_widths!(Native128, 128, u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2);
+impl_widths!(Native256, 256, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4);
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __faster_tier_fn {
+    ($feat:tt, $name:ident, ($($arg:ident : $ty:ty),*), $ret:ty, $body:block) => {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = $feat)]
+        #[inline]
+        unsafe fn $name($($arg : $ty),*) -> $ret $body
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        #[inline(always)]
+        unsafe fn $name($($arg : $ty),*) -> $ret $body
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __faster_dispatch_body {
+    (($($arg:ident : $ty:ty),*), $ret:ty, $avx2:block, $sse41:block, $sse2:block, $fallback:block) => {{
+        $crate::__faster_tier_fn!("avx2", __faster_avx2, ($($arg : $ty),*), $ret, $avx2);
+        $crate::__faster_tier_fn!("sse4.1", __faster_sse41, ($($arg : $ty),*), $ret, $sse41);
+        $crate::__faster_tier_fn!("sse2", __faster_sse2, ($($arg : $ty),*), $ret, $sse2);
+
+        #[inline(always)]
+        fn __faster_fallback($($arg : $ty),*) -> $ret $fallback
+
+        match $crate::dispatch::Tier::detect() {
+            $crate::dispatch::Tier::Avx2 => unsafe { __faster_avx2($($arg),*) },
+            $crate::dispatch::Tier::Sse41 => unsafe { __faster_sse41($($arg),*) },
+            $crate::dispatch::Tier::Sse2 => unsafe { __faster_sse2($($arg),*) },
+            $crate::dispatch::Tier::Fallback => __faster_fallback($($arg),*),
+        }
+    }};
+    (($($arg:ident : $ty:ty),*), $ret:ty, $body:block) => {
+        $crate::__faster_dispatch_body!(($($arg : $ty),*), $ret, $body, $body, $body, $body)
+    };
+    (<$w:ident> ($($arg:ident : $ty:ty),*), $ret:ty, $body:block) => {{
+        #[inline(always)]
+        fn __faster_kernel<$w : $crate::widths::Widths>($($arg : $ty),*) -> $ret $body
+
+        $crate::__faster_dispatch_body!(($($arg : $ty),*), $ret,
+                                        { __faster_kernel::<$crate::dispatch::Native256>($($arg),*) },
+                                        { __faster_kernel::<$crate::dispatch::Native128>($($arg),*) },
+                                        { __faster_kernel::<$crate::dispatch::Native128>($($arg),*) },
+                                        { __faster_kernel::<$crate::dispatch::Native128>($($arg),*) })
+    }};
+}
+
+/// Define a function which is compiled once for every [`Tier`], and which
+/// runs the version best suited to the processor it finds itself on.
+///
+/// The highest supported tier is detected on the first call of any
+/// dispatched function and cached afterwards, so the per-call overhead is a
+/// relaxed atomic load and a branch.
+///
+/// Every clone is compiled with the instruction set extensions of its tier
+/// enabled. The widths of `u8s`, `f32s`, etc. are fixed when the crate is
+/// compiled, so a kernel should instead be generic over a [`Widths`] family
+/// `W`, and build its vectors from `W::f32s` and friends (typically via
+/// `simd_iter_as`). The `Avx2` tier then runs it with 256-bit vectors and the
+/// other tiers with 128-bit vectors, whichever tier the crate was compiled
+/// for. The arithmetic, bitwise and comparison operators of those vectors are
+/// lowered with the tier's instructions; operations which faster implements
+/// with explicit intrinsics, selected by `cfg(target_feature)`, still use the
+/// implementation chosen when the crate was compiled.
+///
+/// A kernel which isn't generic is compiled for every tier as well, but keeps
+/// the vector types it names. Dispatched functions may have no generic
+/// parameters besides the family, and their arguments must be plain
+/// identifiers.
+///
+/// [`Tier`]: dispatch/enum.Tier.html
+/// [`Widths`]: widths/trait.Widths.html
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate faster;
+/// use faster::*;
+///
+/// dispatch! {
+///     fn sum_squares<W>(data: &[f32]) -> f32 {
+///         data.simd_iter_as(W::f32s::splat(0.0))
+///             .simd_reduce(W::f32s::splat(0.0), |acc, v| acc + v * v)
+///             .sum()
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_eq!(sum_squares(&[2.0f32; 100]), 400.0);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! dispatch {
+    ($(#[$attr:meta])* pub fn $name:ident<$w:ident>($($arg:ident : $ty:ty),*) -> $ret:ty $body:block $($rest:tt)*) => {
+        $(#[$attr])*
+        pub fn $name($($arg : $ty),*) -> $ret {
+            $crate::__faster_dispatch_body!(<$w> ($($arg : $ty),*), $ret, $body)
+        }
+        $crate::dispatch!($($rest)*);
+    };
+    ($(#[$attr:meta])* fn $name:ident<$w:ident>($($arg:ident : $ty:ty),*) -> $ret:ty $body:block $($rest:tt)*) => {
+        $(#[$attr])*
+        fn $name($($arg : $ty),*) -> $ret {
+            $crate::__faster_dispatch_body!(<$w> ($($arg : $ty),*), $ret, $body)
+        }
+        $crate::dispatch!($($rest)*);
+    };
+    ($(#[$attr:meta])* pub fn $name:ident<$w:ident>($($arg:ident : $ty:ty),*) $body:block $($rest:tt)*) => {
+        $crate::dispatch!($(#[$attr])* pub fn $name<$w>($($arg : $ty),*) -> () $body $($rest)*);
+    };
+    ($(#[$attr:meta])* fn $name:ident<$w:ident>($($arg:ident : $ty:ty),*) $body:block $($rest:tt)*) => {
+        $crate::dispatch!($(#[$attr])* fn $name<$w>($($arg : $ty),*) -> () $body $($rest)*);
+    };
+    ($(#[$attr:meta])* pub fn $name:ident($($arg:ident : $ty:ty),*) -> $ret:ty $body:block $($rest:tt)*) => {
+        $(#[$attr])*
+        pub fn $name($($arg : $ty),*) -> $ret {
+            $crate::__faster_dispatch_body!(($($arg : $ty),*), $ret, $body)
+        }
+        $crate::dispatch!($($rest)*);
+    };
+    ($(#[$attr:meta])* fn $name:ident($($arg:ident : $ty:ty),*) -> $ret:ty $body:block $($rest:tt)*) => {
+        $(#[$attr])*
+        fn $name($($arg : $ty),*) -> $ret {
+            $crate::__faster_dispatch_body!(($($arg : $ty),*), $ret, $body)
+        }
+        $crate::dispatch!($($rest)*);
+    };
+    ($(#[$attr:meta])* pub fn $name:ident($($arg:ident : $ty:ty),*) $body:block $($rest:tt)*) => {
+        $crate::dispatch!($(#[$attr])* pub fn $name($($arg : $ty),*) -> () $body $($rest)*);
+    };
+    ($(#[$attr:meta])* fn $name:ident($($arg:ident : $ty:ty),*) $body:block $($rest:tt)*) => {
+        $crate::dispatch!($(#[$attr])* fn $name($($arg : $ty),*) -> () $body $($rest)*);
+    };
+    () => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::Tier;
+
+    dispatch! {
+        fn add_one(data: &[u32], out: &mut [u32]) {
+            data.simd_iter(u32s(0))
+                .simd_map(|v| v + u32s(1))
+                .scalar_fill(out);
+        }
+
+        pub fn product(data: &[f64]) -> f64 {
+            data.simd_iter(f64s(1.0))
+                .simd_reduce(f64s(1.0), |acc, v| acc * v)
+                .product()
+        }
+
+        fn vector_bits<W>() -> usize {
+            W::BITS
+        }
+
+        fn double<W>(data: &[f32], out: &mut [f32]) {
+            data.simd_iter_as(W::f32s::splat(0.0))
+                .simd_map(|v| v + v)
+                .scalar_fill(out);
+        }
+    }
+
+    #[test]
+    fn detect_is_cached() {
+        let tier = Tier::detect();
+        assert!(tier >= Tier::compiled());
+        assert_eq!(tier, Tier::detect());
+    }
+
+    #[test]
+    fn dispatched_kernels() {
+        let data = [7u32; 35];
+        let mut out = [0u32; 35];
+        add_one(&data, &mut out);
+        assert_eq!(&out[..], &[8u32; 35][..]);
+        assert_eq!(product(&[2.0f64; 10]), 1024.0);
+    }
+
+    #[test]
+    fn generic_kernels_use_tier_widths() {
+        // The 256-bit vectors are picked by the processor, not by the
+        // features the crate was compiled with, so an SSE2 build runs them
+        // on AVX2 processors too.
+        let bits = if Tier::detect() == Tier::Avx2 { 256 } else { 128 };
+        assert_eq!(vector_bits(), bits);
+
+        let mut data = [0.0f32; 37];
+        for (i, x) in data.iter_mut().enumerate() {
+            *x = i as f32;
+        }
+        let mut out = [0.0f32; 37];
+        double(&data, &mut out);
+        for i in 0..data.len() {
+            assert_eq!(out[i], data[i] * 2.0);
+        }
+    }
+}
@@ -0,0 +1,457 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Storage types for floats narrower than `f32`.
+//!
+//! Arithmetic isn't done on these types directly. Instead, slices of them are
+//! widened to vectors of `f32s` when loaded, and narrowed again when stored.
+//! Vectors of bfloat16s are held in `u16s`, which widen to `f32s` with
+//! `UpcastBf16` and narrow with `DowncastBf16`.
+
+use crate::std::slice::{from_raw_parts, from_raw_parts_mut};
+use crate::arch::current::vecs::*;
+use crate::intrin::half::*;
+use crate::into_iters::{IntoSIMDRefIterator, IntoSIMDRefMutIterator};
+use crate::iters::{SIMDArray, SIMDArrayMut, SIMDIter, SIMDObject, SIMDSized};
+
+/// An IEEE 754 binary16 float, stored as its bits.
+///
+/// Two halves are equal if their bits are equal, so NaNs may equal each other
+/// and zeroes of opposite signs don't.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct f16(u16);
+
+impl f16 {
+    /// Return the half with the bits `bits`.
+    #[inline(always)]
+    pub fn from_bits(bits: u16) -> Self {
+        f16(bits)
+    }
+
+    /// Return the bits of this half.
+    #[inline(always)]
+    pub fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Return the half nearest to `x`, with ties rounding to even. Values
+    /// beyond the range of a half become infinite, and NaNs keep as much of
+    /// their payload as fits.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f16::from_f32(1.5).to_bits(), 0x3E00);
+    /// assert_eq!(f16::from_f32(65520.0).to_bits(), 0x7C00);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn from_f32(x: f32) -> Self {
+        let bits = x.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let abs = bits & 0x7FFF_FFFF;
+        let half = if abs > 0x7F80_0000 {
+            // NaN, which is kept quiet
+            0x7E00 | ((abs >> 13) & 0x3FF) as u16
+        } else if abs >= 0x477F_F000 {
+            // Halfway past the largest half, or infinite
+            0x7C00
+        } else if abs >= 0x3880_0000 {
+            // Normal; the exponent is rebased and the dropped bits round the
+            // mantissa, carrying into the exponent if need be
+            let rebased = abs - 0x3800_0000;
+            ((rebased + 0xFFF + ((rebased >> 13) & 1)) >> 13) as u16
+        } else {
+            // Subnormal; adding 0.5 leaves the mantissa in units of 2^-24,
+            // rounded by the addition
+            (f32::from_bits(abs) + 0.5).to_bits().wrapping_sub(0x3F00_0000) as u16
+        };
+        f16(sign | half)
+    }
+
+    /// Return this half as an f32, which is always exact. NaNs are quieted.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f16::from_bits(0xC500).to_f32(), -5.0);
+    /// assert_eq!(f16::from_bits(0x0001).to_f32(), 5.9604645e-8);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let abs = (self.0 & 0x7FFF) as u32;
+        if abs > 0x7C00 {
+            f32::from_bits(sign | 0x7FC0_0000 | ((abs & 0x3FF) << 13))
+        } else if abs == 0x7C00 {
+            f32::from_bits(sign | 0x7F80_0000)
+        } else if abs >= 0x0400 {
+            f32::from_bits(sign | ((abs << 13) + 0x3800_0000))
+        } else {
+            f32::from_bits(sign | (abs as f32 * (1.0 / 16777216.0)).to_bits())
+        }
+    }
+}
+
+/// A bfloat16 float, stored as its bits. It has the exponent range of an f32,
+/// but only eight bits of precision, so its bits are those of the top half
+/// of an f32.
+///
+/// Two bfloat16s are equal if their bits are equal, as with `f16`.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct bf16(u16);
+
+impl bf16 {
+    /// Return the bfloat16 with the bits `bits`.
+    #[inline(always)]
+    pub fn from_bits(bits: u16) -> Self {
+        bf16(bits)
+    }
+
+    /// Return the bits of this bfloat16.
+    #[inline(always)]
+    pub fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Return the bfloat16 nearest to `x`, with ties rounding to even. NaNs
+    /// are quieted, and keep the top of their payload.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(bf16::from_f32(1.0 + 1.0 / 256.0).to_bits(), 0x3F80);
+    /// assert_eq!(bf16::from_f32(-3.0).to_bits(), 0xC040);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn from_f32(x: f32) -> Self {
+        let bits = x.to_bits();
+        if x.is_nan() {
+            bf16((bits >> 16) as u16 | 0x0040)
+        } else {
+            bf16(((bits + 0x7FFF + ((bits >> 16) & 1)) >> 16) as u16)
+        }
+    }
+
+    /// Return this bfloat16 as an f32, which is always exact.
+    #[inline(always)]
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+}
+
+#[inline(always)]
+pub(crate) fn f16_bits(data: &[f16]) -> &[u16] {
+    // f16 is a transparent wrapper of u16
+    unsafe { from_raw_parts(data.as_ptr() as *const u16, data.len()) }
+}
+
+#[inline(always)]
+pub(crate) fn f16_bits_mut(data: &mut [f16]) -> &mut [u16] {
+    unsafe { from_raw_parts_mut(data.as_mut_ptr() as *mut u16, data.len()) }
+}
+
+/// A slice of halves which is widened to vectors of f32s as it is loaded.
+/// See `IntoSIMDRefIterator` for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct HalfSlice<'a> {
+    data: &'a [f16],
+}
+
+impl<'a> HalfSlice<'a> {
+    /// Wrap `data`, such that it is loaded as vectors of f32s.
+    #[inline(always)]
+    pub fn new(data: &'a [f16]) -> Self {
+        HalfSlice { data: data }
+    }
+}
+
+impl<'a> SIMDObject for HalfSlice<'a> {
+    type Vector = f32s;
+    type Scalar = f32;
+}
+
+impl<'a> SIMDSized for HalfSlice<'a> {
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl<'a> SIMDArray for HalfSlice<'a> {
+    #[inline(always)]
+    fn load(&self, offset: usize) -> Self::Vector {
+        f32s::load_f16(self.data, offset)
+    }
+
+    #[inline(always)]
+    unsafe fn load_unchecked(&self, offset: usize) -> Self::Vector {
+        f32s::load_f16_unchecked(self.data, offset)
+    }
+
+    #[inline(always)]
+    fn load_scalar(&self, offset: usize) -> Self::Scalar {
+        self.data[offset].to_f32()
+    }
+
+    #[inline(always)]
+    unsafe fn load_scalar_unchecked(&self, offset: usize) -> Self::Scalar {
+        debug_assert!(offset < self.data.len());
+        self.data.get_unchecked(offset).to_f32()
+    }
+}
+
+/// A mutable slice of halves which is widened to vectors of f32s as it is
+/// loaded, and narrowed back to halves as it is stored. See
+/// `IntoSIMDRefMutIterator` for more information.
+#[derive(Debug)]
+pub struct HalfSliceMut<'a> {
+    data: &'a mut [f16],
+}
+
+impl<'a> HalfSliceMut<'a> {
+    /// Wrap `data`, such that it is loaded and stored as vectors of f32s.
+    #[inline(always)]
+    pub fn new(data: &'a mut [f16]) -> Self {
+        HalfSliceMut { data: data }
+    }
+}
+
+impl<'a> SIMDObject for HalfSliceMut<'a> {
+    type Vector = f32s;
+    type Scalar = f32;
+}
+
+impl<'a> SIMDSized for HalfSliceMut<'a> {
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl<'a> SIMDArray for HalfSliceMut<'a> {
+    #[inline(always)]
+    fn load(&self, offset: usize) -> Self::Vector {
+        f32s::load_f16(self.data, offset)
+    }
+
+    #[inline(always)]
+    unsafe fn load_unchecked(&self, offset: usize) -> Self::Vector {
+        f32s::load_f16_unchecked(self.data, offset)
+    }
+
+    #[inline(always)]
+    fn load_scalar(&self, offset: usize) -> Self::Scalar {
+        self.data[offset].to_f32()
+    }
+
+    #[inline(always)]
+    unsafe fn load_scalar_unchecked(&self, offset: usize) -> Self::Scalar {
+        debug_assert!(offset < self.data.len());
+        self.data.get_unchecked(offset).to_f32()
+    }
+}
+
+impl<'a> SIMDArrayMut for HalfSliceMut<'a> {
+    #[inline(always)]
+    fn store(&mut self, value: Self::Vector, offset: usize) {
+        value.store_f16(self.data, offset)
+    }
+
+    #[inline(always)]
+    unsafe fn store_unchecked(&mut self, value: Self::Vector, offset: usize) {
+        value.store_f16_unchecked(self.data, offset)
+    }
+
+    #[inline(always)]
+    fn store_scalar(&mut self, value: Self::Scalar, offset: usize) {
+        self.data[offset] = f16::from_f32(value);
+    }
+
+    #[inline(always)]
+    unsafe fn store_scalar_unchecked(&mut self, value: Self::Scalar, offset: usize) {
+        debug_assert!(offset < self.data.len());
+        *self.data.get_unchecked_mut(offset) = f16::from_f32(value);
+    }
+}
+
+impl<'a> IntoSIMDRefIterator<'a> for &'a [f16] {
+    type Iter = SIMDIter<HalfSlice<'a>>;
+
+    #[inline(always)]
+    fn simd_iter(&'a self, default: f32s) -> Self::Iter {
+        SIMDIter {
+            data: HalfSlice::new(self),
+            position: 0,
+            default: default,
+        }
+    }
+}
+
+impl<'a> IntoSIMDRefIterator<'a> for [f16] {
+    type Iter = SIMDIter<HalfSlice<'a>>;
+
+    /// Return an iterator over this data which widens it into vectors of
+    /// f32s.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let weights = [f16::from_f32(0.25); 100];
+    /// let total = weights.simd_iter(f32s(0.0))
+    ///     .simd_reduce(f32s(0.0), |acc, v| acc + v)
+    ///     .sum();
+    /// assert_eq!(total, 25.0);
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn simd_iter(&'a self, default: f32s) -> Self::Iter {
+        SIMDIter {
+            data: HalfSlice::new(self),
+            position: 0,
+            default: default,
+        }
+    }
+}
+
+impl<'a> IntoSIMDRefMutIterator<'a> for &'a mut [f16] {
+    type Iter = SIMDIter<HalfSliceMut<'a>>;
+
+    #[inline(always)]
+    fn simd_iter_mut(&'a mut self, default: f32s) -> Self::Iter {
+        SIMDIter {
+            data: HalfSliceMut::new(self),
+            position: 0,
+            default: default,
+        }
+    }
+}
+
+impl<'a> IntoSIMDRefMutIterator<'a> for [f16] {
+    type Iter = SIMDIter<HalfSliceMut<'a>>;
+
+    /// Return an iterator over this data which widens it into vectors of
+    /// f32s, and rounds them back to halves when they are stored.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mut weights = [f16::from_f32(0.25); 100];
+    /// weights.simd_iter_mut(f32s(0.0)).simd_for_each(|v| *v = *v * f32s(3.0));
+    /// assert!(weights.iter().all(|w| w.to_f32() == 0.75));
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn simd_iter_mut(&'a mut self, default: f32s) -> Self::Iter {
+        SIMDIter {
+            data: HalfSliceMut::new(self),
+            position: 0,
+            default: default,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::*;
+
+    #[test]
+    fn f16_to_f32() {
+        assert_eq!(f16::from_bits(0x3C00).to_f32(), 1.0);
+        assert_eq!(f16::from_bits(0x7BFF).to_f32(), 65504.0);
+        assert_eq!(f16::from_bits(0x0400).to_f32(), 1.0 / 16384.0);
+        assert_eq!(f16::from_bits(0x03FF).to_f32(), 1023.0 / 16777216.0);
+        assert_eq!(f16::from_bits(0x8000).to_f32().to_bits(), 0x8000_0000);
+        assert_eq!(f16::from_bits(0xFC00).to_f32(), -1.0 / 0.0);
+        assert_eq!(f16::from_bits(0x7C01).to_f32().to_bits(), 0x7FC0_2000);
+    }
+
+    #[test]
+    fn f32_to_f16() {
+        let cases = [(1.0f32, 0x3C00u16), (-2.0, 0xC000), (-0.0, 0x8000), (65504.0, 0x7BFF), (65519.0, 0x7BFF),
+                     (65520.0, 0x7C00), (1e10, 0x7C00), (-1.0 / 0.0, 0xFC00), (1.0 + 1.0 / 2048.0, 0x3C00),
+                     (1.0 + 3.0 / 2048.0, 0x3C02), (1.0 / 33554432.0, 0), (1.5 / 33554432.0, 0x0001),
+                     (3.0 / 33554432.0, 0x0002), (1e-10, 0), (1023.5 / 16777216.0, 0x0400),
+                     (f32::from_bits(0x7F80_2001), 0x7E01), (f32::from_bits(0xFFC0_0000), 0xFE00)];
+        for &(x, bits) in cases.iter() {
+            assert_eq!(f16::from_f32(x).to_bits(), bits, "f16::from_f32({:?})", x);
+        }
+    }
+
+    #[test]
+    fn f16_round_trip() {
+        for bits in 0..0x10000u32 {
+            let half = f16::from_bits(bits as u16);
+            let back = f16::from_f32(half.to_f32());
+            if half.to_f32().is_nan() {
+                assert_eq!(back.to_bits(), bits as u16 | 0x0200);
+            } else {
+                assert_eq!(back, half);
+            }
+        }
+    }
+
+    #[test]
+    fn bf16_conversions() {
+        let cases = [(1.0f32, 0x3F80u16), (-0.0, 0x8000), (1.0 + 1.0 / 256.0, 0x3F80), (1.0 + 3.0 / 256.0, 0x3F82),
+                     (f32::from_bits(0x3F80_8001), 0x3F81), (f32::from_bits(0x7F7F_FFFF), 0x7F80),
+                     (f32::from_bits(0x0000_8000), 0x0000), (f32::from_bits(0x7F80_0001), 0x7FC0),
+                     (f32::from_bits(0xFFA0_0000), 0xFFE0), (1.0 / 0.0, 0x7F80)];
+        for &(x, bits) in cases.iter() {
+            assert_eq!(bf16::from_f32(x).to_bits(), bits, "bf16::from_f32({:?})", x);
+        }
+        for bits in 0..0x10000u32 {
+            let x = bf16::from_bits(bits as u16).to_f32();
+            assert_eq!(x.to_bits(), bits << 16);
+            if !x.is_nan() {
+                assert_eq!(bf16::from_f32(x).to_bits(), bits as u16);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn f16_simd_iter() {
+        let halves = (0..99).map(|x| f16::from_f32(x as f32 - 49.5)).collect::<Vec<_>>();
+        let doubled = (&halves[..]).simd_iter(f32s(0.0)).simd_map(|v| v + v).scalar_collect();
+        assert_eq!(doubled, (0..99).map(|x| 2.0 * (x as f32 - 49.5)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn f16_simd_iter_mut() {
+        let mut halves = [f16::default(); 99];
+        for i in 0..halves.len() {
+            halves[i] = f16::from_f32(i as f32 - 49.5);
+        }
+        halves.simd_iter_mut(f32s(0.0)).simd_for_each(|v| *v = *v + *v);
+        for i in 0..halves.len() {
+            assert_eq!(halves[i].to_f32(), 2.0 * (i as f32 - 49.5));
+        }
+
+        // Rounds to the nearest half when storing
+        let mut halves = [f16::from_f32(1.0); 3];
+        (&mut halves[..]).simd_iter_mut(f32s(0.0)).simd_for_each(|v| *v = *v + f32s(1.0 / 4096.0));
+        assert!(halves.iter().all(|h| h.to_bits() == 0x3C00));
+    }
+}
@@ -5,7 +5,8 @@
 // License, v. 2.0. If a copy owf the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::iters::{SIMDIter, SIMDIterator, SIMDObject};
+use crate::iters::{SIMDIter, SIMDIterator, SIMDObject, PackedAs};
+use crate::vecs::Packed;
 #[allow(unused_imports)] // Remove for specialization
 use crate::iters::SIMDAdapter;
 use crate::arch::current::vecs::*;
@@ -19,6 +20,20 @@ pub trait IntoSIMDIterator {
     /// values into SIMD vectors. See `SIMDIterator::simd_map` and
     /// `SIMDIterator::simd_reduce` for more information.
     fn into_simd_iter(self, default: <Self::Iter as SIMDObject>::Vector) -> Self::Iter;
+
+    /// Return an iterator over this data which will automatically pack
+    /// values into vectors of type `V`, rather than the native vector type
+    /// of the data. See `IntoSIMDRefIterator::simd_iter_as` for more
+    /// information.
+    #[inline(always)]
+    fn into_simd_iter_as<V>(self, default: V) -> SIMDIter<PackedAs<Self, V>>
+        where Self : Sized + AsRef<[V::Scalar]>, V : Packed<Scalar = <Self::Iter as SIMDObject>::Scalar> {
+        SIMDIter {
+            data: PackedAs::new(self),
+            position: 0,
+            default: default,
+        }
+    }
 }
 
 /// A trait which transforms a contiguous collection into a slice-backed stream
@@ -30,6 +45,43 @@ pub trait IntoSIMDRefIterator<'a> {
     /// values into SIMD vectors. See `SIMDIterator::simd_map` and
     /// `SIMDIterator::simd_reduce` for more information.
     fn simd_iter(&'a self, default: <Self::Iter as SIMDObject>::Vector) -> Self::Iter;
+
+    /// Return an iterator over this data which will automatically pack
+    /// values into vectors of type `V`, rather than the native vector type
+    /// of the data. Any vector type whose elements match the collection may
+    /// be used, which is useful when the layout of the data is tied to a
+    /// certain width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::f32x4;
+    ///
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() { }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// // Halve the alpha channel of some RGBA pixels, regardless of the
+    /// // width of f32s on this machine.
+    /// let pixels = [1.0f32; 64];
+    /// let faded = pixels.simd_iter_as(f32x4::splat(0.0))
+    ///     .simd_map(|v| v * f32x4::new(1.0, 1.0, 1.0, 0.5))
+    ///     .scalar_collect();
+    /// assert_eq!(&faded[..4], &[1.0, 1.0, 1.0, 0.5]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn simd_iter_as<V>(&'a self, default: V) -> SIMDIter<PackedAs<&'a Self, V>>
+        where Self : AsRef<[V::Scalar]>, V : Packed<Scalar = <Self::Iter as SIMDObject>::Scalar> {
+        SIMDIter {
+            data: PackedAs::new(self),
+            position: 0,
+            default: default,
+        }
+    }
 }
 
 /// A trait which transforms a contiguous collection into a mutable slice-backed
@@ -41,6 +93,20 @@ pub trait IntoSIMDRefMutIterator<'a> {
     /// values into SIMD vectors. See `SIMDIterator::simd_map` and
     /// `SIMDIterator::simd_reduce` for more information.
     fn simd_iter_mut(&'a mut self, default: <Self::Iter as SIMDObject>::Vector) -> Self::Iter;
+
+    /// Return an iterator over this data which will automatically pack
+    /// values into vectors of type `V`, rather than the native vector type
+    /// of the data. See `IntoSIMDRefIterator::simd_iter_as` for more
+    /// information.
+    #[inline(always)]
+    fn simd_iter_mut_as<V>(&'a mut self, default: V) -> SIMDIter<PackedAs<&'a mut Self, V>>
+        where Self : AsRef<[V::Scalar]> + AsMut<[V::Scalar]>, V : Packed<Scalar = <Self::Iter as SIMDObject>::Scalar> {
+        SIMDIter {
+            data: PackedAs::new(self),
+            position: 0,
+            default: default,
+        }
+    }
 }
 
 macro_rules! impl_array_intos {
@@ -23,6 +23,25 @@ pub trait Abs {
     fn abs(&self) -> Self::Out;
 }
 
+// Only float vectors keep their type; the absolute values of signed integer
+// vectors are returned as unsigned vectors.
+#[allow(unused_macros)]
+macro_rules! impl_abs_float_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Abs for $vec {
+                type Out = Self;
+
+                #[inline(always)]
+                fn abs(&self) -> Self::Out {
+                    fallback!();
+                    (0..Self::WIDTH).fold(*self, |acc, i| acc.replace(i, self.extract(i).abs()))
+                }
+            }
+        )*
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -0,0 +1,194 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+pub trait AbsDiff : Packed {
+    type Out : Packed;
+
+    /// Return a vector containing the absolute difference between each pair
+    /// of elements of `self` and `other`. The difference between integers is
+    /// exact, and is returned as an unsigned vector so that it cannot
+    /// overflow.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(3).abs_diff(u8s::interleave(10, 0)), u8s::interleave(7, 3));
+    /// assert_eq!(i8s(-128).abs_diff(i8s(127)), u8s(255));
+    /// assert_eq!(f32s(1.5).abs_diff(f32s(4.0)), f32s(2.5));
+    /// # }
+    /// ```
+    fn abs_diff(&self, other: Self) -> Self::Out;
+}
+
+pub trait Sad : Packed {
+    type Out : Packed;
+
+    /// Return a vector whose element at index i is the sum of the absolute
+    /// differences between the elements of `self` and `other` at indices 8i
+    /// through 8i + 7.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::interleave(255, 0).sad(u8s(10)), u64s(4 * 245 + 4 * 10));
+    /// # }
+    /// ```
+    fn sad(&self, other: Self) -> Self::Out;
+}
+
+#[inline(always)]
+pub(crate) fn abs_diff_fallback<T, U, F>(a: &T, b: &T, op: F) -> U
+    where T : Packed, U : Packed, F : Fn(T::Scalar, T::Scalar) -> U::Scalar {
+    fallback!();
+    let mut ret = U::default();
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(a.extract(i), b.extract(i)));
+    }
+    ret
+}
+
+#[inline(always)]
+pub(crate) fn sad_fallback<T, U>(a: &T, b: &T) -> U
+    where T : Packed<Scalar = u8>, U : Packed<Scalar = u64> {
+    fallback!();
+    let mut ret = U::default();
+    for i in 0..T::WIDTH {
+        let (x, y) = (a.extract(i), b.extract(i));
+        let diff = if x > y { x - y } else { y - x };
+        ret = ret.replace(i / 8, ret.extract(i / 8) + diff as u64);
+    }
+    ret
+}
+
+// The difference is taken in the wrapping arithmetic of the element type,
+// which holds the exact distance once read as unsigned. Floats have their
+// sign bit cleared, so that equal zeroes of opposite signs give +0.
+#[allow(unused_macros)]
+macro_rules! impl_abs_diff_fallback {
+    (int, $($vec:ty, $out:ty, $uel:ty),*) => {
+        $(
+            impl AbsDiff for $vec {
+                type Out = $out;
+
+                #[inline(always)]
+                fn abs_diff(&self, other: Self) -> Self::Out {
+                    abs_diff_fallback(self, &other, |a, b| {
+                        if a > b { a.wrapping_sub(b) as $uel } else { b.wrapping_sub(a) as $uel }
+                    })
+                }
+            }
+        )*
+    };
+    (float, $($vec:ty, $el:ident, $abs_mask:expr),*) => {
+        $(
+            impl AbsDiff for $vec {
+                type Out = $vec;
+
+                #[inline(always)]
+                fn abs_diff(&self, other: Self) -> Self::Out {
+                    abs_diff_fallback(self, &other, |a, b| $el::from_bits((a - b).to_bits() & $abs_mask))
+                }
+            }
+        )*
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_sad_fallback {
+    ($($vec:ty, $out:ty),*) => {
+        $(
+            impl Sad for $vec {
+                type Out = $out;
+
+                #[inline(always)]
+                fn sad(&self, other: Self) -> Self::Out {
+                    sad_fallback(self, &other)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_abs_diff {
+    (int, ($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let specials = crate::intrin::specials::<$el>();
+                for &a in specials.iter() {
+                    for &b in specials.iter() {
+                        let expected = (a as i128 - b as i128).abs();
+                        let diff = $vec::splat(a).abs_diff($vec::splat(b));
+                        for i in 0..$vec::WIDTH {
+                            assert_eq!(diff.extract(i) as i128, expected, "|{:?} - {:?}|", a, b);
+                        }
+                    }
+                }
+
+                let (min, max) = ($el::min_value(), $el::max_value());
+                let diff = $vec::interleave(min, max).abs_diff($vec::interleave(max, 1 as $el));
+                for i in 0..$vec::WIDTH {
+                    let (a, b) = if i % 2 == 0 { (min, max) } else { (max, 1 as $el) };
+                    assert_eq!(diff.extract(i) as i128, (a as i128 - b as i128).abs());
+                }
+            }
+        )*
+    };
+    (float, ($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let specials = crate::intrin::specials::<$el>();
+                for &a in specials.iter() {
+                    for &b in specials.iter() {
+                        let diff = $vec::splat(a).abs_diff($vec::splat(b));
+                        let expected = (a - b).abs();
+                        for i in 0..$vec::WIDTH {
+                            let d = diff.extract(i);
+                            assert!(d.to_bits() == expected.to_bits() || (d.is_nan() && expected.is_nan()),
+                                    "|{:?} - {:?}| = {:?}", a, b, d);
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! test_sad {
+    (($($vec:tt),*), ($($out:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!($vec::interleave(255, 0).sad($vec::splat(10)), $out::splat(4 * 245 + 4 * 10));
+                assert_eq!($vec::splat(7).sad($vec::splat(7)), $out::splat(0));
+
+                let mut a = $vec::splat(0);
+                let mut b = $vec::splat(0);
+                for i in 0..$vec::WIDTH {
+                    a = a.replace(i, (i as u8).wrapping_mul(73));
+                    b = b.replace(i, (i as u8).wrapping_mul(151).wrapping_add(9));
+                }
+                let sums = a.sad(b);
+                for j in 0..$out::WIDTH {
+                    let expected = (8 * j..8 * j + 8)
+                        .map(|i| (a.extract(i) as i64 - b.extract(i) as i64).abs() as u64)
+                        .sum::<u64>();
+                    assert_eq!(sums.extract(j), expected);
+                }
+            }
+        )*
+    }
+}
@@ -0,0 +1,174 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+use crate::intrin::ord::PackedOrd;
+
+pub trait ArgMinMax : Packed {
+    /// The vector of unsigned integers as wide as the elements of `Self`,
+    /// which numbers its lanes.
+    type Indices : Packed;
+
+    /// Return the index of the smallest element of this vector. If several
+    /// elements are equally small, the lowest index among them is returned.
+    /// NaNs are never chosen unless every element is NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s::interleave(3, 1).hargmin(), 1);
+    /// assert_eq!(u16s::halfs(2, 2).hargmin(), 0);
+    /// # }
+    /// ```
+    fn hargmin(&self) -> usize;
+
+    /// Return the index of the largest element of this vector. If several
+    /// elements are equally large, the lowest index among them is returned.
+    /// NaNs are never chosen unless every element is NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s::interleave(3, 1).hargmax(), 0);
+    /// assert_eq!(u16s::halfs(1, 2).hargmax(), u16s::WIDTH / 2);
+    /// # }
+    /// ```
+    fn hargmax(&self) -> usize;
+}
+
+#[inline(always)]
+fn is_nan<T : PartialOrd>(x: &T) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+/// Return whether `x` should replace `best` as the smallest value seen. A NaN
+/// is replaced by any other value, and never replaces anything.
+#[inline(always)]
+pub(crate) fn replaces_min<T : PartialOrd>(x: T, best: T) -> bool {
+    x < best || (is_nan(&best) && !is_nan(&x))
+}
+
+/// Return whether `x` should replace `best` as the largest value seen.
+#[inline(always)]
+pub(crate) fn replaces_max<T : PartialOrd>(x: T, best: T) -> bool {
+    x > best || (is_nan(&best) && !is_nan(&x))
+}
+
+/// Return a mask of the lanes where `x` should replace `best` as the smallest
+/// value seen, by the rules of `replaces_min`.
+#[inline(always)]
+pub(crate) fn beats_min<T : PackedOrd>(x: &T, best: &T) -> T::Out {
+    x.lt_mask(*best) | (best.unordered_mask(*best) & !x.unordered_mask(*x))
+}
+
+/// Return a mask of the lanes where `x` should replace `best` as the largest
+/// value seen, by the rules of `replaces_max`.
+#[inline(always)]
+pub(crate) fn beats_max<T : PackedOrd>(x: &T, best: &T) -> T::Out {
+    x.gt_mask(*best) | (best.unordered_mask(*best) & !x.unordered_mask(*x))
+}
+
+#[inline(always)]
+pub(crate) fn harg_fallback<T, F>(vec: &T, better: F) -> usize
+    where T : Packed, T::Scalar : PartialOrd, F : Fn(T::Scalar, T::Scalar) -> bool {
+    fallback!();
+    let mut best = 0;
+    for i in 1..T::WIDTH {
+        if better(vec.extract(i), vec.extract(best)) {
+            best = i;
+        }
+    }
+    best
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_argminmax_fallback {
+    ($($vec:ty, $idx:ty),*) => {
+        $(
+            impl ArgMinMax for $vec {
+                type Indices = $idx;
+
+                #[inline(always)]
+                fn hargmin(&self) -> usize {
+                    harg_fallback(self, replaces_min)
+                }
+
+                #[inline(always)]
+                fn hargmax(&self) -> usize {
+                    harg_fallback(self, replaces_max)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_argminmax {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                // Every value appears several times in the wider vectors, and
+                // the extremes move through every lane
+                for off in 0..5 {
+                    let mut v = $vec::splat(0 as $el);
+                    for i in 0..$vec::WIDTH {
+                        v = v.replace(i, ((i * 3 + off) % 5) as $el);
+                    }
+                    let mut min = 0;
+                    let mut max = 0;
+                    for i in 0..$vec::WIDTH {
+                        if v.extract(i) < v.extract(min) { min = i; }
+                        if v.extract(i) > v.extract(max) { max = i; }
+                    }
+                    assert_eq!(v.hargmin(), min, "hargmin({:?})", v);
+                    assert_eq!(v.hargmax(), max, "hargmax({:?})", v);
+                }
+
+                assert_eq!($vec::splat(1 as $el).hargmin(), 0);
+                assert_eq!($vec::splat(1 as $el).hargmax(), 0);
+                assert_eq!($vec::halfs(1 as $el, 0 as $el).hargmin(), $vec::WIDTH / 2);
+                assert_eq!($vec::halfs(0 as $el, 1 as $el).hargmax(), $vec::WIDTH / 2);
+                assert_eq!($vec::splat(1 as $el).replace($vec::WIDTH - 1, $el::min_value()).hargmin(), $vec::WIDTH - 1);
+                assert_eq!($vec::splat(0 as $el).replace($vec::WIDTH - 1, $el::max_value()).hargmax(), $vec::WIDTH - 1);
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_argminmax_nan {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let nan = crate::std::$el::NAN;
+                assert_eq!($vec::splat(nan).hargmin(), 0);
+                assert_eq!($vec::splat(nan).hargmax(), 0);
+
+                for i in 0..$vec::WIDTH {
+                    let x = $vec::splat(nan).replace(i, 3 as $el);
+                    assert_eq!(x.hargmin(), i, "hargmin({:?})", x);
+                    assert_eq!(x.hargmax(), i, "hargmax({:?})", x);
+
+                    // NaN in the first lane, and the extreme tied in two
+                    // others
+                    let j = $vec::WIDTH - 1 - i / 2;
+                    let y = $vec::splat(1 as $el).replace(i, -2 as $el).replace(j, -2 as $el).replace(0, nan);
+                    let first = if i == 0 { j } else { i.min(j) };
+                    assert_eq!(y.hargmin(), first, "hargmin({:?})", y);
+                    let z = $vec::splat(-1 as $el).replace(i, 2 as $el).replace(j, 2 as $el).replace(0, nan);
+                    assert_eq!(z.hargmax(), first, "hargmax({:?})", z);
+                }
+            }
+        )*
+    }
+}
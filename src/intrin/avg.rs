@@ -0,0 +1,69 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+pub trait Avg : Packed {
+    /// Return a vector containing the average of each pair of elements of
+    /// `self` and `other`, rounded up. The sum is computed without overflow.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(255).avg(u8s::interleave(254, 0)), u8s::interleave(255, 128));
+    /// assert_eq!(i16s(-3).avg(i16s(0)), i16s(-1));
+    /// # }
+    /// ```
+    fn avg(&self, other: Self) -> Self;
+}
+
+#[inline(always)]
+pub(crate) fn avg_fallback<T, F>(a: &T, b: &T, op: F) -> T
+    where T : Packed, F : Fn(T::Scalar, T::Scalar) -> T::Scalar {
+    fallback!();
+    let mut ret = *a;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(a.extract(i), b.extract(i)));
+    }
+    ret
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_avg_fallback {
+    ($($vec:ty, $el:ty),*) => {
+        $(
+            impl Avg for $vec {
+                #[inline(always)]
+                fn avg(&self, other: Self) -> Self {
+                    avg_fallback(self, &other, |a, b| ((a as i64 + b as i64 + 1) >> 1) as $el)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_avg {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let specials = crate::intrin::specials::<$el>();
+                for &a in specials.iter() {
+                    for &b in specials.iter() {
+                        let avg = $vec::splat(a).avg($vec::splat(b));
+                        assert_eq!(avg, $vec::splat(((a as i64 + b as i64 + 1) >> 1) as $el), "avg({:?}, {:?})", a, b);
+                    }
+                }
+                assert_eq!($vec::interleave(1 as $el, 6 as $el).avg($vec::splat(2 as $el)),
+                           $vec::interleave(2 as $el, 4 as $el));
+            }
+        )*
+    }
+}
@@ -0,0 +1,183 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::half::bf16;
+use crate::vecs::*;
+
+pub trait UpcastBf16<T> {
+    /// Return two vectors containing the f32s equal to the bfloat16s whose
+    /// bits are in `self`. The first vector contains the first half of
+    /// `self`, and the second vector contains the second half. This is
+    /// always exact.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s::halfs(0x3F80, 0xC040).upcast_bf16(), (f32s(1.0), f32s(-3.0)));
+    /// # }
+    /// ```
+    fn upcast_bf16(self) -> (T, T);
+}
+
+pub trait DowncastBf16<T> {
+    /// Return a vector containing the bits of the bfloat16s nearest to the
+    /// elements of `self` and `other`, with ties rounding to even. The first
+    /// half of the returned vector contains those of `self`, and the second
+    /// half contains those of `other`. NaNs are quieted, and keep the top of
+    /// their payload.
+    ///
+    /// Together with `upcast_bf16`, this converts whole slices of bfloat16s
+    /// in a single pass.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() { }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// let weights = [bf16::from_f32(1.5).to_bits(); 100];
+    /// let scaled = weights.simd_iter(u16s(0))
+    ///     .simd_map(|v| {
+    ///         let (a, b) = v.upcast_bf16();
+    ///         (a * f32s(3.0)).downcast_bf16(b * f32s(3.0))
+    ///     })
+    ///     .scalar_collect();
+    /// assert_eq!(scaled, vec![bf16::from_f32(4.5).to_bits(); 100]);
+    /// # }
+    /// ```
+    fn downcast_bf16(self, other: Self) -> T;
+}
+
+#[inline(always)]
+pub(crate) fn upcast_bf16_fallback<T, U>(a: &T) -> (U, U)
+    where T : Packed<Scalar = u16>, U : Packed<Scalar = f32> {
+    fallback!();
+    let (mut lo, mut hi) = (U::default(), U::default());
+    for i in 0..U::WIDTH {
+        lo = lo.replace(i, bf16::from_bits(a.extract(i)).to_f32());
+        hi = hi.replace(i, bf16::from_bits(a.extract(i + U::WIDTH)).to_f32());
+    }
+    (lo, hi)
+}
+
+#[inline(always)]
+pub(crate) fn downcast_bf16_fallback<T, U>(a: &T, b: &T) -> U
+    where T : Packed<Scalar = f32>, U : Packed<Scalar = u16> {
+    fallback!();
+    let mut ret = U::default();
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, bf16::from_f32(a.extract(i)).to_bits());
+        ret = ret.replace(i + T::WIDTH, bf16::from_f32(b.extract(i)).to_bits());
+    }
+    ret
+}
+
+// The bits of a bfloat16 are those of the top half of an f32, so widening
+// is a shift, and narrowing rounds away the bottom half before the elements
+// are packed together.
+#[allow(unused_macros)]
+macro_rules! impl_bf16 {
+    ($($vec:ty, $fvec:ident, $wvec:ident),*) => {
+        $(
+            impl UpcastBf16<$fvec> for $vec {
+                #[inline(always)]
+                fn upcast_bf16(self) -> ($fvec, $fvec) {
+                    let (lo, hi) = Upcast::<$wvec>::upcast(self);
+                    // Every bit pattern is a valid float
+                    unsafe { (lo.shift_left(16).be_f32s_unchecked(), hi.shift_left(16).be_f32s_unchecked()) }
+                }
+            }
+
+            impl DowncastBf16<$vec> for $fvec {
+                #[inline(always)]
+                fn downcast_bf16(self, other: Self) -> $vec {
+                    let round = |x: $fvec| {
+                        let bits = x.be_u32s();
+                        let top = bits.shift_right_logical(16);
+                        let carry = ((bits & $wvec::splat(0xFFFF)) + $wvec::splat(0x7FFF) + (top & $wvec::splat(1)))
+                            .shift_right_logical(16);
+                        x.ne_mask(x).select(top | $wvec::splat(0x0040), top + carry)
+                    };
+                    // Every element fits in 16 bits, so none are saturated
+                    round(self).saturating_downcast(round(other))
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_bf16_fallback {
+    ($($vec:ty, $fvec:ty),*) => {
+        $(
+            impl UpcastBf16<$fvec> for $vec {
+                #[inline(always)]
+                fn upcast_bf16(self) -> ($fvec, $fvec) {
+                    upcast_bf16_fallback(&self)
+                }
+            }
+
+            impl DowncastBf16<$vec> for $fvec {
+                #[inline(always)]
+                fn downcast_bf16(self, other: Self) -> $vec {
+                    downcast_bf16_fallback(&self, &other)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_bf16 {
+    (($($vec:tt),*), ($($fvec:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                use crate::half::bf16;
+
+                // Every bfloat16, then their quieted and rounded forms
+                let mut bits = 0u32;
+                while bits < 0x10000 {
+                    let mut v = $vec::splat(0);
+                    for i in 0..$vec::WIDTH {
+                        v = v.replace(i, (bits + i as u32) as u16);
+                    }
+                    let (lo, hi) = v.upcast_bf16();
+                    for i in 0..$fvec::WIDTH {
+                        assert_eq!(lo.extract(i).to_bits(), (bits + i as u32) << 16);
+                        assert_eq!(hi.extract(i).to_bits(), (bits + (i + $fvec::WIDTH) as u32) << 16);
+                    }
+                    let back = lo.downcast_bf16(hi);
+                    for i in 0..$vec::WIDTH {
+                        let x = bf16::from_bits((bits + i as u32) as u16).to_f32();
+                        assert_eq!(back.extract(i), bf16::from_f32(x).to_bits(), "downcast_bf16({:?})", x);
+                    }
+                    bits += $vec::WIDTH as u32;
+                }
+
+                let floats = [1.0f32 + 1.0 / 256.0, 1.0 + 3.0 / 256.0, f32::from_bits(0x3F80_8001),
+                              f32::from_bits(0x7F7F_FFFF), f32::from_bits(0x8000_8000), f32::from_bits(0x7F80_0001),
+                              f32::from_bits(0xFFA0_0000), -1.0 / 0.0, -2.5];
+                let (mut a, mut b) = ($fvec::splat(0.0), $fvec::splat(0.0));
+                for i in 0..$fvec::WIDTH {
+                    a = a.replace(i, floats[i % floats.len()]);
+                    b = b.replace(i, floats[(i + $fvec::WIDTH) % floats.len()]);
+                }
+                let narrowed = a.downcast_bf16(b);
+                for i in 0..$vec::WIDTH {
+                    let x = floats[i % floats.len()];
+                    assert_eq!(narrowed.extract(i), bf16::from_f32(x).to_bits(), "downcast_bf16({:?})", x);
+                }
+            }
+        )*
+    }
+}
@@ -0,0 +1,120 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+use crate::std::mem::{size_of, transmute_copy};
+
+pub trait Bitmask {
+    /// Return an integer whose ith bit is the most significant bit of the
+    /// element of this vector at index i. For masks, whose elements are
+    /// either filled with 1s or with zeroes, this is the set of lanes which
+    /// are filled with 1s. Bits at and above `Self::WIDTH` are zero.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mask = u32s::halfs(1, 2).eq_mask(u32s(2));
+    /// assert_eq!(mask.bitmask().trailing_zeros() as usize, u32s::WIDTH / 2);
+    /// assert_eq!(mask.bitmask().count_ones() as usize, u32s::WIDTH / 2);
+    /// # }
+    /// ```
+    fn bitmask(&self) -> u64;
+
+    /// Return a vector whose element at index i is filled with 1s if the ith
+    /// bit of `bits` is set, and filled with zeroes otherwise. Bits at and
+    /// above `Self::WIDTH` are ignored.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::from_bitmask(0x5555_5555_5555_5555), u8s::interleave(0xFF, 0));
+    /// assert_eq!(u16s::from_bitmask(0b1010).bitmask(), 0b1010);
+    /// # }
+    /// ```
+    fn from_bitmask(bits: u64) -> Self;
+}
+
+/// Return a mask of the bits which a bitmask of `width` lanes may use.
+#[inline(always)]
+pub(crate) fn lane_bits(width: usize) -> u64 {
+    if width >= 64 { !0 } else { (1 << width) - 1 }
+}
+
+/// Take the most significant bit of each element of `vec`, read as the
+/// unsigned vector `U` of the same shape.
+#[inline(always)]
+pub(crate) fn bitmask_fallback<T, U>(vec: &T) -> u64
+    where T : Packed, U : Packed, U::Scalar : Into<u64> {
+    fallback!();
+    debug_assert_eq!(size_of::<T>(), size_of::<U>());
+    let lanes: U = unsafe { transmute_copy(vec) };
+    let msb = 8 * size_of::<U::Scalar>() - 1;
+    (0..U::WIDTH).fold(0, |acc, i| acc | ((lanes.extract(i).into() >> msb) << i))
+}
+
+/// Build a mask from `bits` in the unsigned vector `U`, and reinterpret it as
+/// `T`.
+#[inline(always)]
+pub(crate) fn from_bitmask_fallback<T, U>(bits: u64) -> T
+    where T : Packed, U : Pattern {
+    fallback!();
+    debug_assert_eq!(size_of::<T>(), size_of::<U>());
+    let ones = U::ones().extract(0);
+    let mut lanes = U::zeroes();
+    for i in 0..U::WIDTH {
+        if bits & (1 << i) != 0 {
+            lanes = lanes.replace(i, ones);
+        }
+    }
+    unsafe { transmute_copy(&lanes) }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_bitmask_fallback {
+    ($uvec:ty, $($vec:ty),*) => {
+        $(
+            impl Bitmask for $vec {
+                #[inline(always)]
+                fn bitmask(&self) -> u64 {
+                    bitmask_fallback::<Self, $uvec>(self)
+                }
+
+                #[inline(always)]
+                fn from_bitmask(bits: u64) -> Self {
+                    from_bitmask_fallback::<Self, $uvec>(bits)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_bitmask {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let all = lane_bits($vec::WIDTH);
+                assert_eq!($vec::splat(0 as $el).bitmask(), 0);
+                for &bits in [0u64, 1, 0b10, 0x5555_5555_5555_5555, 0x8000_0000_0000_0001,
+                              0x0123_4567_89AB_CDEF, !0].iter() {
+                    let mask = $vec::from_bitmask(bits);
+                    assert_eq!(mask.bitmask(), bits & all);
+                    for i in 0..$vec::WIDTH {
+                        if bits & (1 << i) == 0 {
+                            assert_eq!(mask.extract(i), 0 as $el);
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
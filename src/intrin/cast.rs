@@ -5,85 +5,689 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+// Most of these traits convert between element types of the same width, so
+// that the number of elements is kept. Conversions which change the width of
+// each element split each vector into several, or join several into one.
+// Each element is rounded at most once on the way:
+//
+// - i32s to i16s, or i16s to i8s: `saturating_downcast`. i32s to i8s or u8s:
+//   `saturating_quad_downcast`.
+// - f64s to i32s: `downcast_as_ints` or `round_downcast_as_ints`.
+// - f32s to i64s: `upcast_as_ints` or `round_upcast_as_ints`.
+// - i64s to f32s: `downcast_as_floats`.
+// - f32s or i32s to f64s: `upcast`.
+// - i16s to f32s: `upcast_as_floats`. u8s to f32s: `quad_upcast_as_floats`.
+//
+// Integers are converted to integers as by `as`, which wraps around, or
+// saturated to the bounds of the new type. Integers are converted to floats
+// by rounding to the nearest float. Floats are converted to integers by
+// truncating towards zero, or by rounding to nearest with `RoundAsInts`.
+// Floats beyond the range of the integer type saturate to its bounds, and
+// NaN becomes 0.
+
+use crate::vecs::*;
+
 pub trait Asi8s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to i8s.
+    /// Return a vector containing all elements of `self` cast to i8s, which
+    /// wrap around if they are above `i8::max_value()`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::interleave(5, 200).as_i8s(), i8s::interleave(5, -56));
+    /// # }
+    /// ```
     fn as_i8s(self) -> Self::Cast;
+
+    /// Return a vector containing all elements of `self` cast to i8s, which
+    /// saturate to `i8::max_value()`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::interleave(5, 200).saturating_as_i8s(), i8s::interleave(5, 127));
+    /// # }
+    /// ```
+    fn saturating_as_i8s(self) -> Self::Cast;
 }
 
 pub trait Asu8s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to u8s.
+    /// Return a vector containing all elements of `self` cast to u8s, which
+    /// wrap around if they are negative.
     fn as_u8s(self) -> Self::Cast;
+
+    /// Return a vector containing all elements of `self` cast to u8s, where
+    /// negative elements become 0.
+    fn saturating_as_u8s(self) -> Self::Cast;
 }
 
 pub trait Asi16s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to i16s.
+    /// Return a vector containing all elements of `self` cast to i16s, which
+    /// wrap around if they are above `i16::max_value()`.
     fn as_i16s(self) -> Self::Cast;
+
+    /// Return a vector containing all elements of `self` cast to i16s, which
+    /// saturate to `i16::max_value()`.
+    fn saturating_as_i16s(self) -> Self::Cast;
 }
 
 pub trait Asu16s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to u16s.
+    /// Return a vector containing all elements of `self` cast to u16s, which
+    /// wrap around if they are negative.
     fn as_u16s(self) -> Self::Cast;
+
+    /// Return a vector containing all elements of `self` cast to u16s, where
+    /// negative elements become 0.
+    fn saturating_as_u16s(self) -> Self::Cast;
 }
 
 pub trait Asf32s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to f32s.
+    /// Return a vector containing all elements of `self` cast to f32s,
+    /// rounded to the nearest f32 where they aren't exactly representable.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i32s::interleave(-3, 16777217).as_f32s(), f32s::interleave(-3.0, 16777216.0));
+    /// assert_eq!(u32s(0xFFFF_FFFF).as_f32s(), f32s(4294967296.0));
+    /// # }
+    /// ```
     fn as_f32s(self) -> Self::Cast;
 }
 
 pub trait Asi32s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to i32s.
+    /// Return a vector containing all elements of `self` cast to i32s. Floats
+    /// are truncated towards zero, saturating at the bounds of i32, and NaN
+    /// becomes 0. Unsigned integers wrap around if they are above
+    /// `i32::max_value()`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(-2.7, 1e10).as_i32s(), i32s::interleave(-2, i32::max_value()));
+    /// assert_eq!(f32s(0.0 / 0.0).as_i32s(), i32s(0));
+    /// assert_eq!(u32s(0xFFFF_FFFF).as_i32s(), i32s(-1));
+    /// # }
+    /// ```
     fn as_i32s(self) -> Self::Cast;
+
+    /// Return a vector containing all elements of `self` cast to i32s, which
+    /// saturate at the bounds of i32. Floats are converted as by `as_i32s`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(0xFFFF_FFFF).saturating_as_i32s(), i32s(i32::max_value()));
+    /// # }
+    /// ```
+    fn saturating_as_i32s(self) -> Self::Cast;
 }
 
 pub trait Asu32s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to u32s.
+    /// Return a vector containing all elements of `self` cast to u32s. Floats
+    /// are truncated towards zero, saturating at the bounds of u32, and NaN
+    /// becomes 0. Signed integers wrap around if they are negative.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(-2.7, 3e9).as_u32s(), u32s::interleave(0, 3000000000));
+    /// assert_eq!(i32s(-1).as_u32s(), u32s(0xFFFF_FFFF));
+    /// # }
+    /// ```
     fn as_u32s(self) -> Self::Cast;
+
+    /// Return a vector containing all elements of `self` cast to u32s, where
+    /// negative elements become 0. Floats are converted as by `as_u32s`.
+    fn saturating_as_u32s(self) -> Self::Cast;
 }
 
 pub trait Asf64s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to f64s.
+    /// Return a vector containing all elements of `self` cast to f64s,
+    /// rounded to the nearest f64 where they aren't exactly representable.
     fn as_f64s(self) -> Self::Cast;
 }
 
 pub trait Asi64s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to i64s.
+    /// Return a vector containing all elements of `self` cast to i64s. Floats
+    /// are truncated towards zero, saturating at the bounds of i64, and NaN
+    /// becomes 0. Unsigned integers wrap around if they are above
+    /// `i64::max_value()`.
     fn as_i64s(self) -> Self::Cast;
+
+    /// Return a vector containing all elements of `self` cast to i64s, which
+    /// saturate at the bounds of i64. Floats are converted as by `as_i64s`.
+    fn saturating_as_i64s(self) -> Self::Cast;
 }
 
 pub trait Asu64s {
     type Cast;
 
-    /// Return a vector containing all elements of `self` cast to u64s.
+    /// Return a vector containing all elements of `self` cast to u64s. Floats
+    /// are truncated towards zero, saturating at the bounds of u64, and NaN
+    /// becomes 0. Signed integers wrap around if they are negative.
     fn as_u64s(self) -> Self::Cast;
+
+    /// Return a vector containing all elements of `self` cast to u64s, where
+    /// negative elements become 0. Floats are converted as by `as_u64s`.
+    fn saturating_as_u64s(self) -> Self::Cast;
+}
+
+pub trait RoundAsInts {
+    type Cast;
+
+    /// Return a vector containing all elements of `self` rounded to the
+    /// nearest signed integer of the same width, with ties rounding to even.
+    /// Elements saturate at the bounds of the integer type, and NaN becomes
+    /// 0.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(-2.7, 2.5).round_as_ints(), i32s::interleave(-3, 2));
+    /// assert_eq!(f64s(-1e300).round_as_ints(), i64s(i64::min_value()));
+    /// # }
+    /// ```
+    fn round_as_ints(self) -> Self::Cast;
+}
+
+pub trait DowncastAsInts {
+    type Cast;
+
+    /// Return a vector containing the elements of `self` followed by those of
+    /// `other`, cast to signed integers of half their width as by `as_i32s`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s(-2.5).downcast_as_ints(f64s(3e9)), i32s::halfs(-2, i32::max_value()));
+    /// # }
+    /// ```
+    fn downcast_as_ints(self, other: Self) -> Self::Cast;
+
+    /// Return a vector containing the elements of `self` followed by those of
+    /// `other`, rounded to signed integers of half their width as by
+    /// `round_as_ints`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s(-2.5).round_downcast_as_ints(f64s(0.0 / 0.0)), i32s::halfs(-2, 0));
+    /// # }
+    /// ```
+    fn round_downcast_as_ints(self, other: Self) -> Self::Cast;
+}
+
+pub trait UpcastAsInts {
+    type Cast;
+
+    /// Return the first and second halves of `self`, cast to signed integers
+    /// of twice their width as by `as_i64s`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::halfs(-2.5, 1e30).upcast_as_ints(), (i64s(-2), i64s(i64::max_value())));
+    /// # }
+    /// ```
+    fn upcast_as_ints(self) -> (Self::Cast, Self::Cast);
+
+    /// Return the first and second halves of `self`, rounded to signed
+    /// integers of twice their width as by `round_as_ints`.
+    fn round_upcast_as_ints(self) -> (Self::Cast, Self::Cast);
+}
+
+pub trait DowncastAsFloats {
+    type Cast;
+
+    /// Return a vector containing the elements of `self` followed by those of
+    /// `other`, rounded to the nearest float of half their width.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i64s(-3).downcast_as_floats(i64s(1 << 40)), f32s::halfs(-3.0, 1099511627776.0));
+    /// # }
+    /// ```
+    fn downcast_as_floats(self, other: Self) -> Self::Cast;
 }
 
-// macro_rules! impl_cast {
-//     ($trait:path, $from:ty, $to:ty, $name:ident, $rsname:ident) => (
-//         impl $trait for $from {
-//             type Cast = $to;
+pub trait UpcastAsFloats {
+    type Cast;
 
-//             #[inline(always)]
-//             fn $name(self) -> Self::Cast {
-//                 self.$rsname()
-//             }
-//         }
-//     );
-// }
+    /// Return the first and second halves of `self`, converted exactly to
+    /// floats of twice their width.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i16s::halfs(-32768, 12).upcast_as_floats(), (f32s(-32768.0), f32s(12.0)));
+    /// # }
+    /// ```
+    fn upcast_as_floats(self) -> (Self::Cast, Self::Cast);
+}
+
+pub trait QuadUpcastAsFloats {
+    type Cast;
+
+    /// Return the quarters of `self` in order, converted exactly to floats of
+    /// four times their width.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let (a, _, _, d) = u8s::halfs(3, 255).quad_upcast_as_floats();
+    /// assert_eq!((a, d), (f32s(3.0), f32s(255.0)));
+    /// # }
+    /// ```
+    fn quad_upcast_as_floats(self) -> (Self::Cast, Self::Cast, Self::Cast, Self::Cast);
+}
+
+#[inline(always)]
+pub(crate) fn cast_fallback<T, U, F>(a: &T, op: F) -> U
+    where T : Packed, U : Packed, F : Fn(T::Scalar) -> U::Scalar {
+    fallback!();
+    let mut ret = U::default();
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(a.extract(i)));
+    }
+    ret
+}
+
+/// Convert the elements of each of `parts` in turn into a vector of narrower
+/// elements.
+#[inline(always)]
+pub(crate) fn narrow_fallback<T, U, F>(parts: &[T], op: F) -> U
+    where T : Packed, U : Packed, F : Fn(T::Scalar) -> U::Scalar {
+    fallback!();
+    let mut ret = U::default();
+    for (p, part) in parts.iter().enumerate() {
+        for i in 0..T::WIDTH {
+            ret = ret.replace(p * T::WIDTH + i, op(part.extract(i)));
+        }
+    }
+    ret
+}
+
+/// Convert the `part`th run of `U::WIDTH` elements of `a` into a vector of
+/// wider elements.
+#[inline(always)]
+pub(crate) fn widen_fallback<T, U, F>(a: &T, part: usize, op: F) -> U
+    where T : Packed, U : Packed, F : Fn(T::Scalar) -> U::Scalar {
+    fallback!();
+    let mut ret = U::default();
+    for i in 0..U::WIDTH {
+        ret = ret.replace(i, op(a.extract(part * U::WIDTH + i)));
+    }
+    ret
+}
+
+// The bounds are the powers of two at or just past each end of the range of
+// the integer type, which are exact in every float type.
+#[allow(unused_macros)]
+macro_rules! trunc_scalar {
+    ($x:expr, $tel:ident, $lo:expr, $hi:expr) => {{
+        let x = $x;
+        if x.is_nan() {
+            0
+        } else if x <= $lo {
+            $tel::min_value()
+        } else if x >= $hi {
+            $tel::max_value()
+        } else {
+            x as $tel
+        }
+    }}
+}
+
+// Adding and subtracting 2^(mantissa bits) rounds away the fraction of a
+// smaller magnitude, in the default rounding mode.
+#[allow(unused_macros)]
+macro_rules! round_scalar {
+    ($x:expr, $fel:ident, $tel:ident, $magic:expr, $sign:expr) => {{
+        let x: $fel = $x;
+        let abs = $fel::from_bits(x.to_bits() & !$sign);
+        let x = if abs < $magic {
+            $fel::from_bits(((abs + $magic) - $magic).to_bits() | (x.to_bits() & $sign))
+        } else {
+            x
+        };
+        let lo = $tel::min_value() as $fel;
+        if x.is_nan() {
+            0
+        } else if x <= lo {
+            $tel::min_value()
+        } else if x >= -lo {
+            $tel::max_value()
+        } else {
+            x as $tel
+        }
+    }}
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_cast_fallback {
+    (int, $trait:ident, $as:ident, $sat:ident, $($from:ty, $to:ty, $tel:ident),*) => {
+        $(
+            impl $trait for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn $as(self) -> Self::Cast {
+                    cast_fallback(&self, |x| x as $tel)
+                }
+
+                #[inline(always)]
+                fn $sat(self) -> Self::Cast {
+                    cast_fallback(&self, |x| {
+                        if (x as i128) < ($tel::min_value() as i128) {
+                            $tel::min_value()
+                        } else if (x as i128) > ($tel::max_value() as i128) {
+                            $tel::max_value()
+                        } else {
+                            x as $tel
+                        }
+                    })
+                }
+            }
+        )*
+    };
+    (float, $trait:ident, $as:ident, $($from:ty, $to:ty, $tel:ident),*) => {
+        $(
+            impl $trait for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn $as(self) -> Self::Cast {
+                    cast_fallback(&self, |x| x as $tel)
+                }
+            }
+        )*
+    };
+    (trunc, $trait:ident, $as:ident, $sat:ident, $($from:ty, $to:ty, $tel:ident, $lo:expr, $hi:expr),*) => {
+        $(
+            impl $trait for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn $as(self) -> Self::Cast {
+                    cast_fallback(&self, |x| trunc_scalar!(x, $tel, $lo, $hi))
+                }
+
+                #[inline(always)]
+                fn $sat(self) -> Self::Cast {
+                    self.$as()
+                }
+            }
+        )*
+    };
+    (round, $($from:ty, $to:ty, $fel:ident, $tel:ident, $magic:expr, $sign:expr),*) => {
+        $(
+            impl RoundAsInts for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn round_as_ints(self) -> Self::Cast {
+                    cast_fallback(&self, |x| round_scalar!(x, $fel, $tel, $magic, $sign))
+                }
+            }
+        )*
+    };
+}
+
+// Each arm converts between a pair of element types listed at the top of this
+// file.
+#[allow(unused_macros)]
+macro_rules! impl_cross_cast_fallback {
+    (f64_i32, $($from:ty, $to:ty),*) => {
+        $(
+            impl DowncastAsInts for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn downcast_as_ints(self, other: Self) -> Self::Cast {
+                    narrow_fallback(&[self, other], |x| trunc_scalar!(x, i32, -2147483648.0, 2147483648.0))
+                }
+
+                #[inline(always)]
+                fn round_downcast_as_ints(self, other: Self) -> Self::Cast {
+                    narrow_fallback(&[self, other], |x| round_scalar!(x, f64, i32, 4503599627370496.0, 0x8000_0000_0000_0000))
+                }
+            }
+        )*
+    };
+    (f32_i64, $($from:ty, $to:ty),*) => {
+        $(
+            impl UpcastAsInts for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn upcast_as_ints(self) -> (Self::Cast, Self::Cast) {
+                    let trunc = |x: f32| trunc_scalar!(x, i64, -9223372036854775808.0, 9223372036854775808.0);
+                    (widen_fallback(&self, 0, trunc), widen_fallback(&self, 1, trunc))
+                }
+
+                #[inline(always)]
+                fn round_upcast_as_ints(self) -> (Self::Cast, Self::Cast) {
+                    let round = |x: f32| round_scalar!(x, f32, i64, 8388608.0, 0x8000_0000);
+                    (widen_fallback(&self, 0, round), widen_fallback(&self, 1, round))
+                }
+            }
+        )*
+    };
+    (i64_f32, $($from:ty, $to:ty),*) => {
+        $(
+            impl DowncastAsFloats for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn downcast_as_floats(self, other: Self) -> Self::Cast {
+                    narrow_fallback(&[self, other], |x| x as f32)
+                }
+            }
+        )*
+    };
+    (i16_f32, $($from:ty, $to:ty),*) => {
+        $(
+            impl UpcastAsFloats for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn upcast_as_floats(self) -> (Self::Cast, Self::Cast) {
+                    (widen_fallback(&self, 0, |x| x as f32), widen_fallback(&self, 1, |x| x as f32))
+                }
+            }
+        )*
+    };
+    (u8_f32, $($from:ty, $to:ty),*) => {
+        $(
+            impl QuadUpcastAsFloats for $from {
+                type Cast = $to;
+
+                #[inline(always)]
+                fn quad_upcast_as_floats(self) -> (Self::Cast, Self::Cast, Self::Cast, Self::Cast) {
+                    (widen_fallback(&self, 0, |x| x as f32), widen_fallback(&self, 1, |x| x as f32),
+                     widen_fallback(&self, 2, |x| x as f32), widen_fallback(&self, 3, |x| x as f32))
+                }
+            }
+        )*
+    };
+    (i32_i8, $($from:ty, $to:ty, $tel:ident),*) => {
+        $(
+            impl QuadDowncast<$to> for $from {
+                #[inline(always)]
+                fn saturating_quad_downcast(self, b: Self, c: Self, d: Self) -> $to {
+                    narrow_fallback(&[self, b, c, d], |x| x.min($tel::max_value() as i32).max($tel::min_value() as i32) as $tel)
+                }
+            }
+        )*
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! test_cast {
+    (($($vec:tt),*), ($($name:ident),*), $method:ident, $cases:expr) => {
+        $(
+            #[test]
+            fn $name() {
+                let cases = $cases;
+                for &(from, to) in cases.iter() {
+                    let cast = $vec::splat(from).$method();
+                    for i in 0..$vec::WIDTH {
+                        assert_eq!(cast.extract(i), to, "{}({:?})", stringify!($method), from);
+                    }
+                }
+
+                let mut mixed = $vec::splat(cases[0].0);
+                for i in 0..$vec::WIDTH {
+                    mixed = mixed.replace(i, cases[i % cases.len()].0);
+                }
+                let cast = mixed.$method();
+                for i in 0..$vec::WIDTH {
+                    assert_eq!(cast.extract(i), cases[i % cases.len()].1);
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_cross_cast {
+    (($($f64:tt, $i32:tt, $f32:tt, $i64:tt, $i16:tt, $u8:tt, $i8:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let (max, min) = (i32::max_value(), i32::min_value());
+                let doubles = [-2.5f64, 2.5, 3.5, 1e10, -1e10, 0.0 / 0.0, 2147483647.5, -2147483648.5];
+                let trunc = [-2i32, 2, 3, max, min, 0, max, min];
+                let round = [-2i32, 2, 4, max, min, 0, max, min];
+                let (mut a, mut b) = ($f64::splat(0.0), $f64::splat(0.0));
+                for i in 0..$f64::WIDTH {
+                    a = a.replace(i, doubles[i % 8]);
+                    b = b.replace(i, doubles[(i + $f64::WIDTH) % 8]);
+                }
+                let (t, r): ($i32, $i32) = (a.downcast_as_ints(b), a.round_downcast_as_ints(b));
+                for i in 0..$i32::WIDTH {
+                    assert_eq!(t.extract(i), trunc[i % 8], "downcast_as_ints({:?}, {:?})", a, b);
+                    assert_eq!(r.extract(i), round[i % 8], "round_downcast_as_ints({:?}, {:?})", a, b);
+                }
+
+                let (max, min) = (i64::max_value(), i64::min_value());
+                let floats = [-2.5f32, 2.5, 3.5, 1e19, -1e19, 0.0 / 0.0, 1e30, -0.5];
+                let trunc = [-2i64, 2, 3, max, min, 0, max, 0];
+                let round = [-2i64, 2, 4, max, min, 0, max, 0];
+                let mut x = $f32::splat(0.0);
+                for i in 0..$f32::WIDTH {
+                    x = x.replace(i, floats[i % 8]);
+                }
+                let (t, r) = (x.upcast_as_ints(), x.round_upcast_as_ints());
+                for i in 0..$i64::WIDTH {
+                    assert_eq!((t.0.extract(i), t.1.extract(i)), (trunc[i % 8], trunc[(i + $i64::WIDTH) % 8]),
+                               "upcast_as_ints({:?})", x);
+                    assert_eq!((r.0.extract(i), r.1.extract(i)), (round[i % 8], round[(i + $i64::WIDTH) % 8]),
+                               "round_upcast_as_ints({:?})", x);
+                }
+
+                // 2^53 + 2^29 + 1 rounds to 2^53 by way of a double, but is
+                // past halfway to the next float
+                let longs = [-3i64, 1 << 40, i64::max_value(), i64::min_value(), 9007199791611905, 16777217, 0, -16777219];
+                let nearest = [-3.0f32, 1099511627776.0, 9223372036854775808.0, -9223372036854775808.0,
+                               9007200328482816.0, 16777216.0, 0.0, -16777220.0];
+                let (mut a, mut b) = ($i64::splat(0), $i64::splat(0));
+                for i in 0..$i64::WIDTH {
+                    a = a.replace(i, longs[i % 8]);
+                    b = b.replace(i, longs[(i + $i64::WIDTH) % 8]);
+                }
+                let f: $f32 = a.downcast_as_floats(b);
+                for i in 0..$f32::WIDTH {
+                    assert_eq!(f.extract(i), nearest[i % 8], "downcast_as_floats({:?}, {:?})", a, b);
+                }
+
+                let words = [-200i32, 100, 300, -1, -70000, 70000, 0, 127];
+                let signed = [-128i8, 100, 127, -1, -128, 127, 0, 127];
+                let unsigned = [0u8, 100, 255, 0, 0, 255, 0, 127];
+                let mut parts = [$i32::splat(0); 4];
+                for p in 0..4 {
+                    for i in 0..$i32::WIDTH {
+                        parts[p] = parts[p].replace(i, words[(p * $i32::WIDTH + i) % 8]);
+                    }
+                }
+                let s: $i8 = parts[0].saturating_quad_downcast(parts[1], parts[2], parts[3]);
+                let u: $u8 = parts[0].saturating_quad_downcast(parts[1], parts[2], parts[3]);
+                for i in 0..$i8::WIDTH {
+                    assert_eq!((s.extract(i), u.extract(i)), (signed[i % 8], unsigned[i % 8]),
+                               "saturating_quad_downcast({:?})", parts);
+                }
+
+                let shorts = [-32768i16, 12, 32767, -1, 0, 5, -300, 255];
+                let mut x = $i16::splat(0);
+                for i in 0..$i16::WIDTH {
+                    x = x.replace(i, shorts[i % 8]);
+                }
+                let (lo, hi) = x.upcast_as_floats();
+                for i in 0..$f32::WIDTH {
+                    assert_eq!((lo.extract(i), hi.extract(i)), (shorts[i % 8] as f32, shorts[(i + $f32::WIDTH) % 8] as f32),
+                               "upcast_as_floats({:?})", x);
+                }
+
+                let bytes = [0u8, 7, 255, 128, 1, 200, 99, 3];
+                let mut x = $u8::splat(0);
+                for i in 0..$u8::WIDTH {
+                    x = x.replace(i, bytes[i % 8]);
+                }
+                let quarters = x.quad_upcast_as_floats();
+                let quarters = [quarters.0, quarters.1, quarters.2, quarters.3];
+                for q in 0..4 {
+                    for i in 0..$f32::WIDTH {
+                        assert_eq!(quarters[q].extract(i), bytes[(q * $f32::WIDTH + i) % 8] as f32,
+                                   "quad_upcast_as_floats({:?})", x);
+                    }
+                }
+            }
+        )*
+    }
+}
@@ -32,3 +32,24 @@ pub trait Cmp {
     /// ```
     fn min(&self, other: Self) -> Self;
 }
+
+#[allow(unused_macros)]
+macro_rules! impl_cmp_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Cmp for $vec {
+                #[inline(always)]
+                fn max(&self, other: Self) -> Self {
+                    fallback!();
+                    (0..Self::WIDTH).fold(*self, |acc, i| acc.replace(i, self.extract(i).max(other.extract(i))))
+                }
+
+                #[inline(always)]
+                fn min(&self, other: Self) -> Self {
+                    fallback!();
+                    (0..Self::WIDTH).fold(*self, |acc, i| acc.replace(i, self.extract(i).min(other.extract(i))))
+                }
+            }
+        )*
+    }
+}
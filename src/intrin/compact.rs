@@ -0,0 +1,98 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+use crate::intrin::eq::Eq;
+
+pub trait Compact : Eq {
+    /// Return a vector containing the elements of `self` whose lanes are
+    /// filled with 1s in `mask`, moved to the lowest lanes in their original
+    /// order, along with the number of such elements. The contents of the
+    /// remaining lanes are unspecified.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let v = u32s::interleave(1, 2);
+    /// let (packed, n) = v.compact(v.eq_mask(u32s(2)));
+    /// assert_eq!(n, u32s::WIDTH / 2);
+    /// assert!((0..n).all(|i| packed.extract(i) == 2));
+    /// # }
+    /// ```
+    fn compact(&self, mask: Self::Out) -> (Self, usize);
+}
+
+#[inline(always)]
+pub(crate) fn compact_fallback<T : Eq>(vec: &T, mask: T::Out) -> (T, usize) {
+    fallback!();
+    let bits = mask.bitmask();
+    let mut ret = *vec;
+    let mut count = 0;
+    for i in 0..T::WIDTH {
+        if bits & (1 << i) != 0 {
+            ret = ret.replace(count, vec.extract(i));
+            count += 1;
+        }
+    }
+    (ret, count)
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_compact_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Compact for $vec {
+                #[inline(always)]
+                fn compact(&self, mask: Self::Out) -> (Self, usize) {
+                    compact_fallback(self, mask)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_compact {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let v = $vec::interleave(1 as $el, 2 as $el);
+                let (packed, n) = v.compact(v.eq_mask($vec::splat(2 as $el)));
+                assert_eq!(n, $vec::WIDTH / 2);
+                for i in 0..n {
+                    assert_eq!(packed.extract(i), 2 as $el);
+                }
+
+                let mut v = $vec::splat(0 as $el);
+                for i in 0..$vec::WIDTH {
+                    v = v.replace(i, i as $el);
+                }
+                assert_eq!(v.compact(v.ne_mask(v)).1, 0);
+                assert_eq!(v.compact(v.eq_mask(v)), (v, $vec::WIDTH));
+
+                // Try every mask on vectors which are small enough.
+                if $vec::WIDTH <= 8 {
+                    for bits in 0..(1usize << $vec::WIDTH) {
+                        let mut sel = $vec::splat(0 as $el);
+                        for i in 0..$vec::WIDTH {
+                            sel = sel.replace(i, ((bits >> i) & 1) as $el);
+                        }
+                        let (packed, n) = v.compact(sel.eq_mask($vec::splat(1 as $el)));
+                        let expected = (0..$vec::WIDTH).filter(|i| (bits >> i) & 1 == 1);
+                        assert_eq!(n, bits.count_ones() as usize);
+                        for (i, e) in expected.enumerate() {
+                            assert_eq!(packed.extract(i), e as $el);
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
@@ -0,0 +1,310 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+/// Precomputed constants for dividing integers by a divisor which is only
+/// known at runtime. Building a divider costs about as much as a scalar
+/// division, after which each vector is divided with a multiplication and a
+/// few shifts, following Granlund and Montgomery's "Division by Invariant
+/// Integers using Multiplication".
+///
+/// Quotients are truncated towards zero and remainders take the sign of the
+/// dividend, as with Rust's `/` and `%`. Dividing the smallest signed integer
+/// by -1 wraps around to the smallest signed integer, with a remainder of 0.
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+///
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() { }
+///
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// let days: Vec<u32> = (0..100).collect();
+/// let seven = Divider::new(7u32);
+/// let weeks = days.simd_iter(u32s(0))
+///     .simd_map(|v| v.div_by(&seven))
+///     .scalar_collect();
+/// assert_eq!(weeks, days.iter().map(|x| x / 7).collect::<Vec<_>>());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Divider<T> {
+    pub(crate) divisor: T,
+    pub(crate) magic: T,
+    pub(crate) shift1: u32,
+    pub(crate) shift2: u32,
+    // All ones if the divisor is negative, and zero otherwise.
+    pub(crate) sign: T,
+    // The multiplicative inverse of the divisor with its trailing zeroes
+    // shifted out, for exact division.
+    pub(crate) inverse: T,
+    pub(crate) trailing: u32,
+}
+
+/// Integers which a `Divider` can be built for.
+pub trait Divisor : Packable {
+    /// Return the constants for dividing by `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    fn divider(self) -> Divider<Self>;
+}
+
+impl<T> Divider<T> where T : Divisor {
+    /// Return the constants for dividing by `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    #[inline(always)]
+    pub fn new(divisor: T) -> Self {
+        divisor.divider()
+    }
+
+    /// Return the number this divider divides by.
+    #[inline(always)]
+    pub fn divisor(&self) -> T {
+        self.divisor
+    }
+}
+
+/// Division of every element of a vector by the same precomputed divisor.
+pub trait DivideBy : Packed {
+    /// Return a vector containing each element of `self` divided by the
+    /// divisor of `divider`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(100).div_by(&Divider::new(7)), u32s(14));
+    /// assert_eq!(i16s::halfs(-100, 100).div_by(&Divider::new(-7)), i16s::halfs(14, -14));
+    /// # }
+    /// ```
+    fn div_by(&self, divider: &Divider<Self::Scalar>) -> Self;
+
+    /// Return a vector containing the remainder of each element of `self`
+    /// divided by the divisor of `divider`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u64s(100).rem_by(&Divider::new(7)), u64s(2));
+    /// assert_eq!(i32s(-100).rem_by(&Divider::new(7)), i32s(-2));
+    /// # }
+    /// ```
+    fn rem_by(&self, divider: &Divider<Self::Scalar>) -> Self;
+
+    /// Return a vector containing each element of `self` divided by the
+    /// divisor of `divider`, where every element is a multiple of the divisor.
+    /// This only takes a shift and a multiplication, but the result is
+    /// meaningless for elements which aren't multiples of the divisor.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s(1200).div_exact_by(&Divider::new(24)), u16s(50));
+    /// assert_eq!(i64s(-1200).div_exact_by(&Divider::new(24)), i64s(-50));
+    /// # }
+    /// ```
+    fn div_exact_by(&self, divider: &Divider<Self::Scalar>) -> Self;
+}
+
+// Shift amounts are taken from section 4 of the paper for unsigned divisors,
+// and section 5 for signed divisors, where only the second shift is used.
+macro_rules! impl_divisor {
+    (unsigned, $($el:ty, $wide:ty),*) => {
+        $(
+            impl Divisor for $el {
+                fn divider(self) -> Divider<Self> {
+                    assert!(self != 0, "attempt to divide by zero");
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                    // The logarithm of the divisor, rounded up.
+                    let log = bits - (self - 1).leading_zeros();
+                    let magic = ((((1 as $wide) << log) - self as $wide) << bits) / self as $wide + 1;
+                    let trailing = self.trailing_zeros();
+                    Divider {
+                        divisor: self,
+                        magic: magic as $el,
+                        shift1: log.min(1),
+                        shift2: log.saturating_sub(1),
+                        sign: 0,
+                        inverse: impl_divisor!(@inverse self >> trailing),
+                        trailing,
+                    }
+                }
+            }
+        )*
+    };
+    (signed, $($el:ty, $uel:ty, $wide:ty),*) => {
+        $(
+            impl Divisor for $el {
+                fn divider(self) -> Divider<Self> {
+                    assert!(self != 0, "attempt to divide by zero");
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                    let abs = self.wrapping_abs() as $uel;
+                    let log = (bits - (abs - 1).leading_zeros()).max(1);
+                    let magic = ((1 as $wide) << (bits + log - 1)) / abs as $wide + 1;
+                    let trailing = self.trailing_zeros();
+                    Divider {
+                        divisor: self,
+                        magic: magic as $el,
+                        shift1: 0,
+                        shift2: log - 1,
+                        sign: self >> (bits - 1),
+                        inverse: impl_divisor!(@inverse self >> trailing),
+                        trailing,
+                    }
+                }
+            }
+        )*
+    };
+    // Newton's iteration doubles the number of correct low bits each time,
+    // and every odd number is its own inverse modulo 8.
+    (@inverse $odd:expr) => {{
+        let odd = $odd;
+        let mut inverse = odd;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul((2 as Self).wrapping_sub(odd.wrapping_mul(inverse)));
+        }
+        inverse
+    }};
+}
+
+impl_divisor!(unsigned, u16, u32, u32, u64, u64, u128);
+impl_divisor!(signed, i16, u16, u32, i32, u32, u64, i64, u64, u128);
+
+/// The operations of a divider on a single element, for use in fallbacks.
+pub(crate) trait DivideLane : Copy {
+    fn div_lane(self, divider: &Divider<Self>) -> Self;
+    fn div_exact_lane(self, divider: &Divider<Self>) -> Self;
+}
+
+macro_rules! impl_divide_lane {
+    (unsigned, $($el:ty, $wide:ty),*) => {
+        $(
+            impl DivideLane for $el {
+                #[inline(always)]
+                fn div_lane(self, divider: &Divider<Self>) -> Self {
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                    let t = ((self as $wide * divider.magic as $wide) >> bits) as $el;
+                    (((self - t) >> divider.shift1) + t) >> divider.shift2
+                }
+
+                #[inline(always)]
+                fn div_exact_lane(self, divider: &Divider<Self>) -> Self {
+                    (self >> divider.trailing).wrapping_mul(divider.inverse)
+                }
+            }
+        )*
+    };
+    (signed, $($el:ty, $wide:ty),*) => {
+        $(
+            impl DivideLane for $el {
+                #[inline(always)]
+                fn div_lane(self, divider: &Divider<Self>) -> Self {
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                    let t = ((self as $wide * divider.magic as $wide) >> bits) as $el;
+                    let q = (self.wrapping_add(t) >> divider.shift2).wrapping_sub(self >> (bits - 1));
+                    (q ^ divider.sign).wrapping_sub(divider.sign)
+                }
+
+                #[inline(always)]
+                fn div_exact_lane(self, divider: &Divider<Self>) -> Self {
+                    (self >> divider.trailing).wrapping_mul(divider.inverse)
+                }
+            }
+        )*
+    };
+}
+
+impl_divide_lane!(unsigned, u16, u32, u32, u64, u64, u128);
+impl_divide_lane!(signed, i16, i32, i32, i64, i64, i128);
+
+#[inline(always)]
+pub(crate) fn divide_fallback<T, F>(x: &T, divider: &Divider<T::Scalar>, op: F) -> T
+    where T : Packed, F : Fn(T::Scalar, &Divider<T::Scalar>) -> T::Scalar {
+    fallback!();
+    let mut ret = *x;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(x.extract(i), divider));
+    }
+    ret
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_divide_by_fallback {
+    ($($vec:ty, $el:ty),*) => {
+        $(
+            impl DivideBy for $vec {
+                #[inline(always)]
+                fn div_by(&self, divider: &Divider<$el>) -> Self {
+                    divide_fallback(self, divider, DivideLane::div_lane)
+                }
+
+                #[inline(always)]
+                fn rem_by(&self, divider: &Divider<$el>) -> Self {
+                    divide_fallback(self, divider, |x, d| x.wrapping_sub(x.div_lane(d).wrapping_mul(d.divisor)))
+                }
+
+                #[inline(always)]
+                fn div_exact_by(&self, divider: &Divider<$el>) -> Self {
+                    divide_fallback(self, divider, DivideLane::div_exact_lane)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_divide_by {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let specials = crate::intrin::specials::<$el>();
+                let small = [1i64, 2, 3, 5, 6, 7, 10, 64, 100, 641, 1000, 0x7FFF, -1, -2, -3, -7, -10, -64, -641];
+                let large = [$el::min_value(), $el::max_value(), $el::max_value() / (3 as $el)];
+                let divisors = small.iter().map(|&d| d as $el).chain(large.iter().cloned()).filter(|&d| d != 0 as $el);
+
+                for d in divisors {
+                    let divider = Divider::new(d);
+                    assert_eq!(divider.divisor(), d);
+                    let mut state = 0x2545_F491_4F6C_DD1Du64;
+                    for round in 0..64 {
+                        let mut x = $vec::splat(0 as $el);
+                        for i in 0..$vec::WIDTH {
+                            state ^= state << 13;
+                            state ^= state >> 7;
+                            state ^= state << 17;
+                            x = x.replace(i, if round == 0 { specials[i % specials.len()] } else { (state >> (round % 56)) as $el });
+                        }
+
+                        let (q, r) = (x.div_by(&divider), x.rem_by(&divider));
+                        let multiples = x - r;
+                        let exact = multiples.div_exact_by(&divider);
+                        for i in 0..$vec::WIDTH {
+                            let v = x.extract(i);
+                            assert_eq!(q.extract(i), v.wrapping_div(d), "{:?} / {:?}", v, d);
+                            assert_eq!(r.extract(i), v.wrapping_rem(d), "{:?} % {:?}", v, d);
+                            assert_eq!(exact.extract(i), multiples.extract(i).wrapping_div(d), "{:?} / {:?}", multiples.extract(i), d);
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
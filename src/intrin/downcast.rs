@@ -13,6 +13,9 @@ pub trait Downcast<T> {
     /// vector is equal in size to `self` and `other`. If an element exceeds
     /// the maximum or minimum value of the downcast type, it is saturated.
     ///
+    /// Floats are downcast to floats. To narrow floats to integers, use
+    /// `downcast_as_ints`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -26,3 +29,47 @@ pub trait Downcast<T> {
     /// ```
     fn saturating_downcast(self, other: Self) -> T;
 }
+
+pub trait QuadDowncast<T> {
+    /// Return a vector containing the elements of `self`, `b`, `c` and `d` in
+    /// that order, narrowed to a quarter of their width. Elements beyond the
+    /// bounds of the narrower type are saturated.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let bytes: u8s = i32s(-1).saturating_quad_downcast(i32s(7), i32s(300), i32s(255));
+    /// assert_eq!(bytes.extract(0), 0);
+    /// assert_eq!(bytes.extract(u8s::WIDTH / 2), 255);
+    /// # }
+    /// ```
+    fn saturating_quad_downcast(self, b: Self, c: Self, d: Self) -> T;
+}
+
+#[allow(unused_macros)]
+macro_rules! test_downcast {
+    (($($vec:tt),*), ($($narrow:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                // Every element is distinct, so any reordering is caught
+                let (mut a, mut b) = ($vec::splat(0), $vec::splat(0));
+                for i in 0..$vec::WIDTH {
+                    a = a.replace(i, i as _);
+                    b = b.replace(i, (i + $vec::WIDTH) as _);
+                }
+                let narrowed: $narrow = a.saturating_downcast(b);
+                for i in 0..$narrow::WIDTH {
+                    assert_eq!(narrowed.extract(i), i as $el, "saturating_downcast({:?}, {:?})", a, b);
+                }
+
+                let big = ($el::max_value() as i64 + 1) as _;
+                let narrowed: $narrow = $vec::splat(0).replace(1, big).saturating_downcast(b);
+                assert_eq!(narrowed.extract(1), $el::max_value());
+                assert_eq!(narrowed.extract($vec::WIDTH), $vec::WIDTH as $el);
+            }
+        )*
+    }
+}
@@ -5,13 +5,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::std::ops::BitXor;
 use crate::vecs::*;
+use crate::mask::PackedMask;
 
 pub trait Eq : Packed {
-    type Out : Pattern + BitXor<Self::Out, Output = Self::Out>;
+    type Out : PackedMask;
 
-    /// Return a vector where each element at an index i is filled with 1s if
+    /// Return a mask where each element at an index i is filled with 1s if
     /// the elements of `self` and `other` at index i are equal, and filled with
     /// zeroes otherwise.
     ///
@@ -26,7 +26,7 @@ pub trait Eq : Packed {
     /// ```
     fn eq_mask(&self, other: Self) -> Self::Out;
 
-    /// Return a vector where each element at an index i is filled with 1s if
+    /// Return a mask where each element at an index i is filled with 1s if
     /// the elements of `self` and `other` at index i are not equal, and filled
     /// with zeroes otherwise.
     ///
@@ -40,7 +40,7 @@ pub trait Eq : Packed {
     /// # }
     /// ```
     #[inline(always)]
-    fn ne_mask(&self, other: Self) -> Self::Out { self.eq_mask(other) ^ Self::Out::ones() }
+    fn ne_mask(&self, other: Self) -> Self::Out { !self.eq_mask(other) }
 }
 
 macro_rules! rust_fallback_eq {
@@ -48,11 +48,11 @@ macro_rules! rust_fallback_eq {
         $($newfn:ident, $rustfn:ident => $mask:tt, $maskel:tt, $mmfn:tt ( $($mmfnargs:expr),* ), [$($n:expr),+]);*;}) => (
         impl $trait for $type {
             $(
-                type Out = $mask;
+                type Out = crate::mask::Mask<$mask>;
 
                 #[inline(always)]
                 #[cfg(target_feature = $feat)]
-                fn $newfn(&self, other: Self) -> $mask {
+                fn $newfn(&self, other: Self) -> Self::Out {
                     use crate::std::mem::transmute;
                     unsafe { transmute($mmfn(transmute(*self), transmute(other), $($mmfnargs),*)) }
                 }
@@ -63,11 +63,11 @@ macro_rules! rust_fallback_eq {
                     fallback!();
                     use crate::std::mem::transmute;
                     unsafe {
-                        Self::Out::new($(transmute(if self.extract($n).$rustfn(&other.extract($n)) {
+                        crate::mask::Mask::from_vec($mask::new($(transmute(if self.extract($n).$rustfn(&other.extract($n)) {
                             $maskel::max_value()
                         } else {
                             $maskel::min_value()
-                        })),*)
+                        })),*))
                     }
                 }
             )*
@@ -75,6 +75,26 @@ macro_rules! rust_fallback_eq {
     );
 }
 
+#[allow(unused_macros)]
+macro_rules! impl_eq_fallback {
+    ($($vec:ty, $mask:ty),*) => {
+        $(
+            impl Eq for $vec {
+                type Out = crate::mask::Mask<$mask>;
+
+                #[inline(always)]
+                fn eq_mask(&self, other: Self) -> Self::Out {
+                    fallback!();
+                    let bits = (0..Self::WIDTH).fold(0u64, |acc, i| {
+                        acc | (((self.extract(i) == other.extract(i)) as u64) << i)
+                    });
+                    crate::mask::PackedMask::from_bitmask(bits)
+                }
+            }
+        )*
+    }
+}
+
 macro_rules! test_packed_eq {
         ($vec:tt, $el:tt, $mask:tt, $maskel:tt, $name:tt) => {
             #[test]
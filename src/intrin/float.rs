@@ -0,0 +1,246 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+use crate::mask::PackedMask;
+use crate::intrin::bitmask::Bitmask;
+
+pub trait Classify : Packed {
+    type Out : PackedMask;
+
+    /// Return a mask which is true wherever `self` is NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(0.0 / 0.0, 1.0).is_nan(), u32s::interleave(0xFFFF_FFFF, 0));
+    /// # }
+    /// ```
+    fn is_nan(&self) -> Self::Out;
+
+    /// Return a mask which is true wherever `self` is positive or negative
+    /// infinity.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s::interleave(-1.0 / 0.0, 0.0 / 0.0).is_infinite(), u64s::interleave(!0, 0));
+    /// # }
+    /// ```
+    fn is_infinite(&self) -> Self::Out;
+
+    /// Return a mask which is true wherever `self` is neither infinite nor
+    /// NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(1e38, 1.0 / 0.0).is_finite(), u32s::interleave(0xFFFF_FFFF, 0));
+    /// # }
+    /// ```
+    fn is_finite(&self) -> Self::Out;
+
+    /// Return a mask which is true wherever `self` has its sign bit set,
+    /// including at negative zeroes and NaNs.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(-0.0, 0.0).is_sign_negative(), u32s::interleave(0xFFFF_FFFF, 0));
+    /// # }
+    /// ```
+    fn is_sign_negative(&self) -> Self::Out;
+
+    /// Return a mask which is true wherever `self` has its sign bit clear.
+    #[inline(always)]
+    fn is_sign_positive(&self) -> Self::Out {
+        !self.is_sign_negative()
+    }
+}
+
+pub trait CopySign : Packed {
+    /// Return a vector containing the magnitudes of the elements of `self`
+    /// with the signs of the elements of `sign`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(3.5).copysign(f32s::interleave(-0.0, 1.0)), f32s::interleave(-3.5, 3.5));
+    /// # }
+    /// ```
+    fn copysign(&self, sign: Self) -> Self;
+
+    /// Return a vector containing 1.0 wherever `self` has its sign bit clear,
+    /// -1.0 wherever it is set, and NaN wherever `self` is NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s::interleave(-0.0, 25.0).signum(), f64s::interleave(-1.0, 1.0));
+    /// # }
+    /// ```
+    fn signum(&self) -> Self;
+}
+
+pub trait Clamp : Packed {
+    /// Return a vector containing the elements of `self` restricted to the
+    /// range between the elements of `min` and `max`. NaN elements of `self`
+    /// are left as NaN. The result is unspecified wherever `min` is greater
+    /// than `max` or either bound is NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(-3.0, 0.5).clamp(f32s(-1.0), f32s(1.0)), f32s::interleave(-1.0, 0.5));
+    /// assert!(f32s(0.0 / 0.0).clamp(f32s(-1.0), f32s(1.0)).is_nan().all());
+    /// # }
+    /// ```
+    fn clamp(&self, min: Self, max: Self) -> Self;
+}
+
+#[inline(always)]
+pub(crate) fn classify_fallback<T, M, F>(a: &T, op: F) -> M
+    where T : Packed, M : Bitmask, F : Fn(T::Scalar) -> bool {
+    fallback!();
+    let bits = (0..T::WIDTH).fold(0u64, |acc, i| acc | ((op(a.extract(i)) as u64) << i));
+    M::from_bitmask(bits)
+}
+
+#[inline(always)]
+pub(crate) fn float_fallback<T, F>(a: &T, b: &T, c: &T, op: F) -> T
+    where T : Packed, F : Fn(T::Scalar, T::Scalar, T::Scalar) -> T::Scalar {
+    fallback!();
+    let mut ret = *a;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(a.extract(i), b.extract(i), c.extract(i)));
+    }
+    ret
+}
+
+// The sign manipulation goes through the bits of each element, as
+// `copysign` and `signum` aren't available on floats without `std`.
+#[allow(unused_macros)]
+macro_rules! impl_classify_fallback {
+    ($($vec:ty, $el:ident, $mask:ty, $sign:expr),*) => {
+        $(
+            impl Classify for $vec {
+                type Out = crate::mask::Mask<$mask>;
+
+                #[inline(always)]
+                fn is_nan(&self) -> Self::Out {
+                    classify_fallback(self, |x| x.is_nan())
+                }
+
+                #[inline(always)]
+                fn is_infinite(&self) -> Self::Out {
+                    classify_fallback(self, |x| x.is_infinite())
+                }
+
+                #[inline(always)]
+                fn is_finite(&self) -> Self::Out {
+                    classify_fallback(self, |x| x.is_finite())
+                }
+
+                #[inline(always)]
+                fn is_sign_negative(&self) -> Self::Out {
+                    classify_fallback(self, |x| x.is_sign_negative())
+                }
+            }
+
+            impl CopySign for $vec {
+                #[inline(always)]
+                fn copysign(&self, sign: Self) -> Self {
+                    float_fallback(self, &sign, &sign, |x, s, _| {
+                        $el::from_bits((x.to_bits() & !$sign) | (s.to_bits() & $sign))
+                    })
+                }
+
+                #[inline(always)]
+                fn signum(&self) -> Self {
+                    float_fallback(self, self, self, |x, _, _| {
+                        if x.is_nan() { x } else { $el::from_bits((1.0 as $el).to_bits() | (x.to_bits() & $sign)) }
+                    })
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_clamp_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Clamp for $vec {
+                #[inline(always)]
+                fn clamp(&self, min: Self, max: Self) -> Self {
+                    float_fallback(self, &min, &max, |x, lo, hi| if x < lo { lo } else if x > hi { hi } else { x })
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_float_classify {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let specials = crate::intrin::specials::<$el>();
+                let mut x = $vec::splat(0.0 as $el);
+                for off in 0..specials.len() {
+                    for i in 0..$vec::WIDTH {
+                        x = x.replace(i, specials[(i + off) % specials.len()]);
+                    }
+
+                    let (nan, inf, fin, neg) = (x.is_nan(), x.is_infinite(), x.is_finite(), x.is_sign_negative());
+                    let (pos, signum) = (x.is_sign_positive(), x.signum());
+                    let copy = $vec::splat(7.0 as $el).copysign(x);
+                    let clamped = x.clamp($vec::splat(-1.0 as $el), $vec::splat(2.0 as $el));
+                    for i in 0..$vec::WIDTH {
+                        let e = x.extract(i);
+                        assert_eq!(nan.into_inner().extract(i) != 0, e.is_nan(), "is_nan({:?})", e);
+                        assert_eq!(inf.into_inner().extract(i) != 0, e.is_infinite(), "is_infinite({:?})", e);
+                        assert_eq!(fin.into_inner().extract(i) != 0, e.is_finite(), "is_finite({:?})", e);
+                        assert_eq!(neg.into_inner().extract(i) != 0, e.is_sign_negative(), "is_sign_negative({:?})", e);
+                        assert_eq!(pos.into_inner().extract(i) != 0, e.is_sign_positive(), "is_sign_positive({:?})", e);
+
+                        let s = signum.extract(i);
+                        if e.is_nan() {
+                            assert!(s.is_nan());
+                        } else {
+                            assert_eq!(s, if e.is_sign_negative() { -1.0 } else { 1.0 }, "signum({:?})", e);
+                        }
+                        assert_eq!(copy.extract(i), if e.is_sign_negative() { -7.0 } else { 7.0 }, "copysign({:?})", e);
+
+                        let c = clamped.extract(i);
+                        if e.is_nan() {
+                            assert!(c.is_nan());
+                        } else {
+                            let expected = if e < -1.0 { -1.0 } else if e > 2.0 { 2.0 } else { e };
+                            assert_eq!(c, expected, "clamp({:?})", e);
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
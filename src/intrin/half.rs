@@ -0,0 +1,171 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::half::f16;
+use crate::vecs::*;
+
+pub trait LoadF16 : Packed<Scalar = f32> {
+    /// Create a new vector with `Self::WIDTH` elements from the halves in
+    /// `data`, beginning at `offset`. Every half is exactly representable as
+    /// an f32.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let halves = [f16::from_bits(0x3C00); 64];
+    /// assert_eq!(f32s::load_f16(&halves, 3), f32s(1.0));
+    /// # }
+    /// ```
+    fn load_f16(data: &[f16], offset: usize) -> Self;
+
+    /// Write `Self::WIDTH` elements from this vector to `data` as halves,
+    /// beginning at `offset`. Elements are rounded to the nearest half, with
+    /// ties rounding to even, and become infinite if they are too large.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mut halves = [f16::default(); 64];
+    /// f32s(1e6).store_f16(&mut halves, 0);
+    /// assert_eq!(halves[0], f16::from_bits(0x7C00));
+    /// # }
+    /// ```
+    fn store_f16(self, data: &mut [f16], offset: usize);
+
+    /// Create a new vector with `Self::WIDTH` elements from the halves in
+    /// `data`, beginning at `offset`, without checking whether `data` is long
+    /// enough.
+    unsafe fn load_f16_unchecked(data: &[f16], offset: usize) -> Self;
+
+    /// Write `Self::WIDTH` elements from this vector to `data` as halves,
+    /// beginning at `offset`, without checking whether `data` is long enough.
+    unsafe fn store_f16_unchecked(self, data: &mut [f16], offset: usize);
+}
+
+#[inline(always)]
+pub(crate) fn load_f16_fallback<T>(data: &[f16], offset: usize) -> T where T : Packed<Scalar = f32> {
+    fallback!();
+    let data = &data[offset..offset + T::WIDTH];
+    let mut ret = T::default();
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, data[i].to_f32());
+    }
+    ret
+}
+
+#[inline(always)]
+pub(crate) fn store_f16_fallback<T>(a: &T, data: &mut [f16], offset: usize) where T : Packed<Scalar = f32> {
+    fallback!();
+    let data = &mut data[offset..offset + T::WIDTH];
+    for i in 0..T::WIDTH {
+        data[i] = f16::from_f32(a.extract(i));
+    }
+}
+
+#[inline(always)]
+pub(crate) unsafe fn load_f16_unchecked_fallback<T>(data: &[f16], offset: usize) -> T where T : Packed<Scalar = f32> {
+    fallback!();
+    debug_assert!(offset + T::WIDTH <= data.len());
+    let mut ret = T::default();
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, data.get_unchecked(offset + i).to_f32());
+    }
+    ret
+}
+
+#[inline(always)]
+pub(crate) unsafe fn store_f16_unchecked_fallback<T>(a: &T, data: &mut [f16], offset: usize) where T : Packed<Scalar = f32> {
+    fallback!();
+    debug_assert!(offset + T::WIDTH <= data.len());
+    for i in 0..T::WIDTH {
+        *data.get_unchecked_mut(offset + i) = f16::from_f32(a.extract(i));
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_load_f16_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl LoadF16 for $vec {
+                #[inline(always)]
+                fn load_f16(data: &[f16], offset: usize) -> Self {
+                    load_f16_fallback(data, offset)
+                }
+
+                #[inline(always)]
+                fn store_f16(self, data: &mut [f16], offset: usize) {
+                    store_f16_fallback(&self, data, offset)
+                }
+
+                #[inline(always)]
+                unsafe fn load_f16_unchecked(data: &[f16], offset: usize) -> Self {
+                    load_f16_unchecked_fallback(data, offset)
+                }
+
+                #[inline(always)]
+                unsafe fn store_f16_unchecked(self, data: &mut [f16], offset: usize) {
+                    store_f16_unchecked_fallback(&self, data, offset)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_load_f16 {
+    (($($vec:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                use crate::half::f16;
+
+                // Every half, starting at an offset which isn't a multiple
+                // of the width
+                let mut halves = [f16::default(); 0x10001];
+                for bits in 0..0x10000 {
+                    halves[bits + 1] = f16::from_bits(bits as u16);
+                }
+
+                let mut stored = [f16::default(); 0x10001];
+                let mut stored_unchecked = [f16::default(); 0x10001];
+                let mut i = 1;
+                while i + $vec::WIDTH <= halves.len() {
+                    let v = $vec::load_f16(&halves, i);
+                    let u = unsafe { $vec::load_f16_unchecked(&halves, i) };
+                    for j in 0..$vec::WIDTH {
+                        let e = halves[i + j].to_f32();
+                        assert!(v.extract(j) == e || (v.extract(j).is_nan() && e.is_nan()),
+                                "load_f16({:?})", halves[i + j]);
+                        assert_eq!(u.extract(j).to_bits(), v.extract(j).to_bits(), "load_f16_unchecked({:?})", halves[i + j]);
+                    }
+                    v.store_f16(&mut stored, i);
+                    unsafe { v.store_f16_unchecked(&mut stored_unchecked, i) };
+                    i += $vec::WIDTH;
+                }
+                for j in 1..i {
+                    assert_eq!(stored[j], f16::from_f32(halves[j].to_f32()), "store_f16({:?})", halves[j].to_f32());
+                    assert_eq!(stored_unchecked[j], stored[j], "store_f16_unchecked({:?})", halves[j].to_f32());
+                }
+
+                let floats = [65519.0f32, 65520.0, 1.0 + 1.0 / 2048.0, 1.0 + 3.0 / 2048.0, 1.5 / 33554432.0, -1e-10,
+                              -1.0 / 0.0, f32::from_bits(0x7F80_2001)];
+                let mut v = $vec::splat(0.0);
+                for j in 0..$vec::WIDTH {
+                    v = v.replace(j, floats[j % floats.len()]);
+                }
+                v.store_f16(&mut stored, 0);
+                for j in 0..$vec::WIDTH {
+                    assert_eq!(stored[j], f16::from_f32(floats[j % floats.len()]), "store_f16({:?})", floats[j % floats.len()]);
+                }
+            }
+        )*
+    }
+}
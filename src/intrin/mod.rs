@@ -5,47 +5,78 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-pub mod abs;
+#[macro_use] pub mod abs;
+#[macro_use] pub mod abs_diff;
 pub mod addsub;
-pub mod cast;
-pub mod cmp;
+#[macro_use] pub mod avg;
+#[macro_use] pub mod argminmax;
+#[macro_use] pub mod bitmask;
+#[macro_use] pub mod bf16;
+#[macro_use] pub mod cast;
+#[macro_use] pub mod cmp;
+#[macro_use] pub mod compact;
 #[macro_use] pub mod destride;
-pub mod downcast;
+#[macro_use] pub mod divide;
+#[macro_use] pub mod downcast;
 #[macro_use] pub mod endian;
 #[macro_use] pub mod eq;
+#[macro_use] pub mod float;
+#[macro_use] pub mod half;
 pub mod hadd;
 pub mod hsub;
 #[macro_use] pub mod macros;
 #[macro_use] pub mod merge;
+#[macro_use] pub mod mul_add;
+#[macro_use] pub mod mul_hi;
+#[macro_use] pub mod ord;
 #[macro_use] pub mod popcnt;
 pub mod recip;
-pub mod round;
+#[macro_use] pub mod reduce;
+#[macro_use] pub mod round;
 pub mod rsqrt;
 #[macro_use] pub mod sum;
 pub mod saturating_add;
 pub mod saturating_hadd;
 pub mod saturating_hsub;
 pub mod saturating_sub;
-pub mod sqrt;
+#[macro_use] pub mod scan;
+#[macro_use] pub mod select;
+#[macro_use] pub mod shift;
+#[macro_use] pub mod sqrt;
+#[macro_use] pub mod swizzle;
 #[macro_use] pub mod transmute;
 pub mod upcast;
+#[macro_use] pub mod widening_mul;
 
 // We use an internal prelude not to clutter the namespace when we import
 // from actual prelude.
 pub(crate) mod prelude {
     pub use super::abs::*;
+    pub use super::abs_diff::*;
     pub use super::addsub::*;
+    pub use super::avg::*;
+    pub use super::argminmax::*;
+    pub use super::bitmask::*;
+    pub use super::bf16::*;
     pub use super::cast::*;
     pub use super::cmp::*;
+    pub use super::compact::*;
     pub use super::destride::*;
+    pub use super::divide::*;
     pub use super::downcast::*;
     pub use super::endian::*;
     pub use super::eq::*;
+    pub use super::float::*;
+    pub use super::half::*;
     pub use super::hadd::*;
     pub use super::hsub::*;
     pub use super::merge::*;
+    pub use super::mul_add::*;
+    pub use super::mul_hi::*;
+    pub use super::ord::*;
     pub use super::popcnt::*;
     pub use super::recip::*;
+    pub use super::reduce::*;
     pub use super::round::*;
     pub use super::rsqrt::*;
     pub use super::sum::*;
@@ -53,7 +84,57 @@ pub(crate) mod prelude {
     pub use super::saturating_hadd::*;
     pub use super::saturating_hsub::*;
     pub use super::saturating_sub::*;
+    pub use super::scan::*;
+    pub use super::select::*;
+    pub use super::shift::*;
     pub use super::sqrt::*;
+    pub use super::swizzle::*;
     pub use super::transmute::*;
     pub use super::upcast::*;
+    pub use super::widening_mul::*;
 }
+
+/// Edge values shared by the intrinsic tests, so every operation sees the
+/// same extremes, sign changes and (for floats) subnormals and NaNs.
+#[cfg(test)]
+pub(crate) trait Specials : Sized {
+    fn specials() -> [Self; 16];
+}
+
+#[cfg(test)]
+pub(crate) fn specials<T : Specials>() -> [T; 16] {
+    T::specials()
+}
+
+#[cfg(test)]
+macro_rules! impl_specials {
+    (int, $($el:tt),*) => {
+        $(
+            impl Specials for $el {
+                fn specials() -> [Self; 16] {
+                    let (min, max) = ($el::min_value(), $el::max_value());
+                    [0, 1, 3, 5, 100, 0x55, !0, (0 as $el).wrapping_sub(3),
+                     min, max, min.wrapping_add(1), max.wrapping_sub(1), min.wrapping_add(7),
+                     max / 3, min / 5, (0 as $el).wrapping_sub(max / 7)]
+                }
+            }
+        )*
+    };
+    (float, $($el:tt),*) => {
+        $(
+            impl Specials for $el {
+                fn specials() -> [Self; 16] {
+                    use crate::std::$el::*;
+                    [0.0, -0.0, 1.0, 1.5, -2.25, 100.0, MAX, MIN,
+                     MIN_POSITIVE, MIN_POSITIVE / 4.0, -MIN_POSITIVE / 4.0, EPSILON,
+                     INFINITY, NEG_INFINITY, NAN, -NAN]
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+impl_specials!(int, u8, i8, u16, i16, u32, i32, u64, i64);
+#[cfg(test)]
+impl_specials!(float, f32, f64);
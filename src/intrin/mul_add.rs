@@ -0,0 +1,314 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+/// Fused multiply-add operations, which round only once. Their results are
+/// the same whether or not the target supports FMA, although the polyfill is
+/// much slower than a separate multiply and add.
+pub trait MulAdd : Packed {
+    /// Return a vector containing `self * a + b` for each element, computed
+    /// with a single rounding.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(2.0).mul_add(f32s(3.0), f32s(1.0)), f32s(7.0));
+    /// assert_eq!(f64s(2.0).mul_add(f64s(-3.0), f64s(1.0)), f64s(-5.0));
+    /// # }
+    /// ```
+    fn mul_add(&self, a: Self, b: Self) -> Self;
+
+    /// Return a vector containing `self * a - b` for each element, computed
+    /// with a single rounding.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(2.0).mul_sub(f32s(3.0), f32s(1.0)), f32s(5.0));
+    /// # }
+    /// ```
+    fn mul_sub(&self, a: Self, b: Self) -> Self;
+
+    /// Return a vector containing `b - self * a` for each element, computed
+    /// with a single rounding.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(2.0).neg_mul_add(f32s(3.0), f32s(1.0)), f32s(-5.0));
+    /// # }
+    /// ```
+    fn neg_mul_add(&self, a: Self, b: Self) -> Self;
+}
+
+/// Scalars with a fused multiply-add which doesn't need `std`.
+pub(crate) trait FusedMulAdd : Copy {
+    /// Return `self * a + b`, computed with a single rounding.
+    fn fused_mul_add(self, a: Self, b: Self) -> Self;
+}
+
+impl FusedMulAdd for f32 {
+    #[inline]
+    fn fused_mul_add(self, a: Self, b: Self) -> Self {
+        // The product of two f32s is exact as an f64. The sum is rounded to
+        // odd, which keeps enough information to round it once more to f32
+        // as if it were exact.
+        let p = self as f64 * a as f64;
+        let b = b as f64;
+        let s = p + b;
+        if !s.is_finite() {
+            return s as f32;
+        }
+        let bb = s - p;
+        let err = (p - (s - bb)) + (b - bb);
+        if err != 0.0 && s.to_bits() & 1 == 0 {
+            // s is inexact, so it isn't zero; step towards the exact sum
+            let s = if (err > 0.0) == (s > 0.0) {
+                f64::from_bits(s.to_bits() + 1)
+            } else {
+                f64::from_bits(s.to_bits() - 1)
+            };
+            return s as f32;
+        }
+        s as f32
+    }
+}
+
+/// Split a finite, nonzero f64 into its sign and an integer mantissa `m` and
+/// exponent `e`, such that its magnitude is `m * 2^e`.
+#[inline]
+fn decompose(x: f64) -> (bool, u128, i32) {
+    let bits = x.to_bits();
+    let exp = ((bits >> 52) & 0x7FF) as i32;
+    let frac = bits & ((1 << 52) - 1);
+    if exp == 0 {
+        (x < 0.0, frac as u128, -1074)
+    } else {
+        (x < 0.0, (frac | (1 << 52)) as u128, exp - 1075)
+    }
+}
+
+/// Shift `m` left until its highest set bit is bit 125, which leaves room
+/// for the carry of an addition, and adjust the exponent to match.
+#[inline]
+fn normalize(m: u128, e: i32) -> (u128, i32) {
+    let shift = m.leading_zeros() as i32 - 2;
+    (m << shift, e - shift)
+}
+
+impl FusedMulAdd for f64 {
+    #[inline]
+    fn fused_mul_add(self, a: Self, b: Self) -> Self {
+        // Infinities, NaNs and zero products don't round, and b doesn't
+        // change the rounding of a product it can't cancel.
+        if !self.is_finite() || !a.is_finite() || !b.is_finite() || self == 0.0 || a == 0.0 {
+            return self * a + b;
+        }
+        if b == 0.0 {
+            return self * a;
+        }
+
+        // The product of two 53-bit mantissas fits in 106 bits, so both
+        // terms of the sum are exact here.
+        let (sx, mx, ex) = decompose(self);
+        let (sa, ma, ea) = decompose(a);
+        let (sb, mb, eb) = decompose(b);
+        let (mp, ep) = normalize(mx * ma, ex + ea);
+        let (mb, eb) = normalize(mb, eb);
+        let sp = sx != sa;
+
+        // Align the smaller term with the larger one. The bits shifted out
+        // are kept as a sticky bit, which is far below the bits of the
+        // result, so it only breaks ties when rounding.
+        let ((sl, ml, e), (ss, ms, es)) = if ep >= eb { ((sp, mp, ep), (sb, mb, eb)) }
+                                          else { ((sb, mb, eb), (sp, mp, ep)) };
+        let d = (e - es) as u32;
+        let ms = if d >= 128 { 1 } else { (ms >> d) | ((ms & ((1 << d) - 1) != 0) as u128) };
+
+        let (sign, m) = if sl == ss {
+            (sl, ml + ms)
+        } else if ml > ms {
+            (sl, ml - ms)
+        } else if ms > ml {
+            (ss, ms - ml)
+        } else {
+            return 0.0;
+        };
+
+        // Round to 53 bits, or fewer if the result is subnormal
+        let top = 127 - m.leading_zeros() as i32 + e;
+        let keep = if top >= -1022 { 53 } else { top + 1075 };
+        let shift = 128 - m.leading_zeros() as i32 - keep;
+        let mut q = if shift <= 0 {
+            m << -shift
+        } else if shift >= 128 {
+            0
+        } else {
+            m >> shift
+        };
+        if shift > 0 {
+            let rem = if shift >= 128 { m } else { m - (q << shift) };
+            let half = if shift > 128 { 0 } else { 1u128 << (shift - 1) };
+            if shift <= 128 && (rem > half || (rem == half && q & 1 == 1)) {
+                q += 1;
+            }
+        }
+
+        let sign = (sign as u64) << 63;
+        if top < -1022 {
+            // A carry into bit 52 makes the smallest normal number
+            return f64::from_bits(sign | q as u64);
+        }
+        let (q, top) = if q == 1 << 53 { (q >> 1, top + 1) } else { (q, top) };
+        if top > 1023 {
+            return f64::from_bits(sign | 0x7FF0_0000_0000_0000);
+        }
+        f64::from_bits(sign | ((top + 1023) as u64) << 52 | (q as u64 & ((1 << 52) - 1)))
+    }
+}
+
+#[inline(always)]
+pub(crate) fn mul_add_fallback<T, F>(x: &T, a: T, b: T, op: F) -> T
+    where T : Packed, F : Fn(T::Scalar, T::Scalar, T::Scalar) -> T::Scalar {
+    fallback!();
+    let mut ret = *x;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(x.extract(i), a.extract(i), b.extract(i)));
+    }
+    ret
+}
+
+// Negating an operand is exact, so every variant rounds once.
+#[allow(unused_macros)]
+macro_rules! impl_mul_add_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl MulAdd for $vec {
+                #[inline(always)]
+                fn mul_add(&self, a: Self, b: Self) -> Self {
+                    mul_add_fallback(self, a, b, |x, a, b| x.fused_mul_add(a, b))
+                }
+
+                #[inline(always)]
+                fn mul_sub(&self, a: Self, b: Self) -> Self {
+                    mul_add_fallback(self, a, b, |x, a, b| x.fused_mul_add(a, -b))
+                }
+
+                #[inline(always)]
+                fn neg_mul_add(&self, a: Self, b: Self) -> Self {
+                    mul_add_fallback(self, a, b, |x, a, b| (-x).fused_mul_add(a, b))
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_mul_add {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let (x, a, b) = ($vec::splat(2.0), $vec::splat(3.0), $vec::splat(1.0));
+                assert_eq!(x.mul_add(a, b), $vec::splat(7.0));
+                assert_eq!(x.mul_sub(a, b), $vec::splat(5.0));
+                assert_eq!(x.neg_mul_add(a, b), $vec::splat(-5.0));
+
+                // (1 + e)^2 - (1 + 2e) is e^2, which is lost if the product
+                // is rounded before the addition.
+                let e = crate::std::$el::EPSILON;
+                let x = $vec::splat(1.0 + e);
+                let sum = $vec::splat(1.0 + 2.0 * e);
+                assert_eq!(x.mul_add(x, $vec::splat(-1.0 - 2.0 * e)), $vec::splat(e * e));
+                assert_eq!(x.mul_sub(x, sum), $vec::splat(e * e));
+                assert_eq!(x.neg_mul_add(x, sum), $vec::splat(-e * e));
+            }
+        )*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FusedMulAdd;
+
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn check_f32(x: f32, a: f32, b: f32) {
+        let (expected, actual) = (x.mul_add(a, b), x.fused_mul_add(a, b));
+        assert!(expected.to_bits() == actual.to_bits() || (expected.is_nan() && actual.is_nan()),
+                "{:?} * {:?} + {:?}: expected {:?}, got {:?}", x, a, b, expected, actual);
+    }
+
+    fn check_f64(x: f64, a: f64, b: f64) {
+        let (expected, actual) = (x.mul_add(a, b), x.fused_mul_add(a, b));
+        assert!(expected.to_bits() == actual.to_bits() || (expected.is_nan() && actual.is_nan()),
+                "{:?} * {:?} + {:?}: expected {:?}, got {:?}", x, a, b, expected, actual);
+    }
+
+    #[test]
+    fn fused_mul_add_f32() {
+        let mut state = 0x2545_F491_4F6C_DD1D;
+        for _ in 0..100_000 {
+            let x = f32::from_bits(xorshift(&mut state) as u32);
+            let a = f32::from_bits(xorshift(&mut state) as u32);
+            let b = f32::from_bits(xorshift(&mut state) as u32);
+            check_f32(x, a, b);
+            // Sums which cancel most of the product
+            check_f32(x, a, -(x * a));
+            check_f32(x, a, f32::from_bits((x * a).to_bits() ^ 0x8000_0001));
+        }
+        check_f32(1.0 + ::std::f32::EPSILON, 1.0 - ::std::f32::EPSILON, -1.0);
+        check_f32(::std::f32::MIN_POSITIVE, 0.5, 0.0);
+        check_f32(::std::f32::MAX, 2.0, -::std::f32::MAX);
+        check_f32(::std::f32::INFINITY, 0.0, 1.0);
+        check_f32(-0.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn fused_mul_add_f64() {
+        let mut state = 0x2545_F491_4F6C_DD1D;
+        for _ in 0..100_000 {
+            let x = f64::from_bits(xorshift(&mut state));
+            let a = f64::from_bits(xorshift(&mut state));
+            let b = f64::from_bits(xorshift(&mut state));
+            check_f64(x, a, b);
+            // Operands which keep the product and sum in range
+            let x = f64::from_bits(xorshift(&mut state) & 0x80FF_FFFF_FFFF_FFFF | 0x3F00_0000_0000_0000);
+            let a = f64::from_bits(xorshift(&mut state) & 0x80FF_FFFF_FFFF_FFFF | 0x3F00_0000_0000_0000);
+            check_f64(x, a, b);
+            check_f64(x, a, -(x * a));
+            check_f64(x, a, f64::from_bits((x * a).to_bits() ^ 0x8000_0000_0000_0001));
+            // Products near and below the subnormal range
+            let tiny = f64::from_bits(xorshift(&mut state) & 0x801F_FFFF_FFFF_FFFF);
+            check_f64(tiny, x, b * ::std::f64::MIN_POSITIVE);
+            check_f64(tiny, a, -(tiny * a));
+        }
+        check_f64(1.0 + ::std::f64::EPSILON, 1.0 - ::std::f64::EPSILON, -1.0);
+        check_f64(::std::f64::MIN_POSITIVE, 0.5, 0.0);
+        check_f64(::std::f64::MIN_POSITIVE, -0.5, 0.0);
+        check_f64(5e-324, 0.5, 0.0);
+        check_f64(5e-324, 0.75, 0.0);
+        check_f64(5e-324, -0.5, 5e-324);
+        check_f64(::std::f64::MAX, 2.0, -::std::f64::MAX);
+        check_f64(::std::f64::MAX, 1.0, ::std::f64::MAX);
+        check_f64(::std::f64::INFINITY, 0.0, 1.0);
+        check_f64(-0.0, 1.0, 0.0);
+        check_f64(3.0, -2.0, 6.0);
+    }
+}
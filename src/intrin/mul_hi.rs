@@ -0,0 +1,140 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+pub trait MulHi : Packed {
+    /// Return a vector containing the high half of the full product of each
+    /// pair of elements of `self` and `other`, which is the product shifted
+    /// right by the width of an element.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s(0x8000).mul_hi(u16s(6)), u16s(3));
+    /// assert_eq!(i16s(-0x8000).mul_hi(i16s(6)), i16s(-3));
+    /// assert_eq!(u32s(0xFFFF_FFFF).mul_hi(u32s(0xFFFF_FFFF)), u32s(0xFFFF_FFFE));
+    /// # }
+    /// ```
+    fn mul_hi(&self, other: Self) -> Self;
+}
+
+/// Rounded multiplication of signed fixed-point numbers, where an element of
+/// n bits holds a fraction in [-1, 1) with n - 1 fractional bits, such as Q15
+/// for 16-bit elements.
+pub trait MulHiRound : Packed {
+    /// Return a vector containing each pair of elements of `self` and `other`
+    /// multiplied as fixed-point fractions, which is their product shifted
+    /// right by one less than the width of an element, rounded to nearest
+    /// with ties rounding up. The product of -1 with itself wraps around to
+    /// -1.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// // 0.5 * 0.25 in Q15
+    /// assert_eq!(i16s(0x4000).mul_hi_round(i16s(0x2000)), i16s(0x1000));
+    /// assert_eq!(i8s(3).mul_hi_round(i8s(-21)), i8s(0));
+    /// assert_eq!(i8s(3).mul_hi_round(i8s(-22)), i8s(-1));
+    /// # }
+    /// ```
+    fn mul_hi_round(&self, other: Self) -> Self;
+}
+
+#[inline(always)]
+pub(crate) fn mul_hi_fallback<T, F>(a: &T, b: &T, op: F) -> T
+    where T : Packed, F : Fn(T::Scalar, T::Scalar) -> T::Scalar {
+    fallback!();
+    let mut ret = *a;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(a.extract(i), b.extract(i)));
+    }
+    ret
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_mul_hi_fallback {
+    ($($vec:ty, $el:ty, $wide:ty),*) => {
+        $(
+            impl MulHi for $vec {
+                #[inline(always)]
+                fn mul_hi(&self, other: Self) -> Self {
+                    let bits = crate::std::mem::size_of::<$el>() * 8;
+                    mul_hi_fallback(self, &other, |a, b| ((a as $wide * b as $wide) >> bits) as $el)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_mul_hi_round_fallback {
+    ($($vec:ty, $el:ty),*) => {
+        $(
+            impl MulHiRound for $vec {
+                #[inline(always)]
+                fn mul_hi_round(&self, other: Self) -> Self {
+                    let bits = crate::std::mem::size_of::<$el>() * 8;
+                    mul_hi_fallback(self, &other, |a, b| {
+                        ((a as i64 * b as i64 + (1 << (bits - 2))) >> (bits - 1)) as $el
+                    })
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_mul_hi {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let bits = crate::std::mem::size_of::<$el>() * 8;
+                let specials = crate::intrin::specials::<$el>();
+                for &a in specials.iter() {
+                    for &b in specials.iter() {
+                        let hi = $vec::splat(a).mul_hi($vec::splat(b));
+                        let product = a as i128 * b as i128;
+                        assert_eq!(hi, $vec::splat((product >> bits) as $el), "mul_hi({:?}, {:?})", a, b);
+                    }
+                }
+
+                let hi = $vec::interleave($el::max_value(), 3 as $el).mul_hi($vec::interleave(2 as $el, $el::max_value()));
+                assert_eq!(hi, $vec::interleave((($el::max_value() as i128 * 2) >> bits) as $el,
+                                                (($el::max_value() as i128 * 3) >> bits) as $el));
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_mul_hi_round {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let bits = crate::std::mem::size_of::<$el>() * 8;
+                let specials = crate::intrin::specials::<$el>();
+                for &a in specials.iter() {
+                    for &b in specials.iter() {
+                        let round = $vec::splat(a).mul_hi_round($vec::splat(b));
+                        let expected = (a as i128 * b as i128 + (1 << (bits - 2))) >> (bits - 1);
+                        assert_eq!(round, $vec::splat(expected as $el), "mul_hi_round({:?}, {:?})", a, b);
+                    }
+                }
+
+                let round = $vec::interleave($el::max_value(), 3 as $el).mul_hi_round($vec::interleave(-1 as $el, $el::min_value()));
+                assert_eq!(round, $vec::interleave(-1 as $el, -3 as $el));
+            }
+        )*
+    }
+}
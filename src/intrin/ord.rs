@@ -0,0 +1,169 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+use crate::intrin::eq::Eq;
+use crate::intrin::bitmask::Bitmask;
+
+/// Ordered comparisons between vectors.
+///
+/// Float comparisons are ordered: a lane holding NaN in either vector is
+/// false in every mask. The unordered comparisons are the negations of the
+/// ordered ones, so `!a.ge_mask(b)` is true where `a < b` or either lane is
+/// NaN. Integer vectors are compared by value, whether signed or unsigned.
+pub trait PackedOrd : Eq {
+    /// Return a mask where each element at an index i is true if the element
+    /// of `self` at index i is less than that of `other`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::interleave(0, 200).lt_mask(u8s(100)), u8s::interleave(0xFF, 0));
+    /// assert_eq!(i32s::halfs(-1, 1).lt_mask(i32s(0)), i32s::halfs(-1, 1).eq_mask(i32s(-1)));
+    /// # }
+    /// ```
+    fn lt_mask(&self, other: Self) -> Self::Out;
+
+    /// Return a mask where each element at an index i is true if the element
+    /// of `self` at index i is less than or equal to that of `other`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert!(u32s(3).le_mask(u32s(3)).all());
+    /// assert!(f32s(::std::f32::NAN).le_mask(f32s(3.0)).none());
+    /// # }
+    /// ```
+    fn le_mask(&self, other: Self) -> Self::Out;
+
+    /// Return a mask where each element at an index i is true if the element
+    /// of `self` at index i is greater than that of `other`.
+    #[inline(always)]
+    fn gt_mask(&self, other: Self) -> Self::Out {
+        other.lt_mask(*self)
+    }
+
+    /// Return a mask where each element at an index i is true if the element
+    /// of `self` at index i is greater than or equal to that of `other`.
+    #[inline(always)]
+    fn ge_mask(&self, other: Self) -> Self::Out {
+        other.le_mask(*self)
+    }
+
+    /// Return a mask where each element at an index i is true if the elements
+    /// of `self` and `other` at index i cannot be ordered, which is when
+    /// either is NaN. Integer vectors are always ordered.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let nan = f64s::halfs(::std::f64::NAN, 1.0);
+    /// assert_eq!(nan.unordered_mask(f64s(0.0)), nan.ne_mask(nan));
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn unordered_mask(&self, _other: Self) -> Self::Out {
+        Self::Out::zeroes()
+    }
+
+    /// Return a mask where each element at an index i is true if neither
+    /// element of `self` nor `other` at index i is NaN.
+    #[inline(always)]
+    fn ordered_mask(&self, other: Self) -> Self::Out {
+        !self.unordered_mask(other)
+    }
+}
+
+/// Build a mask from the results of `cmp` on each pair of lanes.
+#[inline(always)]
+pub(crate) fn cmp_fallback<T, F>(a: &T, b: &T, cmp: F) -> T::Out
+    where T : Eq, F : Fn(T::Scalar, T::Scalar) -> bool {
+    fallback!();
+    let bits = (0..T::WIDTH).fold(0u64, |acc, i| {
+        acc | ((cmp(a.extract(i), b.extract(i)) as u64) << i)
+    });
+    T::Out::from_bitmask(bits)
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_ord_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl PackedOrd for $vec {
+                #[inline(always)]
+                fn lt_mask(&self, other: Self) -> Self::Out {
+                    cmp_fallback(self, &other, |a, b| a < b)
+                }
+
+                #[inline(always)]
+                fn le_mask(&self, other: Self) -> Self::Out {
+                    cmp_fallback(self, &other, |a, b| a <= b)
+                }
+
+                #[inline(always)]
+                fn unordered_mask(&self, other: Self) -> Self::Out {
+                    cmp_fallback(self, &other, |a, b| a.partial_cmp(&b).is_none())
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_ord {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let a = $vec::interleave(1 as $el, 2 as $el);
+                let b = $vec::splat(2 as $el);
+                let evens = a.ne_mask(b);
+                assert_eq!(a.lt_mask(b), evens);
+                assert!(a.le_mask(b).all());
+                assert!(a.gt_mask(b).none());
+                assert_eq!(a.ge_mask(b), !evens);
+                assert_eq!(b.gt_mask(a), evens);
+                assert!(a.unordered_mask(b).none());
+                assert!(a.ordered_mask(b).all());
+
+                // Catches comparisons which wrap around the sign bit
+                let min = $vec::splat($el::min_value());
+                let max = $vec::splat($el::max_value());
+                assert!(min.lt_mask(max).all());
+                assert!(max.gt_mask(min).all());
+                assert!(max.le_mask(min).none());
+                assert!(min.ge_mask(max).none());
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_ord_nan {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let nan = $vec::halfs(crate::std::$el::NAN, 1 as $el);
+                let one = $vec::splat(1 as $el);
+                let num = nan.eq_mask(one);
+                assert_eq!(nan.le_mask(one), num);
+                assert_eq!(nan.ge_mask(one), num);
+                assert!(nan.lt_mask(one).none());
+                assert!(one.gt_mask(nan).none());
+                assert_eq!(nan.unordered_mask(one), !num);
+                assert_eq!(one.ordered_mask(nan), num);
+            }
+        )*
+    }
+}
@@ -16,6 +16,76 @@ pub trait Popcnt : Packed {
     }
 }
 
+/// Bit counting within each element, for bitmaps and other structures which
+/// keep an independent word in each lane.
+pub trait BitCount : Packed {
+    /// Return a vector containing the number of ones in the binary
+    /// representation of each element of `self`. Unlike
+    /// `Popcnt::count_ones`, the count is kept for each element.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s::interleave(0xF0F0, 1).popcnt(), u16s::interleave(8, 1));
+    /// assert_eq!(i8s(-1).popcnt(), i8s(8));
+    /// # }
+    /// ```
+    fn popcnt(&self) -> Self;
+
+    /// Return a vector containing the number of leading zeroes in the binary
+    /// representation of each element of `self`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s::interleave(1, 0).leading_zeros(), u32s::interleave(31, 32));
+    /// # }
+    /// ```
+    fn leading_zeros(&self) -> Self;
+
+    /// Return a vector containing the number of trailing zeroes in the binary
+    /// representation of each element of `self`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u64s::interleave(8, 0).trailing_zeros(), u64s::interleave(3, 64));
+    /// # }
+    /// ```
+    fn trailing_zeros(&self) -> Self;
+
+    /// Return a vector containing each element of `self` with the order of
+    /// its bits reversed.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(0b0000_0110).reverse_bits(), u8s(0b0110_0000));
+    /// assert_eq!(u16s(1).reverse_bits(), u16s(0x8000));
+    /// # }
+    /// ```
+    fn reverse_bits(&self) -> Self;
+}
+
+#[inline(always)]
+pub(crate) fn bit_count_fallback<T, F>(a: &T, op: F) -> T
+    where T : Packed, F : Fn(T::Scalar) -> T::Scalar {
+    fallback!();
+    let mut ret = *a;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(a.extract(i)));
+    }
+    ret
+}
+
 // Only used in some architectures. Might produce `unused` warning on others.
 #[allow(unused_macros)]
 macro_rules! impl_popcnt {
@@ -33,6 +103,38 @@ macro_rules! impl_popcnt {
     }
 }
 
+#[allow(unused_macros)]
+macro_rules! impl_bit_count_fallback {
+    ($($vec:ty, $el:ty, $uel:ty),*) => {
+        $(
+            impl BitCount for $vec {
+                #[inline(always)]
+                fn popcnt(&self) -> Self {
+                    bit_count_fallback(self, |x| x.count_ones() as $el)
+                }
+
+                #[inline(always)]
+                fn leading_zeros(&self) -> Self {
+                    bit_count_fallback(self, |x| x.leading_zeros() as $el)
+                }
+
+                #[inline(always)]
+                fn trailing_zeros(&self) -> Self {
+                    bit_count_fallback(self, |x| x.trailing_zeros() as $el)
+                }
+
+                #[inline(always)]
+                fn reverse_bits(&self) -> Self {
+                    let bits = crate::std::mem::size_of::<$el>() * 8;
+                    bit_count_fallback(self, |x| {
+                        (0..bits).fold(0 as $uel, |r, i| r | (((x as $uel) >> i) & 1) << (bits - 1 - i)) as $el
+                    })
+                }
+            }
+        )*
+    }
+}
+
 // Only used in some architectures. Might produce `unused` warning on others.
 #[allow(unused_macros)]
 macro_rules! test_popcnt {
@@ -48,3 +150,36 @@ macro_rules! test_popcnt {
         )*
     )
 }
+
+#[allow(unused_macros)]
+macro_rules! test_bit_count {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let bits = crate::std::mem::size_of::<$el>() * 8;
+                let specials = crate::intrin::specials::<$el>();
+                let mut x = $vec::splat(0 as $el);
+                let mut y = $vec::splat(0 as $el);
+                for i in 0..$vec::WIDTH {
+                    x = x.replace(i, (0x9E37_79B9_7F4A_7C15u64.wrapping_mul(i as u64 + 1) >> (i % bits)) as $el);
+                    y = y.replace(i, specials[i % specials.len()]);
+                }
+
+                for &x in [x, y, y.swap_bytes()].iter() {
+                    let (ones, lead, trail, rev) = (x.popcnt(), x.leading_zeros(), x.trailing_zeros(), x.reverse_bits());
+                    for i in 0..$vec::WIDTH {
+                        let e = x.extract(i);
+                        assert_eq!(ones.extract(i), e.count_ones() as $el, "popcnt({:?})", e);
+                        assert_eq!(lead.extract(i), e.leading_zeros() as $el, "leading_zeros({:?})", e);
+                        assert_eq!(trail.extract(i), e.trailing_zeros() as $el, "trailing_zeros({:?})", e);
+                        for b in 0..bits {
+                            assert_eq!((rev.extract(i) >> b) & (1 as $el), (e >> (bits - 1 - b)) & (1 as $el),
+                                       "reverse_bits({:?})", e);
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
@@ -0,0 +1,295 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+use crate::intrin::ord::PackedOrd;
+use crate::intrin::select::Select;
+
+/// Horizontal extremes of a vector.
+///
+/// Float extremes ignore NaN elements, like `f32::min` and `f32::max`: the
+/// result is NaN only if every element is NaN. Either zero may be returned if
+/// the extreme elements are zeroes of both signs.
+pub trait HMinMax : Packed {
+    /// Return the smallest element of this vector.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i8s::interleave(-3, 7).hmin(), -3);
+    /// assert_eq!(f32s::halfs(2.5, -0.5).hmin(), -0.5);
+    /// assert_eq!(f32s::halfs(::std::f32::NAN, 2.5).hmin(), 2.5);
+    /// # }
+    /// ```
+    fn hmin(&self) -> Self::Scalar;
+
+    /// Return the largest element of this vector.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s::interleave(3, 60000).hmax(), 60000);
+    /// assert!(f64s(::std::f64::NAN).hmax().is_nan());
+    /// # }
+    /// ```
+    fn hmax(&self) -> Self::Scalar;
+}
+
+pub trait HProduct : Packed {
+    /// Return the product of all elements of this vector. Integers wrap
+    /// around on overflow, and floats are multiplied in an unspecified order,
+    /// which may round differently than `Packed::product`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(1).replace(0, 6).hproduct(), 6);
+    /// assert_eq!(f64s(0.5).replace(1, 8.0).hproduct(), 8.0 / (1u64 << (f64s::WIDTH - 1)) as f64);
+    /// # }
+    /// ```
+    fn hproduct(&self) -> Self::Scalar;
+}
+
+pub trait HBitwise : Packed {
+    /// Return the bitwise and of all elements of this vector.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::interleave(0b1100, 0b1010).hand(), 0b1000);
+    /// # }
+    /// ```
+    fn hand(&self) -> Self::Scalar;
+
+    /// Return the bitwise or of all elements of this vector.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::interleave(0b1100, 0b1010).hor(), 0b1110);
+    /// # }
+    /// ```
+    fn hor(&self) -> Self::Scalar;
+
+    /// Return the bitwise exclusive or of all elements of this vector.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i32s(0).replace(1, -1).replace(2, 6).hxor(), -7);
+    /// # }
+    /// ```
+    fn hxor(&self) -> Self::Scalar;
+}
+
+#[inline(always)]
+pub(crate) fn hreduce_fallback<T, F>(a: &T, op: F) -> T::Scalar
+    where T : Packed, F : Fn(T::Scalar, T::Scalar) -> T::Scalar {
+    fallback!();
+    let mut acc = a.extract(0);
+    for i in 1..T::WIDTH {
+        acc = op(acc, a.extract(i));
+    }
+    acc
+}
+
+/// Return the smaller of `a` and `b`, or the other if one is NaN. Since NaN
+/// loses to everything, reductions over this agree in any order.
+#[inline(always)]
+pub(crate) fn min_num<T : PartialOrd>(a: T, b: T) -> T {
+    if b < a || a.partial_cmp(&a).is_none() { b } else { a }
+}
+
+/// Return the larger of `a` and `b`, or the other if one is NaN.
+#[inline(always)]
+pub(crate) fn max_num<T : PartialOrd>(a: T, b: T) -> T {
+    if b > a || a.partial_cmp(&a).is_none() { b } else { a }
+}
+
+/// The elementwise `min_num` of two vectors.
+#[inline(always)]
+pub(crate) fn packed_min_num<T>(a: T, b: T) -> T
+    where T : PackedOrd, T::Out : Select<T> {
+    (b.lt_mask(a) | a.unordered_mask(a)).select(b, a)
+}
+
+/// The elementwise `max_num` of two vectors.
+#[inline(always)]
+pub(crate) fn packed_max_num<T>(a: T, b: T) -> T
+    where T : PackedOrd, T::Out : Select<T> {
+    (b.gt_mask(a) | a.unordered_mask(a)).select(b, a)
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_hminmax_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl HMinMax for $vec {
+                #[inline(always)]
+                fn hmin(&self) -> Self::Scalar {
+                    hreduce_fallback(self, min_num)
+                }
+
+                #[inline(always)]
+                fn hmax(&self) -> Self::Scalar {
+                    hreduce_fallback(self, max_num)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_hproduct_fallback {
+    (int, $($vec:ty),*) => {
+        $(
+            impl HProduct for $vec {
+                #[inline(always)]
+                fn hproduct(&self) -> Self::Scalar {
+                    hreduce_fallback(self, |a, b| a.wrapping_mul(b))
+                }
+            }
+        )*
+    };
+    (float, $($vec:ty),*) => {
+        $(
+            impl HProduct for $vec {
+                #[inline(always)]
+                fn hproduct(&self) -> Self::Scalar {
+                    hreduce_fallback(self, |a, b| a * b)
+                }
+            }
+        )*
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_hbitwise_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl HBitwise for $vec {
+                #[inline(always)]
+                fn hand(&self) -> Self::Scalar {
+                    hreduce_fallback(self, |a, b| a & b)
+                }
+
+                #[inline(always)]
+                fn hor(&self) -> Self::Scalar {
+                    hreduce_fallback(self, |a, b| a | b)
+                }
+
+                #[inline(always)]
+                fn hxor(&self) -> Self::Scalar {
+                    hreduce_fallback(self, |a, b| a ^ b)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_hreduce_int {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                // Every extreme lands in every lane at some offset
+                let specials = crate::intrin::specials::<$el>();
+                let mut x = $vec::splat(0 as $el);
+                for off in 0..(specials.len() + $vec::WIDTH) {
+                    for i in 0..$vec::WIDTH {
+                        x = x.replace(i, specials[(i * 7 + off) % specials.len()]);
+                    }
+                    if off >= specials.len() {
+                        x = x.replace(off - specials.len(), 2 as $el);
+                    }
+
+                    let fold = |op: fn($el, $el) -> $el| (1..$vec::WIDTH).fold(x.extract(0), |a, i| op(a, x.extract(i)));
+                    assert_eq!(x.hmin(), fold(|a, b| a.min(b)), "hmin({:?})", x);
+                    assert_eq!(x.hmax(), fold(|a, b| a.max(b)), "hmax({:?})", x);
+                    assert_eq!(x.hproduct(), fold(|a, b| a.wrapping_mul(b)), "hproduct({:?})", x);
+                    assert_eq!(x.hand(), fold(|a, b| a & b), "hand({:?})", x);
+                    assert_eq!(x.hor(), fold(|a, b| a | b), "hor({:?})", x);
+                    assert_eq!(x.hxor(), fold(|a, b| a ^ b), "hxor({:?})", x);
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_hreduce_float {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                // Powers of two, so that every order of multiplication is exact
+                let factors = [1.0 as $el, -2.0 as $el, 0.5 as $el, 64.0 as $el, -0.25 as $el, 4.0 as $el, -1.0 as $el];
+                let mut x = $vec::splat(1.0 as $el);
+                for off in 0..(factors.len() + $vec::WIDTH) {
+                    for i in 0..$vec::WIDTH {
+                        x = x.replace(i, factors[(i * 3 + off) % factors.len()]);
+                    }
+                    if off >= factors.len() {
+                        x = x.replace(off - factors.len(), -1024.0 as $el);
+                    }
+
+                    let (mut min, mut max, mut product) = (x.extract(0), x.extract(0), x.extract(0));
+                    for i in 1..$vec::WIDTH {
+                        let e = x.extract(i);
+                        min = if e < min { e } else { min };
+                        max = if e > max { e } else { max };
+                        product *= e;
+                    }
+                    assert_eq!(x.hmin(), min, "hmin({:?})", x);
+                    assert_eq!(x.hmax(), max, "hmax({:?})", x);
+                    assert_eq!(x.hproduct(), product, "hproduct({:?})", x);
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_hminmax_nan {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let nan = crate::std::$el::NAN;
+                assert!($vec::splat(nan).hmin().is_nan());
+                assert!($vec::splat(nan).hmax().is_nan());
+
+                // A NaN in any lane is skipped, whichever side of each
+                // comparison it ends up on
+                for i in 0..$vec::WIDTH {
+                    let x = $vec::splat(nan).replace(i, 3 as $el);
+                    assert_eq!(x.hmin(), 3 as $el, "hmin({:?})", x);
+                    assert_eq!(x.hmax(), 3 as $el, "hmax({:?})", x);
+
+                    let j = (i + 1) % $vec::WIDTH;
+                    let y = $vec::splat(1 as $el).replace(j, -2 as $el).replace(i, nan);
+                    assert_eq!(y.hmin(), -2 as $el, "hmin({:?})", y);
+                    let z = $vec::splat(-1 as $el).replace(j, 2 as $el).replace(i, nan);
+                    assert_eq!(z.hmax(), 2 as $el, "hmax({:?})", z);
+                }
+            }
+        )*
+    }
+}
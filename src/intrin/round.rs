@@ -71,6 +71,39 @@ pub trait Round {
     fn trunc(&self) -> Self;
 }
 
+#[allow(unused_macros)]
+macro_rules! impl_round_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Round for $vec {
+                #[inline(always)]
+                fn round(&self) -> Self {
+                    fallback!();
+                    (0..Self::WIDTH).fold(*self, |acc, i| acc.replace(i, self.extract(i).round()))
+                }
+
+                #[inline(always)]
+                fn ceil(&self) -> Self {
+                    fallback!();
+                    (0..Self::WIDTH).fold(*self, |acc, i| acc.replace(i, self.extract(i).ceil()))
+                }
+
+                #[inline(always)]
+                fn floor(&self) -> Self {
+                    fallback!();
+                    (0..Self::WIDTH).fold(*self, |acc, i| acc.replace(i, self.extract(i).floor()))
+                }
+
+                #[inline(always)]
+                fn trunc(&self) -> Self {
+                    fallback!();
+                    (0..Self::WIDTH).fold(*self, |acc, i| acc.replace(i, self.extract(i).trunc()))
+                }
+            }
+        )*
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -0,0 +1,116 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::std::ops::Add;
+use crate::vecs::*;
+
+pub trait Scan : Packed + Add<Self, Output = Self> {
+    /// Return a vector where the element at index i is the sum of the
+    /// elements of `self` at indices 0 through i.
+    ///
+    /// Sums are computed in log2(`Self::WIDTH`) steps rather than in order,
+    /// so the rounding of float scans may differ from that of a sequential
+    /// sum. Integer sums wrap on overflow.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let scan = u32s(1).scan();
+    /// assert!((0..u32s::WIDTH).all(|i| scan.extract(i) == i as u32 + 1));
+    /// # }
+    /// ```
+    fn scan(&self) -> Self;
+
+    /// Return a vector where the element at index i is the sum of the
+    /// elements of `self` at indices 0 through i - 1. The first element of
+    /// the returned vector is zero.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let scan = u32s(1).scan_exclusive();
+    /// assert!((0..u32s::WIDTH).all(|i| scan.extract(i) == i as u32));
+    /// # }
+    /// ```
+    fn scan_exclusive(&self) -> Self;
+}
+
+/// Move every element of `vec` up by `amt` lanes, filling the bottom with
+/// the default value of the element type.
+#[inline(always)]
+pub(crate) fn shift_lanes_up<T : Packed>(vec: T, amt: usize) -> T {
+    let mut ret = T::default();
+    for i in amt..T::WIDTH {
+        ret = ret.replace(i, vec.extract(i - amt));
+    }
+    ret
+}
+
+// Performs the same additions as the vectorized versions, so floats round
+// identically whichever is used.
+#[inline(always)]
+pub(crate) fn scan_fallback<T : Packed + Add<T, Output = T>>(vec: T) -> T {
+    fallback!();
+    let mut acc = vec;
+    let mut amt = 1;
+    while amt < T::WIDTH {
+        acc = acc + shift_lanes_up(acc, amt);
+        amt *= 2;
+    }
+    acc
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_scan_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Scan for $vec {
+                #[inline(always)]
+                fn scan(&self) -> Self {
+                    scan_fallback(*self)
+                }
+
+                #[inline(always)]
+                fn scan_exclusive(&self) -> Self {
+                    scan_fallback(shift_lanes_up(*self, 1))
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_scan {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let inclusive = $vec::splat(1 as $el).scan();
+                let exclusive = $vec::splat(1 as $el).scan_exclusive();
+                for i in 0..$vec::WIDTH {
+                    assert_eq!(inclusive.extract(i), (i + 1) as $el);
+                    assert_eq!(exclusive.extract(i), i as $el);
+                }
+
+                let mut v = $vec::splat(0 as $el);
+                for i in 0..$vec::WIDTH {
+                    v = v.replace(i, (i % 3) as $el);
+                }
+                let mut acc = 0 as $el;
+                for i in 0..$vec::WIDTH {
+                    assert_eq!(v.scan_exclusive().extract(i), acc);
+                    acc = acc + (i % 3) as $el;
+                    assert_eq!(v.scan().extract(i), acc);
+                }
+            }
+        )*
+    }
+}
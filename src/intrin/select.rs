@@ -0,0 +1,75 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+use crate::intrin::bitmask::Bitmask;
+use crate::mask::PackedMask;
+
+pub trait Select<T : Packed> : PackedMask {
+    /// Return a vector whose element at index i is the element of `if_true`
+    /// at index i if this mask is true there, and the element of `if_false`
+    /// at index i otherwise.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let x = i32s::halfs(-1, 1);
+    /// let neg = x.eq_mask(i32s(-1));
+    /// assert_eq!(neg.select(i32s(5), i32s(7)), i32s::halfs(5, 7));
+    /// assert_eq!((!neg).select(f32s(0.5), f32s(2.0)), f32s::halfs(2.0, 0.5));
+    /// # }
+    /// ```
+    fn select(&self, if_true: T, if_false: T) -> T;
+}
+
+#[inline(always)]
+pub(crate) fn select_fallback<M, T>(mask: &M, if_true: T, if_false: T) -> T
+    where M : Bitmask, T : Packed {
+    fallback!();
+    let bits = mask.bitmask();
+    let mut ret = if_false;
+    for i in 0..T::WIDTH {
+        if bits & (1 << i) != 0 {
+            ret = ret.replace(i, if_true.extract(i));
+        }
+    }
+    ret
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_select_fallback {
+    ($mask:ty, $($vec:ty),*) => {
+        $(
+            impl Select<$vec> for $mask {
+                #[inline(always)]
+                fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                    select_fallback(self, if_true, if_false)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_select {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let a = $vec::splat(1 as $el);
+                let b = $vec::splat(2 as $el);
+                let sel = $vec::interleave(1 as $el, 0 as $el).eq_mask(a);
+                assert_eq!(sel.select(a, b), $vec::interleave(1 as $el, 2 as $el));
+                assert_eq!((!sel).select(a, b), $vec::interleave(2 as $el, 1 as $el));
+                assert_eq!(a.eq_mask(a).select(a, b), a);
+                assert_eq!(a.ne_mask(a).select(a, b), b);
+            }
+        )*
+    }
+}
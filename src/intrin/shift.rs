@@ -0,0 +1,348 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+/// Shifts which move every element by the same number of bits.
+///
+/// Shifting by the width of an element or more shifts out every bit, leaving
+/// zero, or copies of the sign bit for arithmetic shifts. This matches the
+/// x86 vector shifts rather than Rust's shift operators, which panic or wrap
+/// the amount.
+pub trait Shift : Packed {
+    /// Return a vector containing each element of `self` shifted left by
+    /// `amount` bits.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(0b0110_0001).shift_left(2), u8s(0b1000_0100));
+    /// assert_eq!(u32s(1).shift_left(32), u32s(0));
+    /// # }
+    /// ```
+    fn shift_left(&self, amount: u32) -> Self;
+
+    /// Return a vector containing each element of `self` shifted right by
+    /// `amount` bits, filling the vacated bits with zeroes.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i16s(-256).shift_right_logical(8), i16s(0xFF));
+    /// # }
+    /// ```
+    fn shift_right_logical(&self, amount: u32) -> Self;
+
+    /// Return a vector containing each element of `self` shifted right by
+    /// `amount` bits, filling the vacated bits with copies of the sign bit.
+    /// Unsigned elements are treated as if they were signed.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i8s::halfs(-128, 64).shift_right_arithmetic(6), i8s::halfs(-2, 1));
+    /// assert_eq!(i64s(-5).shift_right_arithmetic(100), i64s(-1));
+    /// assert_eq!(u32s(0x8000_0000).shift_right_arithmetic(4), u32s(0xF800_0000));
+    /// # }
+    /// ```
+    fn shift_right_arithmetic(&self, amount: u32) -> Self;
+}
+
+/// Shifts which move each element by the number of bits in the matching
+/// element of another vector.
+///
+/// Amounts are read as unsigned integers, so a negative amount shifts out
+/// every bit, as does any amount of at least the width of an element.
+pub trait ShiftVar : Packed {
+    /// Return a vector whose element at index i is the element of `self` at
+    /// index i shifted left by the element of `amounts` at index i.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(1).shift_left_var(u32s::interleave(3, 40)), u32s::interleave(8, 0));
+    /// # }
+    /// ```
+    fn shift_left_var(&self, amounts: Self) -> Self;
+
+    /// Return a vector whose element at index i is the element of `self` at
+    /// index i shifted right by the element of `amounts` at index i, filling
+    /// the vacated bits with zeroes.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s(0x8000).shift_right_logical_var(u16s::interleave(15, 1)),
+    ///            u16s::interleave(1, 0x4000));
+    /// # }
+    /// ```
+    fn shift_right_logical_var(&self, amounts: Self) -> Self;
+
+    /// Return a vector whose element at index i is the element of `self` at
+    /// index i shifted right by the element of `amounts` at index i, filling
+    /// the vacated bits with copies of the sign bit.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i32s(-64).shift_right_arithmetic_var(i32s::interleave(4, -1)),
+    ///            i32s::interleave(-4, -1));
+    /// # }
+    /// ```
+    fn shift_right_arithmetic_var(&self, amounts: Self) -> Self;
+}
+
+/// Bitwise rotations of each element. Unlike shifts, rotating by the width of
+/// an element or more wraps around, as the amount is taken modulo the width.
+pub trait Rotate : Packed {
+    /// Return a vector containing each element of `self` rotated left by
+    /// `amount` bits.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(0b1000_0001).rotate_left(1), u8s(0b0000_0011));
+    /// assert_eq!(u64s(3).rotate_left(127), u64s::splat(3u64.rotate_left(127)));
+    /// # }
+    /// ```
+    fn rotate_left(&self, amount: u32) -> Self;
+
+    /// Return a vector containing each element of `self` rotated right by
+    /// `amount` bits.
+    fn rotate_right(&self, amount: u32) -> Self;
+
+    /// Return a vector whose element at index i is the element of `self` at
+    /// index i rotated left by the element of `amounts` at index i.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s(0x1234).rotate_left_var(u16s::interleave(4, 20)), u16s(0x2341));
+    /// # }
+    /// ```
+    fn rotate_left_var(&self, amounts: Self) -> Self;
+
+    /// Return a vector whose element at index i is the element of `self` at
+    /// index i rotated right by the element of `amounts` at index i.
+    fn rotate_right_var(&self, amounts: Self) -> Self;
+}
+
+/// Shifts of a single element with the same out-of-range behaviour as the
+/// vector shifts, for use in fallbacks.
+pub(crate) trait ScalarShift : Copy {
+    fn shl_lane(self, amount: u32) -> Self;
+    fn shr_logical_lane(self, amount: u32) -> Self;
+    fn shr_arithmetic_lane(self, amount: u32) -> Self;
+
+    /// Read this element as a shift amount, saturating at `u32::MAX`.
+    fn lane_amount(self) -> u32;
+}
+
+macro_rules! impl_scalar_shift {
+    ($($el:ty, $uel:ty, $iel:ty),*) => {
+        $(
+            impl ScalarShift for $el {
+                #[inline(always)]
+                fn shl_lane(self, amount: u32) -> Self {
+                    self.checked_shl(amount).unwrap_or(0)
+                }
+
+                #[inline(always)]
+                fn shr_logical_lane(self, amount: u32) -> Self {
+                    (self as $uel).checked_shr(amount).unwrap_or(0) as $el
+                }
+
+                #[inline(always)]
+                fn shr_arithmetic_lane(self, amount: u32) -> Self {
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                    ((self as $iel) >> amount.min(bits - 1)) as $el
+                }
+
+                #[inline(always)]
+                fn lane_amount(self) -> u32 {
+                    (self as $uel).min(u32::max_value() as $uel) as u32
+                }
+            }
+        )*
+    }
+}
+
+impl_scalar_shift!(u8, u8, i8, i8, u8, i8, u16, u16, i16, i16, u16, i16,
+                   u32, u32, i32, i32, u32, i32, u64, u64, i64, i64, u64, i64);
+
+#[inline(always)]
+pub(crate) fn shift_fallback<T, F>(x: &T, amount: u32, op: F) -> T
+    where T : Packed, F : Fn(T::Scalar, u32) -> T::Scalar {
+    fallback!();
+    let mut ret = *x;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(x.extract(i), amount));
+    }
+    ret
+}
+
+#[inline(always)]
+pub(crate) fn shift_var_fallback<T, F>(x: &T, amounts: &T, op: F) -> T
+    where T : Packed, T::Scalar : ScalarShift, F : Fn(T::Scalar, u32) -> T::Scalar {
+    fallback!();
+    let mut ret = *x;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, op(x.extract(i), amounts.extract(i).lane_amount()));
+    }
+    ret
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_shift_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Shift for $vec {
+                #[inline(always)]
+                fn shift_left(&self, amount: u32) -> Self {
+                    shift_fallback(self, amount, ScalarShift::shl_lane)
+                }
+
+                #[inline(always)]
+                fn shift_right_logical(&self, amount: u32) -> Self {
+                    shift_fallback(self, amount, ScalarShift::shr_logical_lane)
+                }
+
+                #[inline(always)]
+                fn shift_right_arithmetic(&self, amount: u32) -> Self {
+                    shift_fallback(self, amount, ScalarShift::shr_arithmetic_lane)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_shift_var_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl ShiftVar for $vec {
+                #[inline(always)]
+                fn shift_left_var(&self, amounts: Self) -> Self {
+                    shift_var_fallback(self, &amounts, ScalarShift::shl_lane)
+                }
+
+                #[inline(always)]
+                fn shift_right_logical_var(&self, amounts: Self) -> Self {
+                    shift_var_fallback(self, &amounts, ScalarShift::shr_logical_lane)
+                }
+
+                #[inline(always)]
+                fn shift_right_arithmetic_var(&self, amounts: Self) -> Self {
+                    shift_var_fallback(self, &amounts, ScalarShift::shr_arithmetic_lane)
+                }
+            }
+        )*
+    }
+}
+
+// Rotates are built from a pair of shifts. When the amount is a multiple of
+// the width, the right shift is by the full width and contributes nothing.
+#[allow(unused_macros)]
+macro_rules! impl_rotate {
+    ($($vec:ty, $el:ty),*) => {
+        $(
+            impl Rotate for $vec {
+                #[inline(always)]
+                fn rotate_left(&self, amount: u32) -> Self {
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                    let amount = amount % bits;
+                    self.shift_left(amount) | self.shift_right_logical(bits - amount)
+                }
+
+                #[inline(always)]
+                fn rotate_right(&self, amount: u32) -> Self {
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                    let amount = amount % bits;
+                    self.shift_right_logical(amount) | self.shift_left(bits - amount)
+                }
+
+                #[inline(always)]
+                fn rotate_left_var(&self, amounts: Self) -> Self {
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as $el;
+                    let amounts = amounts & Self::splat(bits - 1);
+                    self.shift_left_var(amounts) | self.shift_right_logical_var(Self::splat(bits) - amounts)
+                }
+
+                #[inline(always)]
+                fn rotate_right_var(&self, amounts: Self) -> Self {
+                    let bits = (crate::std::mem::size_of::<$el>() * 8) as $el;
+                    let amounts = amounts & Self::splat(bits - 1);
+                    self.shift_right_logical_var(amounts) | self.shift_left_var(Self::splat(bits) - amounts)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_packed_shift {
+    (($($vec:tt),*), ($($el:tt),*), ($($uel:tt),*), ($($iel:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let bits = (crate::std::mem::size_of::<$el>() * 8) as u32;
+                let mut x = $vec::splat(0 as $el);
+                for i in 0..$vec::WIDTH {
+                    let v = $el::max_value().wrapping_sub((i as $el).wrapping_mul(37 as $el))
+                        .rotate_left(i as u32);
+                    x = x.replace(i, if i % 2 == 0 { v } else { !v });
+                }
+
+                for &n in [0, 1, bits / 2 + 1, bits - 1, bits, bits + 1, u32::max_value()].iter() {
+                    let (shl, shrl, shra) = (x.shift_left(n), x.shift_right_logical(n), x.shift_right_arithmetic(n));
+                    let (rotl, rotr) = (x.rotate_left(n), x.rotate_right(n));
+                    for i in 0..$vec::WIDTH {
+                        let v = x.extract(i);
+                        assert_eq!(shl.extract(i), v.checked_shl(n).unwrap_or(0));
+                        assert_eq!(shrl.extract(i), (v as $uel).checked_shr(n).unwrap_or(0) as $el);
+                        assert_eq!(shra.extract(i), ((v as $iel) >> n.min(bits - 1)) as $el);
+                        assert_eq!(rotl.extract(i), v.rotate_left(n % bits));
+                        assert_eq!(rotr.extract(i), v.rotate_right(n % bits));
+                    }
+
+                    // Gives each lane a different amount, with one amount
+                    // which is negative or too large for the lane.
+                    let mut amounts = $vec::splat(!(0 as $el));
+                    for i in 1..$vec::WIDTH {
+                        amounts = amounts.replace(i, ((i as u32 + n % bits) % (bits + 2)) as $el);
+                    }
+                    let (shl, shrl, shra) = (x.shift_left_var(amounts), x.shift_right_logical_var(amounts), x.shift_right_arithmetic_var(amounts));
+                    let (rotl, rotr) = (x.rotate_left_var(amounts), x.rotate_right_var(amounts));
+                    for i in 0..$vec::WIDTH {
+                        let (v, a) = (x.extract(i), (amounts.extract(i) as $uel).min(u32::max_value() as $uel) as u32);
+                        assert_eq!(shl.extract(i), v.checked_shl(a).unwrap_or(0));
+                        assert_eq!(shrl.extract(i), (v as $uel).checked_shr(a).unwrap_or(0) as $el);
+                        assert_eq!(shra.extract(i), ((v as $iel) >> a.min(bits - 1)) as $el);
+                        assert_eq!(rotl.extract(i), v.rotate_left(a % bits));
+                        assert_eq!(rotr.extract(i), v.rotate_right(a % bits));
+                    }
+                }
+            }
+        )*
+    }
+}
@@ -23,6 +23,21 @@ pub trait Sqrt {
     fn sqrt(&self) -> Self;
 }
 
+#[allow(unused_macros)]
+macro_rules! impl_sqrt_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Sqrt for $vec {
+                #[inline(always)]
+                fn sqrt(&self) -> Self {
+                    fallback!();
+                    (0..Self::WIDTH).fold(*self, |acc, i| acc.replace(i, self.extract(i).sqrt()))
+                }
+            }
+        )*
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -5,6 +5,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use crate::vecs::*;
+
 pub trait Swizzle {
     /// Return a vector containing elements of self, but with even and odd
     /// elements swapped in-place. For (n = 0, 2, ... Self::WIDTH), elements at
@@ -22,6 +24,191 @@ pub trait Swizzle {
     fn flip(&self) -> Self;
 }
 
+pub trait Shuffle : Packed {
+    /// The vector of unsigned integers which indexes the elements of `Self`.
+    type Indices : Packed;
+
+    /// An array holding an index into `Self` for every element of `Self`.
+    type Lanes : Copy;
+
+    /// Return a vector whose `i`th element is the element of `self` indexed
+    /// by the `i`th element of `indices`. Indices are taken modulo
+    /// `Self::WIDTH`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::{f32x4, u32x4};
+    ///
+    /// # fn main() {
+    /// let v = f32x4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.permute(u32x4::new(3, 3, 0, 5)), f32x4::new(4.0, 4.0, 1.0, 2.0));
+    /// # }
+    /// ```
+    fn permute(&self, indices: Self::Indices) -> Self;
+
+    /// Return a vector whose `i`th element is the element of `self` at
+    /// `indices[i]`. The indices are an array of exactly `Self::WIDTH`
+    /// elements, so passing too few or too many doesn't compile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of `indices` are out of bounds.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::u32x4;
+    ///
+    /// # fn main() {
+    /// let v = u32x4::new(1, 2, 3, 4);
+    /// assert_eq!(v.shuffle([1, 0, 3, 2]), u32x4::new(2, 1, 4, 3));
+    /// assert_eq!(v.shuffle([3, 3, 3, 0]), u32x4::new(4, 4, 4, 1));
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::u32x4;
+    ///
+    /// # fn main() {
+    /// u32x4::new(1, 2, 3, 4).shuffle([1, 0, 3]);
+    /// # }
+    /// ```
+    fn shuffle(&self, indices: Self::Lanes) -> Self;
+
+    /// Return a vector whose elements are all the `idx`th element of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not less than `Self::WIDTH`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i16s::interleave(-3, 8).broadcast(1), i16s(8));
+    /// # }
+    /// ```
+    fn broadcast(&self, idx: usize) -> Self;
+
+    /// Return a vector containing the elements of `self` rotated `amount`
+    /// places towards the first element, like `slice::rotate_left`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::u32x4;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32x4::new(1, 2, 3, 4).rotate_lanes_left(1), u32x4::new(2, 3, 4, 1));
+    /// assert_eq!(u32x4::new(1, 2, 3, 4).rotate_lanes_left(6), u32x4::new(3, 4, 1, 2));
+    /// # }
+    /// ```
+    fn rotate_lanes_left(&self, amount: usize) -> Self;
+
+    /// Return a vector containing the elements of `self` rotated `amount`
+    /// places towards the last element, like `slice::rotate_right`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::u32x4;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32x4::new(1, 2, 3, 4).rotate_lanes_right(1), u32x4::new(4, 1, 2, 3));
+    /// # }
+    /// ```
+    fn rotate_lanes_right(&self, amount: usize) -> Self;
+
+    /// Return a vector containing the elements of `self` moved `amount`
+    /// places towards the first element, with zeroes in the last `amount`
+    /// elements. Shifting by `Self::WIDTH` or more returns zeroes.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::u32x4;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32x4::new(1, 2, 3, 4).shift_lanes_left(1), u32x4::new(2, 3, 4, 0));
+    /// # }
+    /// ```
+    fn shift_lanes_left(&self, amount: usize) -> Self;
+
+    /// Return a vector containing the elements of `self` moved `amount`
+    /// places towards the last element, with zeroes in the first `amount`
+    /// elements. Shifting by `Self::WIDTH` or more returns zeroes.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::u32x4;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32x4::new(1, 2, 3, 4).shift_lanes_right(1), u32x4::new(0, 1, 2, 3));
+    /// # }
+    /// ```
+    fn shift_lanes_right(&self, amount: usize) -> Self;
+
+    /// Return the `Self::WIDTH` elements beginning at the `amount`th element
+    /// of `self` followed by `other`. Aligning each vector of a stream with
+    /// the next one yields windows at every offset into the stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amount` is greater than `Self::WIDTH`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    /// use faster::arch::current::vecs::u32x4;
+    ///
+    /// # fn main() {
+    /// let (a, b) = (u32x4::new(1, 2, 3, 4), u32x4::new(5, 6, 7, 8));
+    /// assert_eq!(a.alignr(b, 1), u32x4::new(2, 3, 4, 5));
+    /// assert_eq!(a.alignr(b, 3), u32x4::new(4, 5, 6, 7));
+    /// # }
+    /// ```
+    fn alignr(&self, other: Self, amount: usize) -> Self;
+}
+
+#[inline(always)]
+pub(crate) fn flip_fallback<T>(a: &T) -> T where T : Packed {
+    fallback!();
+    let mut ret = T::default();
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, a.extract(i ^ 1));
+    }
+    ret
+}
+
+#[inline(always)]
+pub(crate) fn permute_fallback<T, I, F>(a: &T, indices: &I, index: F) -> T
+    where T : Packed, I : Packed, F : Fn(I::Scalar) -> usize {
+    fallback!();
+    let mut ret = T::default();
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, a.extract(index(indices.extract(i)) % T::WIDTH));
+    }
+    ret
+}
+
+#[inline(always)]
+pub(crate) fn alignr_fallback<T>(a: &T, b: T, amount: usize) -> T where T : Shuffle {
+    assert!(amount <= T::WIDTH);
+    if amount == 0 {
+        *a
+    } else {
+        // Both vectors are rotated into place, and the first
+        // `T::WIDTH - amount` elements are taken from `a`
+        a.rotate_lanes_left(amount).merge_partitioned(b.rotate_lanes_left(amount), T::WIDTH - amount)
+    }
+}
+
+#[allow(unused_macros)]
 macro_rules! impl_packed_swizzle {
     ($vec:tt, $uvec:tt, $feat:expr, $mmfn:tt, ($($c:expr),*), ($($a:expr, $b:expr),*)) => {
         impl Swizzle for $vec {
@@ -43,3 +230,151 @@ macro_rules! impl_packed_swizzle {
         }
     }
 }
+
+#[allow(unused_macros)]
+macro_rules! impl_swizzle_fallback {
+    ($($vec:ty),*) => {
+        $(
+            impl Swizzle for $vec {
+                #[inline(always)]
+                fn flip(&self) -> Self {
+                    flip_fallback(self)
+                }
+            }
+        )*
+    }
+}
+
+// Everything besides `permute` and `alignr` is a permute by an index vector
+// which only depends on the other arguments, so it shares the fast paths of
+// `permute`.
+#[allow(unused_macros)]
+macro_rules! impl_shuffle_lanes {
+    ($idx:ident, $iel:ty, $n:expr) => {
+        type Lanes = [usize; $n];
+
+        #[inline(always)]
+        fn shuffle(&self, indices: Self::Lanes) -> Self {
+            let mut idx = $idx::splat(0);
+            for i in 0..Self::WIDTH {
+                assert!(indices[i] < Self::WIDTH);
+                idx = idx.replace(i, indices[i] as $iel);
+            }
+            self.permute(idx)
+        }
+
+        #[inline(always)]
+        fn broadcast(&self, idx: usize) -> Self {
+            assert!(idx < Self::WIDTH);
+            self.permute($idx::splat(idx as $iel))
+        }
+
+        #[inline(always)]
+        fn rotate_lanes_left(&self, amount: usize) -> Self {
+            let mut idx = $idx::splat(0);
+            for i in 0..Self::WIDTH {
+                idx = idx.replace(i, ((i + amount % Self::WIDTH) % Self::WIDTH) as $iel);
+            }
+            self.permute(idx)
+        }
+
+        #[inline(always)]
+        fn rotate_lanes_right(&self, amount: usize) -> Self {
+            self.rotate_lanes_left(Self::WIDTH - amount % Self::WIDTH)
+        }
+
+        #[inline(always)]
+        fn shift_lanes_left(&self, amount: usize) -> Self {
+            if amount >= Self::WIDTH {
+                Self::zeroes()
+            } else {
+                self.alignr(Self::zeroes(), amount)
+            }
+        }
+
+        #[inline(always)]
+        fn shift_lanes_right(&self, amount: usize) -> Self {
+            if amount >= Self::WIDTH {
+                Self::zeroes()
+            } else {
+                Self::zeroes().alignr(*self, Self::WIDTH - amount)
+            }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_shuffle_fallback {
+    ($($vec:ty, $idx:ident, $iel:ty, $n:expr),*) => {
+        $(
+            impl Shuffle for $vec {
+                type Indices = $idx;
+
+                #[inline(always)]
+                fn permute(&self, indices: Self::Indices) -> Self {
+                    permute_fallback(self, &indices, |i| i as usize)
+                }
+
+                #[inline(always)]
+                fn alignr(&self, other: Self, amount: usize) -> Self {
+                    alignr_fallback(self, other, amount)
+                }
+
+                impl_shuffle_lanes!($idx, $iel, $n);
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_shuffle {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let w = $vec::WIDTH;
+                let (mut a, mut b) = ($vec::splat(0 as $el), $vec::splat(0 as $el));
+                for i in 0..w {
+                    a = a.replace(i, (i + 1) as $el);
+                    b = b.replace(i, (i + w + 1) as $el);
+                }
+                // The elements of `a` followed by those of `b`, or zero
+                let at = |i: usize| if i < 2 * w { (i + 1) as $el } else { 0 as $el };
+
+                let mut indices = [0usize; $vec::WIDTH];
+                for i in 0..w {
+                    indices[i] = (i * 5 + 3) % w;
+                }
+                let mut idx = <$vec as Shuffle>::Indices::splat(0);
+                for i in 0..w {
+                    idx = idx.replace(i, ((i * 5 + 3) % w + w * (i % 3)) as _);
+                }
+                let (shuffled, permuted) = (a.shuffle(indices), a.permute(idx));
+                for i in 0..w {
+                    assert_eq!(shuffled.extract(i), at(indices[i]), "shuffle({:?})", a);
+                    assert_eq!(permuted.extract(i), at(indices[i]), "permute({:?})", a);
+                }
+
+                for n in 0..(2 * w + 1) {
+                    let (rl, rr) = (a.rotate_lanes_left(n), a.rotate_lanes_right(n));
+                    let (sl, sr) = (a.shift_lanes_left(n), a.shift_lanes_right(n));
+                    for i in 0..w {
+                        assert_eq!(rl.extract(i), at((i + n) % w), "rotate_lanes_left({})", n);
+                        assert_eq!(rr.extract(i), at((i + w - n % w) % w), "rotate_lanes_right({})", n);
+                        assert_eq!(sl.extract(i), if i + n < w { at(i + n) } else { 0 as $el }, "shift_lanes_left({})", n);
+                        assert_eq!(sr.extract(i), if i >= n { at(i - n) } else { 0 as $el }, "shift_lanes_right({})", n);
+                    }
+                    if n <= w {
+                        let aligned = a.alignr(b, n);
+                        for i in 0..w {
+                            assert_eq!(aligned.extract(i), at(i + n), "alignr({})", n);
+                        }
+                    }
+                    if n < w {
+                        assert_eq!(a.broadcast(n), $vec::splat(at(n)));
+                    }
+                }
+            }
+        )*
+    }
+}
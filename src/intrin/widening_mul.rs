@@ -0,0 +1,83 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+pub trait WideningMul : Packed {
+    type Out : Packed;
+
+    /// Return two vectors containing the full products of each pair of
+    /// elements of `self` and `other`, in elements twice as wide. As with
+    /// `Upcast`, the first vector holds the products of the first half of the
+    /// elements, and the second vector holds the rest.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(0xFFFF_FFFF).widening_mul(u32s(2)), (u64s(0x1_FFFF_FFFE), u64s(0x1_FFFF_FFFE)));
+    /// assert_eq!(i16s::halfs(-300, 300).widening_mul(i16s(300)), (i32s(-90000), i32s(90000)));
+    /// # }
+    /// ```
+    fn widening_mul(&self, other: Self) -> (Self::Out, Self::Out);
+}
+
+#[inline(always)]
+pub(crate) fn widening_mul_fallback<T, U, F>(a: &T, b: &T, op: F) -> (U, U)
+    where T : Packed, U : Packed, F : Fn(T::Scalar, T::Scalar) -> U::Scalar {
+    fallback!();
+    let (mut lo, mut hi) = (U::default(), U::default());
+    for i in 0..U::WIDTH {
+        lo = lo.replace(i, op(a.extract(i), b.extract(i)));
+        hi = hi.replace(i, op(a.extract(i + U::WIDTH), b.extract(i + U::WIDTH)));
+    }
+    (lo, hi)
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_widening_mul_fallback {
+    ($($vec:ty, $out:ty, $wel:ty),*) => {
+        $(
+            impl WideningMul for $vec {
+                type Out = $out;
+
+                #[inline(always)]
+                fn widening_mul(&self, other: Self) -> (Self::Out, Self::Out) {
+                    widening_mul_fallback(self, &other, |a, b| a as $wel * b as $wel)
+                }
+            }
+        )*
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! test_widening_mul {
+    (($($vec:tt),*), ($($el:tt),*), ($($name:ident),*)) => {
+        $(
+            #[test]
+            fn $name() {
+                let specials = crate::intrin::specials::<$el>();
+                let mut a = $vec::splat(0 as $el);
+                let mut b = $vec::splat(0 as $el);
+                for i in 0..$vec::WIDTH {
+                    a = a.replace(i, specials[i % specials.len()]);
+                    b = b.replace(i, specials[(i * 5 + 2) % specials.len()]);
+                }
+
+                for &(a, b) in [(a, b), (b, a), (a, a)].iter() {
+                    let (lo, hi) = a.widening_mul(b);
+                    let half = $vec::WIDTH / 2;
+                    for i in 0..half {
+                        assert_eq!(lo.extract(i) as i128, a.extract(i) as i128 * b.extract(i) as i128);
+                        assert_eq!(hi.extract(i) as i128, a.extract(i + half) as i128 * b.extract(i + half) as i128);
+                    }
+                }
+            }
+        )*
+    }
+}
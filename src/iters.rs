@@ -6,7 +6,18 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::vecs::{Packable, Packed};
+use crate::intrin::eq::Eq as PackedEq;
+use crate::intrin::compact::Compact;
+use crate::intrin::scan::Scan;
+use crate::intrin::argminmax::{ArgMinMax, beats_min, beats_max, replaces_min, replaces_max};
+use crate::intrin::bitmask::lane_bits;
+use crate::intrin::ord::PackedOrd;
+use crate::intrin::select::Select;
+use crate::mask::PackedMask;
+use crate::std::mem::size_of;
+use crate::std::ops::Add;
 use crate::std::slice::from_raw_parts;
+use crate::std::marker::PhantomData;
 
 pub trait SIMDObject : Sized {
     type Scalar : Packable;
@@ -165,6 +176,430 @@ pub trait SIMDIterator : SIMDIterable {
         }
         start
     }
+
+    #[inline(always)]
+    /// Return an iterator which calls `func` on vectors of elements, along
+    /// with a mask of the lanes which hold elements of the iterator. Each
+    /// lane of the mask is filled with 1s if the corresponding lane of the
+    /// vector was loaded from the iterator, and filled with zeroes if it was
+    /// padded with the default vector.
+    fn simd_map_masked<A, B, F>(self, func: F) -> SIMDMaskedMap<Self, F>
+        where F : FnMut(Self::Vector, <Self::Vector as PackedEq>::Out) -> A, A : Packed<Scalar = B>, B : Packable, Self::Vector : PackedEq {
+        SIMDMaskedMap {
+            iter: self,
+            func: func,
+        }
+    }
+
+    #[inline(always)]
+    /// Return a value generated by reducing `func` over accumulator `start`,
+    /// the values of this iterator, and a mask of the lanes which hold
+    /// elements of the iterator. Each lane of the mask is filled with 1s if
+    /// the corresponding lane of the vector was loaded from the iterator, and
+    /// filled with zeroes if it was padded with the default vector.
+    ///
+    /// Unlike `simd_reduce`, this allows a reduction to ignore padding, rather
+    /// than relying on a default vector which doesn't affect its result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let max = u32::max_value();
+    /// let min = [5u32, 3, 9, 7, 4].simd_iter(u32s(0))
+    ///     .simd_reduce_masked(u32s(max), |acc, v, valid| acc.min(v | (!valid).into_inner()))
+    ///     .scalar_reduce(max, |acc, x| acc.min(x));
+    /// assert_eq!(min, 3);
+    /// # }
+    /// ```
+    fn simd_reduce_masked<A, F>(&mut self, mut start: A, mut func: F) -> A
+        where F : FnMut(A, Self::Vector, <Self::Vector as PackedEq>::Out) -> A, Self::Vector : PackedEq {
+        let valid = <Self::Vector as PackedEq>::Out::ones();
+
+        while let Some(v) = self.next() {
+            start = func(start, v, valid);
+        }
+        if let Some((v, n)) = self.end() {
+            start = func(start, v, <Self::Vector as PackedEq>::Out::partition_mask(n));
+        }
+        start
+    }
+
+    #[inline(always)]
+    /// Return an adaptor which keeps only the elements whose lanes are filled
+    /// with 1s in the mask returned by `func`. The surviving elements may be
+    /// retrieved in their original order with `scalar_collect` or
+    /// `scalar_fill`.
+    ///
+    /// Padding lanes are never kept, regardless of the mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let evens = (0u32..20).collect::<Vec<u32>>().simd_iter(u32s(0))
+    ///     .simd_filter(|v| (v & u32s(1)).eq_mask(u32s(0)))
+    ///     .scalar_collect();
+    /// assert_eq!(evens, vec![0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// # }
+    /// ```
+    fn simd_filter<F>(self, func: F) -> SIMDFilter<Self, F>
+        where F : FnMut(Self::Vector) -> <Self::Vector as PackedEq>::Out, Self::Vector : Compact {
+        SIMDFilter {
+            iter: self,
+            func: func,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator over the running totals of the elements of this
+    /// iterator, where the total at each element includes that element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let totals = [1u32, 2, 3, 4, 5].simd_iter(u32s(0)).simd_scan().scalar_collect();
+    /// assert_eq!(totals, vec![1, 3, 6, 10, 15]);
+    /// # }
+    /// ```
+    fn simd_scan(self) -> SIMDScan<Self> where Self::Vector : Scan {
+        SIMDScan {
+            iter: self,
+            carry: <Self::Vector as Packed>::default(),
+            exclusive: false,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator over the running totals of the elements of this
+    /// iterator, where the total at each element only includes the elements
+    /// before it. This turns a list of lengths into a list of offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let offsets = [1u32, 2, 3, 4, 5].simd_iter(u32s(0)).simd_scan_exclusive().scalar_collect();
+    /// assert_eq!(offsets, vec![0, 1, 3, 6, 10]);
+    /// # }
+    /// ```
+    fn simd_scan_exclusive(self) -> SIMDScan<Self> where Self::Vector : Scan {
+        SIMDScan {
+            iter: self,
+            carry: <Self::Vector as Packed>::default(),
+            exclusive: true,
+        }
+    }
+
+    #[inline(always)]
+    /// Return the index of the smallest element of this iterator, or `None`
+    /// if it is empty. If several elements are equally small, the index of
+    /// the first is returned. NaNs are never chosen unless every element is
+    /// NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!([4.0f32, 1.0, 3.0, 1.0, 2.0].simd_iter(f32s(0.0)).simd_argmin(), Some(1));
+    /// assert_eq!([0u8; 0].simd_iter(u8s(0)).simd_argmin(), None);
+    /// # }
+    /// ```
+    fn simd_argmin(&mut self) -> Option<usize>
+        where Self::Vector : ArgMinMax + PackedOrd, Self::Scalar : PartialOrd,
+              <Self::Vector as PackedEq>::Out : Select<Self::Vector> + Select<<Self::Vector as ArgMinMax>::Indices>,
+              <Self::Vector as ArgMinMax>::Indices : Add<Output = <Self::Vector as ArgMinMax>::Indices>,
+              <<Self::Vector as ArgMinMax>::Indices as Packed>::Scalar : From<u8> + Into<u64> {
+        simd_arg(self, beats_min, replaces_min)
+    }
+
+    #[inline(always)]
+    /// Return the index of the largest element of this iterator, or `None`
+    /// if it is empty. If several elements are equally large, the index of
+    /// the first is returned. NaNs are never chosen unless every element is
+    /// NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!([4i32, 9, 3, 9, 2].simd_iter(i32s(0)).simd_argmax(), Some(1));
+    /// # }
+    /// ```
+    fn simd_argmax(&mut self) -> Option<usize>
+        where Self::Vector : ArgMinMax + PackedOrd, Self::Scalar : PartialOrd,
+              <Self::Vector as PackedEq>::Out : Select<Self::Vector> + Select<<Self::Vector as ArgMinMax>::Indices>,
+              <Self::Vector as ArgMinMax>::Indices : Add<Output = <Self::Vector as ArgMinMax>::Indices>,
+              <<Self::Vector as ArgMinMax>::Indices as Packed>::Scalar : From<u8> + Into<u64> {
+        simd_arg(self, beats_max, replaces_max)
+    }
+
+    #[inline(always)]
+    /// Return whether `func` sets a lane of its mask for any element of this
+    /// iterator. Stops at the first vector with a set lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [1u8, 2, 3, 4, 5];
+    /// assert!(data.simd_iter(u8s(0)).simd_any(|v| v.eq_mask(u8s(3))));
+    /// // Padding is never tested
+    /// assert!(!data.simd_iter(u8s(0)).simd_any(|v| v.eq_mask(u8s(0))));
+    /// # }
+    /// ```
+    fn simd_any<F>(&mut self, func: F) -> bool
+        where F : FnMut(Self::Vector) -> <Self::Vector as PackedEq>::Out, Self::Vector : PackedEq {
+        self.simd_position(func).is_some()
+    }
+
+    #[inline(always)]
+    /// Return whether `func` sets the lanes of its mask for every element of
+    /// this iterator. Stops at the first vector with an unset lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [1u8, 2, 3, 4, 5];
+    /// assert!(data.simd_iter(u8s(0)).simd_all(|v| v.ne_mask(u8s(0))));
+    /// assert!(!data.simd_iter(u8s(0)).simd_all(|v| v.ne_mask(u8s(3))));
+    /// # }
+    /// ```
+    fn simd_all<F>(&mut self, mut func: F) -> bool
+        where F : FnMut(Self::Vector) -> <Self::Vector as PackedEq>::Out, Self::Vector : PackedEq {
+        while let Some(vec) = self.next() {
+            if lanes(&func(vec), 0, false) != 0 {
+                return false;
+            }
+        }
+        if let Some((vec, n)) = self.end() {
+            return lanes(&func(vec), n, false) == 0;
+        }
+        true
+    }
+
+    #[inline(always)]
+    /// Return the index of the first element of this iterator for which
+    /// `func` sets a lane of its mask, or `None` if there is no such element.
+    /// Stops at the first vector with a set lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [5u32, 3, 9, 3, 4];
+    /// assert_eq!(data.simd_iter(u32s(0)).simd_position(|v| v.eq_mask(u32s(3))), Some(1));
+    /// assert_eq!(data.simd_iter(u32s(0)).simd_position(|v| v.eq_mask(u32s(0))), None);
+    /// # }
+    /// ```
+    fn simd_position<F>(&mut self, mut func: F) -> Option<usize>
+        where F : FnMut(Self::Vector) -> <Self::Vector as PackedEq>::Out, Self::Vector : PackedEq {
+        let mut offset = 0;
+
+        while let Some(vec) = self.next() {
+            let bits = lanes(&func(vec), 0, true);
+            if bits != 0 {
+                return Some(offset + bits.trailing_zeros() as usize);
+            }
+            offset += Self::Vector::WIDTH;
+        }
+        if let Some((vec, n)) = self.end() {
+            let bits = lanes(&func(vec), n, true);
+            if bits != 0 {
+                return Some(offset + bits.trailing_zeros() as usize - n);
+            }
+        }
+        None
+    }
+
+    #[inline(always)]
+    /// Return the index of the last element of this iterator for which
+    /// `func` sets a lane of its mask, or `None` if there is no such element.
+    /// This must consume the entire iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [5u32, 3, 9, 3, 4];
+    /// assert_eq!(data.simd_iter(u32s(0)).simd_rposition(|v| v.eq_mask(u32s(3))), Some(3));
+    /// # }
+    /// ```
+    fn simd_rposition<F>(&mut self, mut func: F) -> Option<usize>
+        where F : FnMut(Self::Vector) -> <Self::Vector as PackedEq>::Out, Self::Vector : PackedEq {
+        let mut offset = 0;
+        let mut ret = None;
+
+        while let Some(vec) = self.next() {
+            let bits = lanes(&func(vec), 0, true);
+            if bits != 0 {
+                ret = Some(offset + 63 - bits.leading_zeros() as usize);
+            }
+            offset += Self::Vector::WIDTH;
+        }
+        if let Some((vec, n)) = self.end() {
+            let bits = lanes(&func(vec), n, true);
+            if bits != 0 {
+                ret = Some(offset + 63 - bits.leading_zeros() as usize - n);
+            }
+        }
+        ret
+    }
+
+    #[inline(always)]
+    /// Return the number of elements of this iterator for which `func` sets
+    /// a lane of its mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [5u32, 3, 9, 3, 4];
+    /// assert_eq!(data.simd_iter(u32s(3)).simd_count(|v| v.eq_mask(u32s(3))), 2);
+    /// # }
+    /// ```
+    fn simd_count<F>(&mut self, mut func: F) -> usize
+        where F : FnMut(Self::Vector) -> <Self::Vector as PackedEq>::Out, Self::Vector : PackedEq {
+        let mut count = 0;
+
+        while let Some(vec) = self.next() {
+            count += lanes(&func(vec), 0, true).count_ones() as usize;
+        }
+        if let Some((vec, n)) = self.end() {
+            count += lanes(&func(vec), n, true).count_ones() as usize;
+        }
+        count
+    }
+}
+
+/// Return a bitmask of the lanes of `mask` at or after `from` which are set
+/// (or unset, if `set` is false).
+#[inline(always)]
+fn lanes<M : PackedMask>(mask: &M, from: usize, set: bool) -> u64 {
+    let bits = if set { mask.bitmask() } else { !mask.bitmask() };
+    bits & lane_bits(M::WIDTH) & (!0 << from)
+}
+
+/// Keep whichever of `best` and the element `val` at `index` is better, as
+/// decided by `replaces`, or whichever has the lower index if neither is.
+#[inline(always)]
+fn keep_best<S, F>(best: &mut Option<(usize, S)>, index: usize, val: S, replaces: &F)
+    where S : Copy, F : Fn(S, S) -> bool {
+    match *best {
+        Some((i, b)) if !replaces(val, b) && (replaces(b, val) || i < index) => {},
+        _ => *best = Some((index, val)),
+    }
+}
+
+/// Reduce the best element of each lane of `vals`, which was found in the
+/// vector numbered by the same lane of `nums` counting from the element at
+/// `base`, into `best`.
+#[inline(always)]
+fn keep_best_lanes<V, F>(best: &mut Option<(usize, V::Scalar)>, vals: &V, nums: &V::Indices, base: usize, replaces: &F)
+    where V : ArgMinMax, <V::Indices as Packed>::Scalar : Into<u64>, F : Fn(V::Scalar, V::Scalar) -> bool {
+    for i in 0..V::WIDTH {
+        let index = base + nums.extract(i).into() as usize * V::WIDTH + i;
+        keep_best(best, index, vals.extract(i), replaces);
+    }
+}
+
+/// Find the index of the best element of `iter`. Each lane keeps the best
+/// element it has seen and the number of the vector it came from, which are
+/// blended in wherever `beats` is true, and the lanes are reduced with
+/// `replaces` once at the end. The vector numbers are as wide as the
+/// elements, so the lanes are also reduced whenever they would overflow.
+/// Indices are counted rather than taken from the iterator's position, which
+/// may be measured in another type.
+#[inline(always)]
+fn simd_arg<I, B, F>(iter: &mut I, beats: B, replaces: F) -> Option<usize>
+    where I : SIMDIterator, I::Vector : ArgMinMax + PackedOrd,
+          <I::Vector as PackedEq>::Out : Select<I::Vector> + Select<<I::Vector as ArgMinMax>::Indices>,
+          <I::Vector as ArgMinMax>::Indices : Add<Output = <I::Vector as ArgMinMax>::Indices>,
+          <<I::Vector as ArgMinMax>::Indices as Packed>::Scalar : From<u8> + Into<u64>,
+          B : Fn(&I::Vector, &I::Vector) -> <I::Vector as PackedEq>::Out,
+          F : Fn(I::Scalar, I::Scalar) -> bool {
+    let width = I::Vector::WIDTH;
+    let bits = 8 * size_of::<<<I::Vector as ArgMinMax>::Indices as Packed>::Scalar>();
+    let block = if bits < 8 * size_of::<usize>() { 1 << bits } else { usize::max_value() };
+    let zero = <I::Vector as ArgMinMax>::Indices::splat(0u8.into());
+    let one = <I::Vector as ArgMinMax>::Indices::splat(1u8.into());
+
+    let mut best = None;
+    let mut running = None;
+    let mut base = 0;
+    let mut count = 0;
+    let mut num = zero;
+
+    while let Some(vec) = iter.next() {
+        running = Some(match running {
+            Some((vals, nums)) => {
+                let mask = beats(&vec, &vals);
+                (mask.select(vec, vals), mask.select(num, nums))
+            },
+            None => (vec, num),
+        });
+
+        count += 1;
+        if count == block {
+            if let Some((vals, nums)) = running.take() {
+                keep_best_lanes(&mut best, &vals, &nums, base, &replaces);
+            }
+            base += block * width;
+            count = 0;
+            num = zero;
+        } else {
+            num = num + one;
+        }
+    }
+
+    if let Some((vals, nums)) = running {
+        keep_best_lanes(&mut best, &vals, &nums, base, &replaces);
+    }
+
+    // The padding is at the front of the last vector, so its lanes don't line
+    // up with those of the other vectors, and its elements are kept one by one.
+    if let Some((vec, n)) = iter.end() {
+        let offset = base + count * width;
+        for i in n..width {
+            keep_best(&mut best, offset + i - n, vec.extract(i), &replaces);
+        }
+    }
+    best.map(|(index, _)| index)
 }
 
 /// A trait defining a SIMD iterator over a mutable blob of primitive data
@@ -219,6 +654,32 @@ pub struct SIMDMap<I, F> where I : SIMDIterable {
     pub func: F,
 }
 
+/// A lazy mapping iterator which applies its function to a stream of vectors
+/// and masks of their populated lanes.
+#[derive(Debug)]
+pub struct SIMDMaskedMap<I, F> where I : SIMDIterable {
+    pub iter: I,
+    pub func: F,
+}
+
+/// A lazy filtering adaptor which keeps the elements selected by the masks its
+/// function returns. Since the number of survivors in each vector varies, this
+/// is not a `SIMDIterator`; it may only be drained into scalars.
+#[derive(Debug)]
+pub struct SIMDFilter<I, F> where I : SIMDIterable {
+    pub iter: I,
+    pub func: F,
+}
+
+/// A lazy iterator over the running totals of a stream of vectors.
+#[derive(Debug)]
+pub struct SIMDScan<I> where I : SIMDIterable {
+    pub iter: I,
+    /// The total of every element scanned so far, in every lane.
+    pub carry: I::Vector,
+    pub exclusive: bool,
+}
+
 /// An iterator which packs an iterator of scalars into an iterator of vectors.
 /// Cannot take advantage of vectorized loads, so it's very slow to gather data!
 #[derive(Clone)]
@@ -448,6 +909,93 @@ impl_iter!(Vec<S>, ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar
 impl_iter!(&'a [S], ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
 impl_iter!(&'a mut [S], ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
 
+/// A contiguous collection which is packed into vectors of type `V`, rather
+/// than the default vector type of its elements. See
+/// [`IntoSIMDRefIterator::simd_iter_as`] for more information.
+///
+/// [`IntoSIMDRefIterator::simd_iter_as`]: ../into_iters/trait.IntoSIMDRefIterator.html#method.simd_iter_as
+#[derive(Clone, Debug)]
+pub struct PackedAs<A, V> {
+    data: A,
+    vector: PhantomData<V>,
+}
+
+impl<A, V> PackedAs<A, V> {
+    /// Wrap `data`, such that it is loaded and stored as vectors of type `V`.
+    #[inline(always)]
+    pub fn new(data: A) -> Self {
+        PackedAs {
+            data: data,
+            vector: PhantomData,
+        }
+    }
+
+    /// Return the wrapped collection.
+    #[inline(always)]
+    pub fn into_inner(self) -> A {
+        self.data
+    }
+}
+
+impl<A, S, V> SIMDObject for PackedAs<A, V> where A : AsRef<[S]>, S : Packable, V : Packed<Scalar = S> {
+    type Vector = V;
+    type Scalar = S;
+}
+
+impl<A, S, V> SIMDSized for PackedAs<A, V> where A : AsRef<[S]>, S : Packable, V : Packed<Scalar = S> {
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.data.as_ref().len()
+    }
+}
+
+impl<A, S, V> SIMDArray for PackedAs<A, V> where A : AsRef<[S]>, S : Packable, V : Packed<Scalar = S> {
+    #[inline(always)]
+    fn load(&self, offset: usize) -> Self::Vector {
+        V::load(self.data.as_ref(), offset)
+    }
+
+    #[inline(always)]
+    unsafe fn load_unchecked(&self, offset: usize) -> Self::Vector {
+        debug_assert!(self.data.as_ref()[offset..].len() >= V::WIDTH);
+        V::load_unchecked(self.data.as_ref(), offset)
+    }
+
+    #[inline(always)]
+    fn load_scalar(&self, offset: usize) -> Self::Scalar {
+        self.data.as_ref()[offset]
+    }
+
+    #[inline(always)]
+    unsafe fn load_scalar_unchecked(&self, offset: usize) -> Self::Scalar {
+        debug_assert!(offset < self.data.as_ref().len());
+        *self.data.as_ref().get_unchecked(offset)
+    }
+}
+
+impl<A, S, V> SIMDArrayMut for PackedAs<A, V> where A : AsRef<[S]> + AsMut<[S]>, S : Packable, V : Packed<Scalar = S> {
+    #[inline(always)]
+    fn store(&mut self, value: Self::Vector, offset: usize) {
+        value.store(self.data.as_mut(), offset)
+    }
+
+    #[inline(always)]
+    unsafe fn store_unchecked(&mut self, value: Self::Vector, offset: usize) {
+        value.store_unchecked(self.data.as_mut(), offset)
+    }
+
+    #[inline(always)]
+    fn store_scalar(&mut self, value: Self::Scalar, offset: usize) {
+        self.data.as_mut()[offset] = value;
+    }
+
+    #[inline(always)]
+    unsafe fn store_scalar_unchecked(&mut self, value: Self::Scalar, offset: usize) {
+        debug_assert!(offset < self.data.as_mut().len());
+        *self.data.as_mut().get_unchecked_mut(offset) = value;
+    }
+}
+
 impl<A> SIMDObject for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
     type Vector = A::Vector;
     type Scalar = A::Scalar;
@@ -676,6 +1224,202 @@ impl<'a, A, B, I, F> SIMDIterator for SIMDMap<I, F>
     }
 }
 
+impl<A, B, I, F> Iterator for SIMDMaskedMap<I, F>
+    where I : SIMDIterable, I::Vector : PackedEq, F : FnMut(I::Vector, <I::Vector as PackedEq>::Out) -> A, A : Packed<Scalar = B>, B : Packable {
+    type Item = A;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let valid = <I::Vector as PackedEq>::Out::ones();
+        let func = &mut self.func;
+        self.iter.next().map(|v| func(v, valid))
+    }
+}
+
+impl<I, F> ExactSizeIterator for SIMDMaskedMap<I, F> where Self : Iterator, I : SIMDIterable {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<A, B, I, F> SIMDObject for SIMDMaskedMap<I, F>
+    where I : SIMDIterable, I::Vector : PackedEq, F : FnMut(I::Vector, <I::Vector as PackedEq>::Out) -> A, A : Packed<Scalar = B>, B : Packable {
+    type Vector = A;
+    type Scalar = B;
+
+    #[inline(always)]
+    fn width(&self) -> usize {
+        Self::Vector::WIDTH
+    }
+}
+
+impl<A, B, I, F> SIMDSized for SIMDMaskedMap<I, F>
+    where I : SIMDIterable, I::Vector : PackedEq, F : FnMut(I::Vector, <I::Vector as PackedEq>::Out) -> A, A : Packed<Scalar = B>, B : Packable {
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<A, B, I, F> SIMDIterable for SIMDMaskedMap<I, F>
+    where I : SIMDIterable, I::Vector : PackedEq, F : FnMut(I::Vector, <I::Vector as PackedEq>::Out) -> A, A : Packed<Scalar = B>, B : Packable {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn advance(&mut self, amount: usize) {
+        self.iter.advance(amount);
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        <Self::Vector as Packed>::default()
+    }
+}
+
+impl<A, B, I, F> SIMDIterator for SIMDMaskedMap<I, F>
+    where I : SIMDIterator, I::Vector : PackedEq, F : FnMut(I::Vector, <I::Vector as PackedEq>::Out) -> A, A : Packed<Scalar = B>, B : Packable {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        let (v, n) = self.iter.end()?;
+        let nr = n * I::Scalar::SIZE / Self::Scalar::SIZE;
+        Some(((self.func)(v, <I::Vector as PackedEq>::Out::partition_mask(n)), nr))
+    }
+}
+
+impl<I> SIMDScan<I> where I : SIMDIterable, I::Vector : Scan {
+    #[inline(always)]
+    fn step(&mut self, vec: I::Vector) -> I::Vector {
+        let last = I::Vector::WIDTH - 1;
+        if self.exclusive {
+            let ret = vec.scan_exclusive() + self.carry;
+            self.carry = I::Vector::splat((ret + vec).extract(last));
+            ret
+        } else {
+            let ret = vec.scan() + self.carry;
+            self.carry = I::Vector::splat(ret.extract(last));
+            ret
+        }
+    }
+}
+
+impl<I> Iterator for SIMDScan<I> where I : SIMDIterable, I::Vector : Scan {
+    type Item = I::Vector;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let vec = self.iter.next()?;
+        Some(self.step(vec))
+    }
+}
+
+impl<I> ExactSizeIterator for SIMDScan<I> where I : SIMDIterable, I::Vector : Scan {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> SIMDObject for SIMDScan<I> where I : SIMDIterable, I::Vector : Scan {
+    type Vector = I::Vector;
+    type Scalar = I::Scalar;
+}
+
+impl<I> SIMDSized for SIMDScan<I> where I : SIMDIterable, I::Vector : Scan {
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.iter.scalar_len()
+    }
+}
+
+impl<I> SIMDIterable for SIMDScan<I> where I : SIMDIterable, I::Vector : Scan {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn advance(&mut self, amount: usize) {
+        self.iter.advance(amount);
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        <Self::Vector as Packed>::default()
+    }
+}
+
+impl<I> SIMDIterator for SIMDScan<I> where I : SIMDIterator, I::Vector : Scan {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        let (vec, n) = self.iter.end()?;
+        // Zero the padding, which precedes the valid lanes
+        let vec = <I::Vector as Packed>::default().merge_partitioned(vec, n);
+        Some((self.step(vec), n))
+    }
+}
+
+impl<I, F> SIMDFilter<I, F>
+    where I : SIMDIterator, I::Vector : Compact, F : FnMut(I::Vector) -> <I::Vector as PackedEq>::Out {
+    /// Store the elements which pass the filter in-order in `fill`, and
+    /// return the number of elements written. The contents of `fill` beyond
+    /// that number are unspecified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fill` is too small to hold every surviving element.
+    #[inline(always)]
+    pub fn scalar_fill(&mut self, fill: &mut [I::Scalar]) -> usize {
+        let mut offset = 0;
+
+        while let Some(vec) = self.iter.next() {
+            let mask = (self.func)(vec);
+            offset = Self::store(vec.compact(mask), fill, offset);
+        }
+
+        if let Some((vec, n)) = self.iter.end() {
+            // Never keep the padding at the front of the last vector
+            let mask = <I::Vector as PackedEq>::Out::zeroes().merge_partitioned((self.func)(vec), n);
+            offset = Self::store(vec.compact(mask), fill, offset);
+        }
+
+        offset
+    }
+
+    /// Store the elements which pass the filter in-order in a Vec.
+    #[inline(always)]
+    #[cfg(feature = "std")]
+    pub fn scalar_collect(&mut self) -> Vec<I::Scalar> {
+        // Leave room for a whole vector past the last element, so that every
+        // store may be vectorized.
+        let len = (self.iter.len() + 1) * I::Vector::WIDTH;
+        let mut ret = Vec::with_capacity(len);
+
+        unsafe {
+            ret.set_len(len);
+            let count = self.scalar_fill(&mut ret);
+            ret.set_len(count);
+        }
+        ret
+    }
+
+    #[inline(always)]
+    fn store((vec, count): (I::Vector, usize), fill: &mut [I::Scalar], offset: usize) -> usize {
+        if offset + I::Vector::WIDTH <= fill.len() {
+            unsafe { vec.store_unchecked(fill, offset); }
+        } else {
+            // The buffer won't fit another vector; store elementwise
+            for i in 0..count {
+                fill[offset + i] = unsafe { vec.extract_unchecked(i) };
+            }
+        }
+        offset + count
+    }
+}
+
 /// A trait which can transform a stream of vectors into a contiguous
 /// collection of scalars.
 pub trait IntoScalar<T> : SIMDObject where T : Packable {
@@ -830,4 +1574,34 @@ mod tests {
                      3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
                      5, 0, 0, 0, 0, 0, 0, 0u8][..]);
     }
+
+    #[test]
+    fn masked_reduce_ignores_padding() {
+        for n in 1..(u32s::WIDTH * 3) {
+            let data: Vec<u32> = (1..(n as u32 + 1)).collect();
+            let sum = data.simd_iter(u32s(100))
+                .simd_reduce_masked(u32s(0), |acc, v, valid| acc + (v & valid.into_inner()))
+                .scalar_reduce(0, |acc, x| acc + x);
+            assert_eq!(sum, data.iter().sum::<u32>());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn masked_map_marks_padding() {
+        for n in 1..(u32s::WIDTH * 3) {
+            let data = vec![7u32; n];
+            let mapped = data.simd_iter(u32s(0))
+                .simd_map_masked(|v, valid| v & valid.into_inner())
+                .scalar_collect();
+            assert_eq!(mapped, data);
+
+            let padded = (u32s::WIDTH - n % u32s::WIDTH) % u32s::WIDTH;
+            let mut padding = vec![0u32; n + padded];
+            data.simd_iter(u32s(0))
+                .simd_map_masked(|_, valid| (!valid).into_inner())
+                .scalar_fill_all(&mut padding);
+            assert_eq!(padding.iter().filter(|&&x| x != 0).count(), padded);
+        }
+    }
 }
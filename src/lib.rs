@@ -195,6 +195,11 @@
 //! exactly what is happening under the hood. It's also important to remember
 //! that these problems will crop up even if you only support x86; the width
 //! difference between AVX and SSE is the primary source of these issues!
+//!
+//! The [`portability`] module can run a kernel at every vector width on a
+//! single machine, and report where the results differ.
+//!
+//! [`portability`]: portability/index.html
 
 #![cfg_attr(feature = "no-std", no_std)]
 #![cfg_attr(test, feature(test))]
@@ -206,9 +211,14 @@ pub use ::core as std;
 extern crate vektor;
 
 #[macro_use] pub(crate) mod debug;
+#[macro_use] pub mod widths;
+#[macro_use] pub mod dispatch;
 #[macro_use] pub mod zip;
 #[macro_use] pub mod vecs;
 pub mod vec_patterns;
+pub mod mask;
+pub mod math;
+pub mod half;
 pub mod iters;
 pub mod into_iters;
 #[macro_use] pub mod intrin;
@@ -216,5 +226,7 @@ pub mod into_iters;
 pub mod prelude;
 pub mod stride_zip;
 pub mod stride;
+#[cfg(feature = "std")]
+pub mod portability;
 
 pub use crate::prelude::*;
@@ -0,0 +1,388 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Vectors of booleans, as returned by comparisons.
+
+#![allow(non_camel_case_types)]
+
+use crate::std::fmt::Debug;
+use crate::std::ops::{BitAnd, BitOr, BitXor, Not};
+use crate::arch::current::vecs::*;
+use crate::intrin::bitmask::{Bitmask, lane_bits};
+use crate::intrin::merge::Merge;
+use crate::intrin::transmute::Transmute;
+use crate::vecs::{Packed, Pattern};
+
+/// A vector of booleans, each of which occupies a lane the size of an element
+/// of `V`. True lanes are filled with 1s, and false lanes with zeroes.
+///
+/// Masks are usually built by comparisons or from bitmasks, which keep every
+/// lane well-formed. Code which predates masks may convert an unsigned vector
+/// with `from_vec` or `From`, and back with `into_inner` or the `be_*`
+/// methods.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mask<V>(V);
+
+/// The operations shared by every mask, regardless of the width of its lanes.
+pub trait PackedMask : Bitmask + Merge + Copy + Debug
+    + BitAnd<Self, Output = Self>
+    + BitOr<Self, Output = Self>
+    + BitXor<Self, Output = Self>
+    + Not<Output = Self> {
+    /// The number of lanes in this mask.
+    const WIDTH: usize;
+
+    /// Return a mask with every lane true.
+    fn ones() -> Self;
+
+    /// Return a mask with every lane false.
+    fn zeroes() -> Self;
+
+    /// Return a mask whose lanes before `off` are false, and whose remaining
+    /// lanes are true.
+    fn partition_mask(off: usize) -> Self;
+}
+
+/// A mask for vectors of 8-bit elements.
+pub type m8s = Mask<u8s>;
+/// A mask for vectors of 16-bit elements.
+pub type m16s = Mask<u16s>;
+/// A mask for vectors of 32-bit elements.
+pub type m32s = Mask<u32s>;
+/// A mask for vectors of 64-bit elements.
+pub type m64s = Mask<u64s>;
+
+pub type m8x16 = Mask<u8x16>;
+pub type m16x8 = Mask<u16x8>;
+pub type m32x4 = Mask<u32x4>;
+pub type m64x2 = Mask<u64x2>;
+pub type m8x32 = Mask<u8x32>;
+pub type m16x16 = Mask<u16x16>;
+pub type m32x8 = Mask<u32x8>;
+pub type m64x4 = Mask<u64x4>;
+pub type m8x64 = Mask<u8x64>;
+pub type m16x32 = Mask<u16x32>;
+pub type m32x16 = Mask<u32x16>;
+pub type m64x8 = Mask<u64x8>;
+
+impl<V> Mask<V> {
+    /// Reinterpret an unsigned vector as a mask. Every element of `vec`
+    /// should be either filled with 1s or filled with zeroes; the results of
+    /// selecting or testing with other lanes are unspecified.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mask = Mask::from_vec(u32s::halfs(0xFFFFFFFF, 0));
+    /// assert_eq!(mask, u32s::halfs(1, 0).eq_mask(u32s(1)));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn from_vec(vec: V) -> Self {
+        Mask(vec)
+    }
+
+    /// Return the unsigned vector underlying this mask.
+    #[inline(always)]
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<V> From<V> for Mask<V> {
+    /// Reinterpret an unsigned vector as a mask, like `Mask::from_vec`.
+    #[inline(always)]
+    fn from(vec: V) -> Self {
+        Mask::from_vec(vec)
+    }
+}
+
+impl<V> Mask<V> where V : Packed + Bitmask {
+    /// Return true if any lane of this mask is true.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert!(u32s::halfs(1, 2).eq_mask(u32s(2)).any());
+    /// assert!(!u32s(1).eq_mask(u32s(2)).any());
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn any(&self) -> bool {
+        self.bitmask() != 0
+    }
+
+    /// Return true if every lane of this mask is true.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert!(u32s(2).eq_mask(u32s(2)).all());
+    /// assert!(!u32s::halfs(1, 2).eq_mask(u32s(2)).all());
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn all(&self) -> bool {
+        self.bitmask() == lane_bits(V::WIDTH)
+    }
+
+    /// Return true if no lane of this mask is true.
+    #[inline(always)]
+    pub fn none(&self) -> bool {
+        !self.any()
+    }
+}
+
+impl<V> PackedMask for Mask<V>
+    where V : Packed + Pattern + Bitmask + BitAnd<Output = V> + BitOr<Output = V> + BitXor<Output = V> {
+    const WIDTH: usize = V::WIDTH;
+
+    #[inline(always)]
+    fn ones() -> Self {
+        Mask(V::ones())
+    }
+
+    #[inline(always)]
+    fn zeroes() -> Self {
+        Mask(V::zeroes())
+    }
+
+    #[inline(always)]
+    fn partition_mask(off: usize) -> Self {
+        Mask(V::partition_mask(off))
+    }
+}
+
+impl<V> PartialEq<V> for Mask<V> where V : PartialEq {
+    #[inline(always)]
+    fn eq(&self, other: &V) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<V> Merge for Mask<V> where V : Merge {
+    #[inline(always)]
+    fn merge_halves(&self, other: Self) -> Self {
+        Mask(self.0.merge_halves(other.0))
+    }
+
+    #[inline(always)]
+    fn merge_interleaved(&self, other: Self) -> Self {
+        Mask(self.0.merge_interleaved(other.0))
+    }
+
+    #[inline(always)]
+    fn merge_partitioned(&self, other: Self, offset: usize) -> Self {
+        Mask(self.0.merge_partitioned(other.0, offset))
+    }
+}
+
+impl<V> Bitmask for Mask<V> where V : Bitmask {
+    #[inline(always)]
+    fn bitmask(&self) -> u64 {
+        self.0.bitmask()
+    }
+
+    #[inline(always)]
+    fn from_bitmask(bits: u64) -> Self {
+        Mask(V::from_bitmask(bits))
+    }
+}
+
+impl<V> Transmute for Mask<V> where V : Transmute {
+    type i8s = V::i8s;
+    type u8s = V::u8s;
+    type i16s = V::i16s;
+    type u16s = V::u16s;
+    type i32s = V::i32s;
+    type u32s = V::u32s;
+    type f32s = V::f32s;
+    type i64s = V::i64s;
+    type u64s = V::u64s;
+    type f64s = V::f64s;
+
+    #[inline(always)]
+    fn be_i8s(&self) -> Self::i8s {
+        self.0.be_i8s()
+    }
+
+    #[inline(always)]
+    fn be_u8s(&self) -> Self::u8s {
+        self.0.be_u8s()
+    }
+
+    #[inline(always)]
+    fn be_i16s(&self) -> Self::i16s {
+        self.0.be_i16s()
+    }
+
+    #[inline(always)]
+    fn be_u16s(&self) -> Self::u16s {
+        self.0.be_u16s()
+    }
+
+    #[inline(always)]
+    fn be_i32s(&self) -> Self::i32s {
+        self.0.be_i32s()
+    }
+
+    #[inline(always)]
+    fn be_u32s(&self) -> Self::u32s {
+        self.0.be_u32s()
+    }
+
+    #[inline(always)]
+    unsafe fn be_f32s_unchecked(&self) -> Self::f32s {
+        self.0.be_f32s_unchecked()
+    }
+
+    #[inline(always)]
+    fn be_i64s(&self) -> Self::i64s {
+        self.0.be_i64s()
+    }
+
+    #[inline(always)]
+    fn be_u64s(&self) -> Self::u64s {
+        self.0.be_u64s()
+    }
+
+    #[inline(always)]
+    unsafe fn be_f64s_unchecked(&self) -> Self::f64s {
+        self.0.be_f64s_unchecked()
+    }
+}
+
+impl<V> BitAnd for Mask<V> where V : BitAnd<Output = V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self {
+        Mask(self.0 & rhs.0)
+    }
+}
+
+impl<V> BitOr for Mask<V> where V : BitOr<Output = V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self {
+        Mask(self.0 | rhs.0)
+    }
+}
+
+impl<V> BitXor for Mask<V> where V : BitXor<Output = V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self {
+        Mask(self.0 ^ rhs.0)
+    }
+}
+
+impl<V> Not for Mask<V> where V : Pattern + BitXor<Output = V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        Mask(self.0 ^ V::ones())
+    }
+}
+
+// Masks keep their lane order when resized, so the bitmask of the narrow mask
+// is the concatenation of those of the wide masks.
+macro_rules! impl_mask_resize {
+    ($($narrow:ident, $wide:ident);*) => {
+        $(
+            impl $narrow {
+                /// Return two masks with lanes twice as wide, which hold the
+                /// first and second halves of this mask respectively.
+                #[inline(always)]
+                pub fn widen(self) -> ($wide, $wide) {
+                    let bits = self.bitmask();
+                    ($wide::from_bitmask(bits), $wide::from_bitmask(bits >> $wide::WIDTH))
+                }
+            }
+
+            impl $wide {
+                /// Return a mask with lanes half as wide, whose first half
+                /// holds the lanes of this mask and whose second half holds
+                /// the lanes of `other`.
+                #[inline(always)]
+                pub fn narrow(self, other: Self) -> $narrow {
+                    $narrow::from_bitmask(self.bitmask() | (other.bitmask() << Self::WIDTH))
+                }
+            }
+        )*
+    }
+}
+
+impl_mask_resize!(m8x16, m16x8; m16x8, m32x4; m32x4, m64x2;
+                  m8x32, m16x16; m16x16, m32x8; m32x8, m64x4;
+                  m8x64, m16x32; m16x32, m32x16; m32x16, m64x8);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::*;
+
+    #[test]
+    fn logical_ops() {
+        let a = u32s::halfs(0, 1).eq_mask(u32s(1));
+        let b = u32s::interleave(0, 1).eq_mask(u32s(1));
+        assert_eq!(a & b, u32s::halfs(0, 0).merge_partitioned(u32s::interleave(0, !0), u32s::WIDTH / 2));
+        assert_eq!((a | b).bitmask(), a.bitmask() | b.bitmask());
+        assert_eq!((a ^ b).bitmask(), a.bitmask() ^ b.bitmask());
+        assert_eq!((!a).bitmask(), !a.bitmask() & lane_bits(u32s::WIDTH));
+        assert_eq!(!!a, a);
+    }
+
+    #[test]
+    fn from_vec() {
+        let vec = u16s::interleave(!0, 0);
+        let mask: m16s = vec.into();
+        assert_eq!(mask, Mask::from_vec(vec));
+        assert_eq!(mask, u16s::interleave(1, 0).eq_mask(u16s(1)));
+        assert_eq!(mask.into_inner(), vec);
+        assert_eq!(mask.select(u16s(3), u16s(4)), u16s::interleave(3, 4));
+    }
+
+    #[test]
+    fn any_all_none() {
+        assert!(m16s::ones().all() && m16s::ones().any() && !m16s::ones().none());
+        assert!(!m16s::zeroes().all() && !m16s::zeroes().any() && m16s::zeroes().none());
+        let half = u16s::halfs(1, 0).eq_mask(u16s(1));
+        assert!(!half.all() && half.any() && !half.none());
+    }
+
+    #[test]
+    fn resize() {
+        for &bits in [0u64, 1, 0x8000, 0x1234, 0xFFFF].iter() {
+            let mask = m8x16::from_bitmask(bits);
+            let (lo, hi) = mask.widen();
+            assert_eq!(lo.bitmask(), bits & 0xFF);
+            assert_eq!(hi.bitmask(), bits >> 8);
+            assert_eq!(lo.narrow(hi), mask);
+        }
+        assert_eq!(m32x4::from_bitmask(0b1001).widen(), (m64x2::from_bitmask(0b01), m64x2::from_bitmask(0b10)));
+    }
+
+    #[test]
+    fn comparisons_return_masks() {
+        let mask: m32s = u32s::halfs(1, 2).eq_mask(u32s(2));
+        assert_eq!(mask, u32s::halfs(0, !0));
+        assert_eq!(mask.into_inner(), u32s::halfs(0, !0));
+        assert_eq!(mask.be_u8s(), u32s::halfs(0, !0).be_u8s());
+        assert_eq!(!mask, u32s::halfs(1, 2).ne_mask(u32s(2)));
+    }
+}
@@ -0,0 +1,1157 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Vectorized transcendental functions.
+//!
+//! Each function reduces its argument to a small interval and evaluates a
+//! polynomial there, using only vector arithmetic, comparisons and bit
+//! manipulation, so the same code runs on every architecture. Every
+//! function has an accurate variant and a `_fast` variant. The accurate
+//! variants handle infinities, NaNs, zeroes and subnormals the same way as
+//! the functions in `std`. The fast variants use shorter polynomials and
+//! skip the special cases: their results are unspecified for NaN, for
+//! infinite or subnormal inputs, and for results which would overflow or
+//! underflow.
+//!
+//! The error bounds in each function's documentation are the largest errors,
+//! in units in the last place, seen against correctly rounded results.
+//!
+//! ```
+//! extern crate faster;
+//! use faster::*;
+//!
+//! # fn main() {
+//! let x = f32s(0.5);
+//! let y = (x.sin() * x.sin() + x.cos() * x.cos()).extract(0);
+//! assert!(y > 0.999999 && y < 1.000001);
+//! # }
+//! ```
+
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::intrin::eq::Eq as PackedEq;
+use crate::intrin::mul_add::MulAdd;
+use crate::intrin::ord::PackedOrd;
+use crate::intrin::round::Round;
+use crate::intrin::select::Select;
+use crate::intrin::shift::Shift;
+use crate::intrin::transmute::Transmute;
+
+/// Transcendental functions on float vectors.
+pub trait Math : Packed {
+    /// Return a vector containing e raised to the power of each element.
+    ///
+    /// Accurate to within 1 ULP for f32s and f64s.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(0.0).exp(), f32s(1.0));
+    /// assert_eq!(f64s(-1000.0).exp(), f64s(0.0));
+    /// # }
+    /// ```
+    fn exp(&self) -> Self;
+
+    /// Return a vector containing e raised to the power of each element,
+    /// within 5 ULP for f32s and 2 ULP for f64s. Inputs below `-87` or above
+    /// `88` (`-708` and `709` for f64s) are clamped to those bounds.
+    fn exp_fast(&self) -> Self;
+
+    /// Return a vector containing the natural logarithm of each element.
+    ///
+    /// Accurate to within 1 ULP for f32s and f64s.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(1.0).ln(), f32s(0.0));
+    /// assert_eq!(f64s(0.0).ln(), f64s(::std::f64::NEG_INFINITY));
+    /// # }
+    /// ```
+    fn ln(&self) -> Self;
+
+    /// Return a vector containing the natural logarithm of each positive,
+    /// normal element, within 1 ULP for f32s and 5 ULP for f64s.
+    fn ln_fast(&self) -> Self;
+
+    /// Return a vector containing the base 2 logarithm of each element.
+    /// Powers of two give exact results.
+    ///
+    /// Accurate to within 2 ULP for f32s and f64s.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(1024.0).log2(), f32s(10.0));
+    /// # }
+    /// ```
+    fn log2(&self) -> Self;
+
+    /// Return a vector containing the base 2 logarithm of each positive,
+    /// normal element, within 2 ULP for f32s and 7 ULP for f64s.
+    fn log2_fast(&self) -> Self;
+
+    /// Return a vector containing the sine of each element.
+    ///
+    /// Accurate to within 2 ULP for f32s and 3 ULP for f64s when the elements
+    /// are smaller than 2<sup>12</sup> for f32s or 2<sup>26</sup> for f64s in
+    /// magnitude. The argument reduction loses precision outside that range.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(0.0).sin(), f32s(0.0));
+    /// assert_eq!(f64s(::std::f64::consts::FRAC_PI_2).sin(), f64s(1.0));
+    /// # }
+    /// ```
+    fn sin(&self) -> Self;
+
+    /// Return a vector containing the sine of each element, within 2 ULP
+    /// for f32s and 80 ULP for f64s over the same range as [`sin`].
+    ///
+    /// [`sin`]: #tymethod.sin
+    fn sin_fast(&self) -> Self;
+
+    /// Return a vector containing the cosine of each element.
+    ///
+    /// Accurate to within 2 ULP for f32s and 3 ULP for f64s over the same
+    /// range as [`sin`].
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(0.0).cos(), f32s(1.0));
+    /// assert_eq!(f64s(::std::f64::consts::PI).cos(), f64s(-1.0));
+    /// # }
+    /// ```
+    ///
+    /// [`sin`]: #tymethod.sin
+    fn cos(&self) -> Self;
+
+    /// Return a vector containing the cosine of each element, within 2 ULP
+    /// for f32s and 80 ULP for f64s over the same range as [`sin`].
+    ///
+    /// [`sin`]: #tymethod.sin
+    fn cos_fast(&self) -> Self;
+
+    /// Return a vector containing the tangent of each element.
+    ///
+    /// Accurate to within 4 ULP for f32s and f64s over the same range as
+    /// [`sin`].
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(0.0).tan(), f32s(0.0));
+    /// # }
+    /// ```
+    ///
+    /// [`sin`]: #tymethod.sin
+    fn tan(&self) -> Self;
+
+    /// Return a vector containing the tangent of each element, within 4
+    /// ULP for f32s and 80 ULP for f64s over the same range as [`sin`].
+    ///
+    /// [`sin`]: #tymethod.sin
+    fn tan_fast(&self) -> Self;
+
+    /// Return a vector containing the four quadrant arctangent of each
+    /// element of `self` and `x`, treating `self` as the y coordinate.
+    ///
+    /// Accurate to within 3 ULP for f32s and f64s.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(1.0).atan2(f32s(0.0)), f32s(::std::f32::consts::FRAC_PI_2));
+    /// assert_eq!(f64s(0.0).atan2(f64s(-1.0)), f64s(::std::f64::consts::PI));
+    /// # }
+    /// ```
+    fn atan2(&self, x: Self) -> Self;
+
+    /// Return a vector containing the four quadrant arctangent of each
+    /// element of `self` and `x`, within 3 ULP for f32s and 4 ULP for f64s.
+    /// At least one of the elements in each lane must be finite and nonzero.
+    fn atan2_fast(&self, x: Self) -> Self;
+
+    /// Return a vector containing each element of `self` raised to the
+    /// power of the corresponding element of `y`. Negative bases are
+    /// allowed when the exponent is an integer.
+    ///
+    /// Accurate to within 2 ULP for f32s and f64s. This uses fused
+    /// multiply-adds to carry extra precision, so it is much faster on
+    /// targets which support FMA.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(2.0).pow(f32s(10.0)), f32s(1024.0));
+    /// assert_eq!(f64s(-2.0).pow(f64s(3.0)), f64s(-8.0));
+    /// # }
+    /// ```
+    fn pow(&self, y: Self) -> Self;
+
+    /// Return a vector containing each positive element of `self` raised
+    /// to the power of the corresponding element of `y`. The error grows
+    /// with the magnitude of the result's exponent, up to 256 ULP for f32s
+    /// and 8192 ULP for f64s near the ends of the exponent range.
+    fn pow_fast(&self, y: Self) -> Self;
+
+    /// Return a vector containing the hyperbolic tangent of each element.
+    ///
+    /// Accurate to within 2 ULP for f32s and f64s.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(0.0).tanh(), f32s(0.0));
+    /// assert_eq!(f64s(-100.0).tanh(), f64s(-1.0));
+    /// # }
+    /// ```
+    fn tanh(&self) -> Self;
+
+    /// Return a vector containing the hyperbolic tangent of each element,
+    /// within 2 ULP for f32s and f64s.
+    fn tanh_fast(&self) -> Self;
+
+    /// Return a vector containing the error function of each element.
+    ///
+    /// Accurate to within 3 ULP for f32s and 2 ULP for f64s.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(0.0).erf(), f32s(0.0));
+    /// assert_eq!(f64s(10.0).erf(), f64s(1.0));
+    /// # }
+    /// ```
+    fn erf(&self) -> Self;
+
+    /// Return a vector containing the error function of each element,
+    /// within 3 ULP for f32s and 2 ULP for f64s.
+    fn erf_fast(&self) -> Self;
+}
+
+// The polynomials are minimax fits, stored from the highest degree down,
+// for these functions of the reduced argument:
+//
+// - EXP: (e^r - 1 - r) / r^2 on [-ln(2)/2, ln(2)/2]
+// - LN: (2 atanh(s) / s - 2) / s^2 in s^2, for s = (m - 1) / (m + 1) and m in
+//   [sqrt(1/2), sqrt(2))
+// - LN_EXTRA: (2 atanh(s) - 2s - 2s^3 / 3) / s^5 in s^2, over the same range
+// - SIN: (sin(r) - r) / r^3 and COS: (cos(r) - 1 + r^2 / 2) / r^4 in r^2, on
+//   [-pi/4, pi/4]
+// - ATAN: (atan(t) - t) / t^3 in t^2, on [-tan(pi/8), tan(pi/8)]
+// - TANH: (tanh(x) - x) / x^3 in x^2, on [-0.625, 0.625]
+// - ERF: erf(x) / x in x^2, on [-0.75, 0.75]
+// - ERFC: ln(erfc(x) / t) + x^2 in t - 0.5, for t = 2 / (2 + x) and x in
+//   [0.75, ERF_MAX]
+mod f32_consts {
+    pub const EXP_MIN: f32 = -104.0;
+    pub const EXP_MAX: f32 = 89.0;
+    pub const EXP_MIN_FAST: f32 = -87.0;
+    pub const EXP_MAX_FAST: f32 = 88.0;
+    pub const ERF_MAX: f32 = 4.0;
+
+    pub const MIN_POSITIVE: f32 = crate::std::f32::MIN_POSITIVE;
+    pub const INFINITY: f32 = crate::std::f32::INFINITY;
+    pub const NEG_INFINITY: f32 = crate::std::f32::NEG_INFINITY;
+    pub const NAN: f32 = crate::std::f32::NAN;
+
+    // Adding MAGIC to a small non-negative integer leaves the integer in the
+    // low bits of the sum, and ROUND_MAGIC does the same for negative ones.
+    pub const MAGIC: f32 = 8388608.0;
+    pub const MAGIC_BITS: u32 = 0x4B00_0000;
+    pub const ROUND_MAGIC: f32 = 12582912.0;
+    pub const BIAS: f32 = 127.0;
+    pub const SUBNORMAL_SCALE: f32 = 33554432.0;
+    pub const SUBNORMAL_EXP: f32 = 25.0;
+
+    pub const SIGN_MASK: u32 = 0x8000_0000;
+    pub const ABS_MASK: u32 = 0x7FFF_FFFF;
+    pub const EXP_MASK: u32 = 0x7F80_0000;
+    pub const MANT_MASK: u32 = 0x007F_FFFF;
+    pub const MANT_BITS: u32 = 23;
+    pub const ONE_BITS: u32 = 0x3F80_0000;
+    pub const QUADRANT_TO_SIGN_SHIFT: u32 = 30;
+
+    // LN2_HI and all but the last part of pi/2 end in enough zero bits that
+    // their products with the integers from range reduction are exact. With
+    // 12 bits per part of pi/2, that holds for quadrants up to 2^12.
+    pub const LN2_HI: f32 = 0.69314575;
+    pub const LN2_LO: f32 = 1.4286068e-06;
+    pub const LOG2E: f32 = crate::std::f32::consts::LOG2_E;
+    pub const FRAC_2_PI: f32 = crate::std::f32::consts::FRAC_2_PI;
+    pub const FRAC_PI_2_PARTS: [f32; 4] = [1.5703125, 0.0004837513, 7.5495336e-08, 2.563344e-12];
+    pub const FRAC_PI_2: f32 = crate::std::f32::consts::FRAC_PI_2;
+    pub const FRAC_PI_4: f32 = crate::std::f32::consts::FRAC_PI_4;
+    pub const PI: f32 = crate::std::f32::consts::PI;
+    pub const TAN_FRAC_PI_8: f32 = 0.41421357;
+    pub const SQRT_2: f32 = crate::std::f32::consts::SQRT_2;
+    pub const TWO_THIRDS_HI: f32 = 0.6666667;
+    pub const TWO_THIRDS_LO: f32 = -1.9868216e-08;
+
+    pub const EXP: [f32; 5] = [0.0013928688, 0.008363806, 0.041666534, 0.16666573, 0.5];
+    pub const EXP_FAST: [f32; 4] = [0.00835867, 0.041837346, 0.16666624, 0.49999738];
+    pub const LN: [f32; 4] = [0.23341943, 0.28550398, 0.40000126, 0.6666667];
+    pub const LN_FAST: [f32; 3] = [0.29590318, 0.3998855, 0.66666687];
+    pub const LN_EXTRA: [f32; 4] = [0.19130084, 0.22204407, 0.28571534, 0.4];
+    pub const SIN: [f32; 4] = [2.7234644e-06, -0.00019839966, 0.008333332, -0.16666667];
+    pub const SIN_FAST: [f32; 3] = [-0.00019575306, 0.008332688, -0.16666664];
+    pub const COS: [f32; 4] = [-2.7288203e-07, 2.48005e-05, -0.0013888888, 0.041666668];
+    pub const COS_FAST: [f32; 3] = [2.453533e-05, -0.0013888243, 0.041666664];
+    pub const ATAN: [f32; 6] = [
+        0.050280806, -0.08616864, 0.11070312, -0.14284094, 0.19999976, -0.33333334,
+    ];
+    pub const ATAN_FAST: [f32; 4] = [0.08499109, -0.14019503, 0.19990996, -0.33333284];
+    pub const TANH: [f32; 6] = [
+        0.0022927448, -0.008343945, 0.021768918, -0.053959258, 0.13333304, -0.33333334,
+    ];
+    pub const TANH_FAST: [f32; 5] = [
+        -0.006096714, 0.02099718, -0.053850908, 0.1333277, -0.33333328,
+    ];
+    pub const ERF: [f32; 6] = [
+        -0.000675648, 0.005115332, -0.026835114, 0.112833865, -0.3761262, 1.1283791,
+    ];
+    pub const ERF_FAST: [f32; 5] = [0.0041633295, -0.02636536, 0.112739176, -0.3761195, 1.1283791];
+    pub const ERFC: [f32; 8] = [
+        -0.27606207, 0.11048416, 0.28175518, -0.15793435, -0.37515792, 0.18937318, 1.3452864,
+        -0.67179406,
+    ];
+    pub const ERFC_FAST: [f32; 6] = [
+        0.28042805, -0.1534617, -0.3753998, 0.1893283, 1.3452889, -0.671794,
+    ];
+}
+
+mod f64_consts {
+    pub const EXP_MIN: f64 = -746.0;
+    pub const EXP_MAX: f64 = 710.0;
+    pub const EXP_MIN_FAST: f64 = -708.0;
+    pub const EXP_MAX_FAST: f64 = 709.0;
+    pub const ERF_MAX: f64 = 6.0;
+
+    pub const MIN_POSITIVE: f64 = crate::std::f64::MIN_POSITIVE;
+    pub const INFINITY: f64 = crate::std::f64::INFINITY;
+    pub const NEG_INFINITY: f64 = crate::std::f64::NEG_INFINITY;
+    pub const NAN: f64 = crate::std::f64::NAN;
+
+    pub const MAGIC: f64 = 4503599627370496.0;
+    pub const MAGIC_BITS: u64 = 0x4330_0000_0000_0000;
+    pub const ROUND_MAGIC: f64 = 6755399441055744.0;
+    pub const BIAS: f64 = 1023.0;
+    pub const SUBNORMAL_SCALE: f64 = 18014398509481984.0;
+    pub const SUBNORMAL_EXP: f64 = 54.0;
+
+    pub const SIGN_MASK: u64 = 0x8000_0000_0000_0000;
+    pub const ABS_MASK: u64 = 0x7FFF_FFFF_FFFF_FFFF;
+    pub const EXP_MASK: u64 = 0x7FF0_0000_0000_0000;
+    pub const MANT_MASK: u64 = 0x000F_FFFF_FFFF_FFFF;
+    pub const MANT_BITS: u32 = 52;
+    pub const ONE_BITS: u64 = 0x3FF0_0000_0000_0000;
+    pub const QUADRANT_TO_SIGN_SHIFT: u32 = 62;
+
+    // 27 bits per part of pi/2 leaves room for quadrants up to 2^26
+    pub const LN2_HI: f64 = 0.6931471805598903;
+    pub const LN2_LO: f64 = 5.497923018708371e-14;
+    pub const LOG2E: f64 = crate::std::f64::consts::LOG2_E;
+    pub const FRAC_2_PI: f64 = crate::std::f64::consts::FRAC_2_PI;
+    pub const FRAC_PI_2_PARTS: [f64; 4] = [
+        1.570796325802803, 9.920935739593517e-10, 5.721188709663575e-18, 1.6446256936324258e-26,
+    ];
+    pub const FRAC_PI_2: f64 = crate::std::f64::consts::FRAC_PI_2;
+    pub const FRAC_PI_4: f64 = crate::std::f64::consts::FRAC_PI_4;
+    pub const PI: f64 = crate::std::f64::consts::PI;
+    pub const TAN_FRAC_PI_8: f64 = 0.41421356237309503;
+    pub const SQRT_2: f64 = crate::std::f64::consts::SQRT_2;
+    pub const TWO_THIRDS_HI: f64 = 0.6666666666666666;
+    pub const TWO_THIRDS_LO: f64 = 3.700743415417188e-17;
+
+    pub const EXP: [f64; 11] = [
+        2.091854778955613e-09, 2.5106329509344313e-08, 2.7557263252295125e-07,
+        2.7557252699155586e-06, 2.4801587335156606e-05, 0.00019841269876972497,
+        0.001388888888888021, 0.008333333333325513, 0.04166666666666667, 0.1666666666666667, 0.5,
+    ];
+    pub const EXP_FAST: [f64; 10] = [
+        2.5105020990711403e-08, 2.7621403567087967e-07, 2.7557257421509096e-06,
+        2.480151850210525e-05, 0.00019841269871606314, 0.0013888888919030686, 0.00833333333332769,
+        0.04166666666662045, 0.16666666666666669, 0.5000000000000001,
+    ];
+    pub const LN: [f64; 7] = [
+        0.1463003136412286, 0.15330617306743305, 0.18182922513515942, 0.22222210674238516,
+        0.28571428628810996, 0.3999999999989322, 0.666666666666667,
+    ];
+    pub const LN_FAST: [f64; 6] = [
+        0.16634817293436976, 0.18139329084810124, 0.22222882159268573, 0.28571423954752284,
+        0.40000000011787484, 0.666666666666618,
+    ];
+    pub const LN_EXTRA: [f64; 8] = [
+        0.11735818855512968, 0.11705021567538627, 0.13334850282547114, 0.1538459412012637,
+        0.18181818343316125, 0.2222222222162076, 0.28571428571429425, 0.4,
+    ];
+    pub const SIN: [f64; 6] = [
+        1.5911114983475975e-10, -2.5051032052364444e-08, 2.7557315611669283e-06,
+        -0.0001984126983578818, 0.00833333333333029, -0.16666666666666663,
+    ];
+    pub const SIN_FAST: [f64; 5] = [
+        -2.4756576793832524e-08, 2.7555309971434952e-06, -0.00019841263766273777,
+        0.00833333332567974, -0.166666666666389,
+    ];
+    pub const COS: [f64; 6] = [
+        -1.137824422983457e-11, 2.087608383796075e-09, -2.755731696463517e-07,
+        2.480158729815862e-05, -0.0013888888888886986, 0.041666666666666664,
+    ];
+    pub const COS_FAST: [f64; 5] = [
+        2.066551614514814e-09, -2.755588272064671e-07, 2.4801582957846243e-05,
+        -0.0013888888883416125, 0.04166666666664681,
+    ];
+    pub const ATAN: [f64; 11] = [
+        -0.019029290809743134, 0.03911053674347613, -0.05081236164642543, 0.058573396761385246,
+        -0.06664417824186955, 0.07692176510795928, -0.09090904289128517, 0.11111111008107874,
+        -0.14285714284577217, 0.1999999999999509, -0.3333333333333333,
+    ];
+    pub const ATAN_FAST: [f64; 10] = [
+        0.022592433408305423, -0.04471724183392113, 0.05732790650017491, -0.0664903323195107,
+        0.07690999933891143, -0.09090849485105108, 0.11111109541044667, -0.14285714264618923,
+        0.19999999999889667, -0.33333333333333237,
+    ];
+    pub const TANH: [f64; 12] = [
+        6.485163482793113e-06, -3.12011014257974e-05, 9.257116129556769e-05, -0.0002375906496055562,
+        0.0005896606577757043, -0.0014557754120478055, 0.0035921217511549622, -0.008863235103240878,
+        0.021869488519008305, -0.05396825396789699, 0.13333333333333042, -0.3333333333333333,
+    ];
+    pub const TANH_FAST: [f64; 11] = [
+        -1.724487449484433e-05, 7.959955735264808e-05, -0.00023077616269519857,
+        0.0005874372860094381, -0.001455309297534642, 0.0035920589774734554, -0.008863229830925709,
+        0.021869488260559115, -0.05396825396139557, 0.13333333333326658, -0.3333333333333332,
+    ];
+    pub const ERF: [f64; 11] = [
+        1.1472499701094691e-08, -1.5940066854661717e-07, 1.643068417974155e-06,
+        -1.4924196305569617e-05, 0.00012055289567541706, -0.0008548326188759752,
+        0.005223977615420539, -0.026866170644428485, 0.11283791670952596, -0.37612638903183715,
+        1.1283791670955126,
+    ];
+    pub const ERF_FAST: [f64; 10] = [
+        -1.2711547089821422e-07, 1.6044523839624666e-06, -1.489862284147446e-05,
+        0.00012054266028326731, -0.0008548300832069432, 0.005223977232952475, -0.02686617061128781,
+        0.11283791670806737, -0.3761263890318123, 1.1283791670955126,
+    ];
+    pub const ERFC: [f64; 19] = [
+        -0.4647732415172647, -0.24199753881718206, 0.5883992711372202, -0.010059530612262878,
+        -0.4971883232271137, 0.26088575757593946, 0.29259430612733306, -0.35700112374825843,
+        -0.09598885980113923, 0.3449241590358207, -0.037439120491551854, -0.3002640081181077,
+        0.11257174690432832, 0.2823980338863713, -0.15796302985802596, -0.37516488184962626,
+        0.18937322736760884, 1.3452864479553137, -0.6717940840566923,
+    ];
+    pub const ERFC_FAST: [f64; 17] = [
+        0.5134550286328949, -0.06303835735237284, -0.49362440189939005, 0.2656655666862156,
+        0.2926324755971576, -0.35723021861992144, -0.09599803267893717, 0.3449304230902995,
+        -0.03743878663121692, -0.3002641055002848, 0.11257174150359921, 0.2823980346917224,
+        -0.15796302981831256, -0.3751648818526294, 0.18937322736750184, 1.345286447955317,
+        -0.6717940840566922,
+    ];
+}
+
+macro_rules! impl_math {
+    ($modname:ident, $vec:ident, $uvec:ident, $el:ty, $consts:ident, $to_bits:ident, $from_bits:ident) => {
+        mod $modname {
+            use super::*;
+            use super::$consts::*;
+
+            #[inline(always)]
+            #[cfg(target_feature = "fma")]
+            fn mla(a: $vec, b: $vec, c: $vec) -> $vec {
+                a.mul_add(b, c)
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = "fma"))]
+            fn mla(a: $vec, b: $vec, c: $vec) -> $vec {
+                a * b + c
+            }
+
+            #[inline(always)]
+            fn poly(x: $vec, coeffs: &[$el]) -> $vec {
+                coeffs[1..].iter().fold($vec::splat(coeffs[0]), |acc, &c| mla(acc, x, $vec::splat(c)))
+            }
+
+            #[inline(always)]
+            fn from_bits(bits: $uvec) -> $vec {
+                // Every bit pattern is a valid float
+                unsafe { bits.$from_bits() }
+            }
+
+            #[inline(always)]
+            fn abs(x: $vec) -> $vec {
+                from_bits(x.$to_bits() & $uvec::splat(ABS_MASK))
+            }
+
+            #[inline(always)]
+            fn neg(x: $vec) -> $vec {
+                from_bits(x.$to_bits() ^ $uvec::splat(SIGN_MASK))
+            }
+
+            #[inline(always)]
+            fn copysign(x: $vec, sign: $vec) -> $vec {
+                from_bits(x.$to_bits() & $uvec::splat(ABS_MASK) | sign.$to_bits() & $uvec::splat(SIGN_MASK))
+            }
+
+            #[inline(always)]
+            fn is_sign_negative(x: $vec) -> <$uvec as PackedEq>::Out {
+                (x.$to_bits() & $uvec::splat(SIGN_MASK)).eq_mask($uvec::splat(SIGN_MASK))
+            }
+
+            /// Return 2^n for each integral element of `n` in the normal
+            /// exponent range.
+            #[inline(always)]
+            fn pow2i(n: $vec) -> $vec {
+                from_bits(((n + $vec::splat(MAGIC + BIAS)).$to_bits() - $uvec::splat(MAGIC_BITS)).shift_left(MANT_BITS))
+            }
+
+            /// Return e^(x + lo), where `lo` is a small correction to `x`.
+            /// NaNs are treated as the lower bound of the input range.
+            #[inline(always)]
+            fn exp_core(x: $vec, lo: $vec, fast: bool) -> $vec {
+                let (min, max) = if fast { (EXP_MIN_FAST, EXP_MAX_FAST) } else { (EXP_MIN, EXP_MAX) };
+                let x = x.ge_mask($vec::splat(min)).select(x, $vec::splat(min));
+                let x = x.le_mask($vec::splat(max)).select(x, $vec::splat(max));
+
+                // x = n * ln(2) + r, where n * LN2_HI and the subtraction
+                // are exact.
+                let n = (x * $vec::splat(LOG2E)).round();
+                let r = (x - n * $vec::splat(LN2_HI)) - n * $vec::splat(LN2_LO) + lo;
+                let p = mla(r * r, poly(r, if fast { &EXP_FAST[..] } else { &EXP[..] }), r) + $vec::splat(1.0);
+
+                if fast {
+                    p * pow2i(n)
+                } else {
+                    // Scaling in two steps reaches the subnormals and
+                    // infinity without leaving the exponent range.
+                    let n1 = (n * $vec::splat(0.5)).floor();
+                    p * pow2i(n1) * pow2i(n - n1)
+                }
+            }
+
+            /// Split each element of `x` into an integral exponent and a
+            /// mantissa in [sqrt(1/2), sqrt(2)).
+            #[inline(always)]
+            fn frexp(x: $vec, fast: bool) -> ($vec, $vec) {
+                let tiny = x.lt_mask($vec::splat(MIN_POSITIVE));
+                let x = if fast { x } else { tiny.select(x * $vec::splat(SUBNORMAL_SCALE), x) };
+                let bits = x.$to_bits();
+                let biased = (bits & $uvec::splat(EXP_MASK)).shift_right_logical(MANT_BITS);
+                let exp = from_bits(biased + $uvec::splat(MAGIC_BITS)) - $vec::splat(MAGIC + BIAS);
+                let mant = from_bits(bits & $uvec::splat(MANT_MASK) | $uvec::splat(ONE_BITS));
+
+                let big = mant.gt_mask($vec::splat(SQRT_2));
+                let mant = big.select(mant * $vec::splat(0.5), mant);
+                let exp = big.select(exp + $vec::splat(1.0), exp);
+                let exp = if fast { exp } else { tiny.select(exp - $vec::splat(SUBNORMAL_EXP), exp) };
+                (exp, mant)
+            }
+
+            /// Return `(f, c)` such that ln(m) = f - c.
+            #[inline(always)]
+            fn ln_mant(m: $vec, fast: bool) -> ($vec, $vec) {
+                let f = m - $vec::splat(1.0);
+                let s = f / (f + $vec::splat(2.0));
+                let z = s * s;
+                let r = z * poly(z, if fast { &LN_FAST[..] } else { &LN[..] });
+                let hfsq = $vec::splat(0.5) * f * f;
+                (f, hfsq - s * (hfsq + r))
+            }
+
+            #[inline(always)]
+            fn ln_core(x: $vec, fast: bool) -> $vec {
+                let (e, m) = frexp(x, fast);
+                let (f, c) = ln_mant(m, fast);
+                e * $vec::splat(LN2_HI) - ((c - e * $vec::splat(LN2_LO)) - f)
+            }
+
+            #[inline(always)]
+            fn log2_core(x: $vec, fast: bool) -> $vec {
+                let (e, m) = frexp(x, fast);
+                let (f, c) = ln_mant(m, fast);
+                mla(f - c, $vec::splat(LOG2E), e)
+            }
+
+            #[inline(always)]
+            fn ln_specials(x: $vec, ret: $vec) -> $vec {
+                let ret = x.eq_mask($vec::splat(0.0)).select($vec::splat(NEG_INFINITY), ret);
+                let ret = x.eq_mask($vec::splat(INFINITY)).select(x, ret);
+                (x.lt_mask($vec::splat(0.0)) | x.unordered_mask(x)).select($vec::splat(NAN), ret)
+            }
+
+            /// Return ln(x) for positive x as an unevaluated sum `hi + lo`,
+            /// to roughly twice the working precision. The MulAdd calls
+            /// compute the exact rounding errors of products.
+            #[inline(always)]
+            fn ln_extended(x: $vec) -> ($vec, $vec) {
+                let (e, m) = frexp(x, false);
+
+                // s = (m - 1) / (m + 1), carried as s + s_lo
+                let f = m - $vec::splat(1.0);
+                let d = $vec::splat(1.0) + m;
+                let d_lo = m - (d - $vec::splat(1.0));
+                let s = f / d;
+                let s_lo = (s.neg_mul_add(d, f) - s * d_lo) / d;
+
+                // ln(m) = 2s + 2s^3 / 3 + s^5 * LN_EXTRA(s^2)
+                let z = s * s;
+                let z_lo = s.mul_sub(s, z);
+                let s3 = s * z;
+                let s3_lo = s.mul_sub(z, s3) + s * z_lo + $vec::splat(3.0) * z * s_lo;
+                let t = $vec::splat(TWO_THIRDS_HI) * s3;
+                let t_lo = $vec::splat(TWO_THIRDS_HI).mul_sub(s3, t)
+                    + $vec::splat(TWO_THIRDS_HI) * s3_lo + $vec::splat(TWO_THIRDS_LO) * s3;
+                let tail = s3 * z * poly(z, &LN_EXTRA[..]);
+
+                let a = s + s;
+                let h = a + t;
+                let h_lo = (t - (h - a)) + (s_lo + s_lo) + t_lo + tail;
+
+                // Add e * ln(2), where e * LN2_HI is exact
+                let k = e * $vec::splat(LN2_HI);
+                let hi = k + h;
+                let kb = hi - h;
+                let err = (k - kb) + (h - (hi - kb));
+                (hi, err + h_lo + e * $vec::splat(LN2_LO))
+            }
+
+            /// Return the sine and cosine of each element of `x`.
+            #[inline(always)]
+            fn sin_cos(x: $vec, fast: bool) -> ($vec, $vec) {
+                // x = q * pi/2 + r, where each q * FRAC_PI_2_PARTS[i] is
+                // exact while q is small.
+                let q = (x * $vec::splat(FRAC_2_PI)).round();
+                let r = FRAC_PI_2_PARTS.iter().fold(x, |r, &p| r - q * $vec::splat(p));
+
+                let z = r * r;
+                let s = mla(r * z, poly(z, if fast { &SIN_FAST[..] } else { &SIN[..] }), r);
+                let c = mla(z * z, poly(z, if fast { &COS_FAST[..] } else { &COS[..] }),
+                            $vec::splat(1.0) - $vec::splat(0.5) * z);
+
+                // The low bits of the quadrant choose the polynomial and the
+                // sign of each result.
+                let quadrant = (q + $vec::splat(ROUND_MAGIC)).$to_bits();
+                let odd = (quadrant & $uvec::splat(1)).eq_mask($uvec::splat(1));
+                let sin_sign = (quadrant & $uvec::splat(2)).shift_left(QUADRANT_TO_SIGN_SHIFT);
+                let cos_sign = ((quadrant + $uvec::splat(1)) & $uvec::splat(2)).shift_left(QUADRANT_TO_SIGN_SHIFT);
+                (from_bits(odd.select(c, s).$to_bits() ^ sin_sign),
+                 from_bits(odd.select(s, c).$to_bits() ^ cos_sign))
+            }
+
+            #[inline(always)]
+            fn atan2_core(y: $vec, x: $vec, fast: bool) -> $vec {
+                let (ay, ax) = (abs(y), abs(x));
+                let swap = ay.gt_mask(ax);
+                let t = swap.select(ax, ay) / swap.select(ay, ax);
+                let t = if fast {
+                    t
+                } else {
+                    // Replace the NaNs from 0/0 and inf/inf
+                    let t = ay.eq_mask(ax).select($vec::splat(1.0), t);
+                    ax.eq_mask($vec::splat(0.0)).select($vec::splat(0.0), t)
+                };
+
+                // atan(t) = pi/4 + atan((t - 1) / (t + 1))
+                let big = t.gt_mask($vec::splat(TAN_FRAC_PI_8));
+                let t = big.select((t - $vec::splat(1.0)) / (t + $vec::splat(1.0)), t);
+                let z = t * t;
+                let a = mla(t * z, poly(z, if fast { &ATAN_FAST[..] } else { &ATAN[..] }), t);
+                let a = big.select(a + $vec::splat(FRAC_PI_4), a);
+
+                let a = swap.select($vec::splat(FRAC_PI_2) - a, a);
+                let a = is_sign_negative(x).select($vec::splat(PI) - a, a);
+                copysign(a, y)
+            }
+
+            #[inline(always)]
+            fn tanh_core(x: $vec, fast: bool) -> $vec {
+                let ax = abs(x);
+                let z = x * x;
+                let small = mla(x * z, poly(z, if fast { &TANH_FAST[..] } else { &TANH[..] }), x);
+                let e = exp_core(ax + ax, $vec::splat(0.0), fast);
+                let large = copysign($vec::splat(1.0) - $vec::splat(2.0) / (e + $vec::splat(1.0)), x);
+                ax.lt_mask($vec::splat(0.625)).select(small, large)
+            }
+
+            #[inline(always)]
+            fn erf_core(x: $vec, fast: bool) -> $vec {
+                let ax = abs(x);
+                let small = x * poly(x * x, if fast { &ERF_FAST[..] } else { &ERF[..] });
+
+                // erf(x) = 1 - t * e^(-x^2 + ERFC(t - 0.5))
+                let ax = ax.le_mask($vec::splat(ERF_MAX)).select(ax, $vec::splat(ERF_MAX));
+                let t = $vec::splat(2.0) / (ax + $vec::splat(2.0));
+                let p = poly(t - $vec::splat(0.5), if fast { &ERFC_FAST[..] } else { &ERFC[..] });
+                let e = exp_core(mla(neg(ax), ax, p), $vec::splat(0.0), fast);
+                let large = copysign($vec::splat(1.0) - t * e, x);
+                ax.lt_mask($vec::splat(0.75)).select(small, large)
+            }
+
+            impl Math for $vec {
+                #[inline(always)]
+                fn exp(&self) -> Self {
+                    self.unordered_mask(*self).select(*self, exp_core(*self, $vec::splat(0.0), false))
+                }
+
+                #[inline(always)]
+                fn exp_fast(&self) -> Self {
+                    exp_core(*self, $vec::splat(0.0), true)
+                }
+
+                #[inline(always)]
+                fn ln(&self) -> Self {
+                    ln_specials(*self, ln_core(*self, false))
+                }
+
+                #[inline(always)]
+                fn ln_fast(&self) -> Self {
+                    ln_core(*self, true)
+                }
+
+                #[inline(always)]
+                fn log2(&self) -> Self {
+                    ln_specials(*self, log2_core(*self, false))
+                }
+
+                #[inline(always)]
+                fn log2_fast(&self) -> Self {
+                    log2_core(*self, true)
+                }
+
+                #[inline(always)]
+                fn sin(&self) -> Self {
+                    // Keeps the sign of zero
+                    self.eq_mask($vec::splat(0.0)).select(*self, sin_cos(*self, false).0)
+                }
+
+                #[inline(always)]
+                fn sin_fast(&self) -> Self {
+                    sin_cos(*self, true).0
+                }
+
+                #[inline(always)]
+                fn cos(&self) -> Self {
+                    sin_cos(*self, false).1
+                }
+
+                #[inline(always)]
+                fn cos_fast(&self) -> Self {
+                    sin_cos(*self, true).1
+                }
+
+                #[inline(always)]
+                fn tan(&self) -> Self {
+                    let (s, c) = sin_cos(*self, false);
+                    self.eq_mask($vec::splat(0.0)).select(*self, s / c)
+                }
+
+                #[inline(always)]
+                fn tan_fast(&self) -> Self {
+                    let (s, c) = sin_cos(*self, true);
+                    s / c
+                }
+
+                #[inline(always)]
+                fn atan2(&self, x: Self) -> Self {
+                    self.unordered_mask(x).select(*self + x, atan2_core(*self, x, false))
+                }
+
+                #[inline(always)]
+                fn atan2_fast(&self, x: Self) -> Self {
+                    atan2_core(*self, x, true)
+                }
+
+                #[inline(always)]
+                fn pow(&self, y: Self) -> Self {
+                    let x = *self;
+                    let ax = abs(x);
+                    let (hi, lo) = ln_extended(ax);
+                    let hi = ax.eq_mask($vec::splat(0.0)).select($vec::splat(NEG_INFINITY), hi);
+                    let hi = ax.eq_mask($vec::splat(INFINITY)).select(ax, hi);
+                    let hi = ax.unordered_mask(ax).select(ax, hi);
+
+                    // y * (hi + lo), where the low half is discarded if the
+                    // high half is infinite or NaN
+                    let zh = y * hi;
+                    let zl = y.mul_sub(hi, zh) + y * lo;
+                    let zl = zl.unordered_mask(zl).select($vec::splat(0.0), zl);
+                    let ret = zh.unordered_mask(zh).select(zh, exp_core(zh, zl, false));
+
+                    let y_int = y.floor().eq_mask(y);
+                    let half = y * $vec::splat(0.5);
+                    let y_odd = y_int & !half.floor().eq_mask(half);
+                    let ret = (is_sign_negative(x) & y_odd).select(neg(ret), ret);
+                    let finite_neg = x.lt_mask($vec::splat(0.0)) & ax.lt_mask($vec::splat(INFINITY));
+                    let ret = (finite_neg & !y_int).select($vec::splat(NAN), ret);
+
+                    let one = $vec::splat(1.0);
+                    let ones = x.eq_mask(one) | y.eq_mask($vec::splat(0.0))
+                        | (x.eq_mask($vec::splat(-1.0)) & abs(y).eq_mask($vec::splat(INFINITY)));
+                    ones.select(one, ret)
+                }
+
+                #[inline(always)]
+                fn pow_fast(&self, y: Self) -> Self {
+                    exp_core(y * ln_core(*self, true), $vec::splat(0.0), true)
+                }
+
+                #[inline(always)]
+                fn tanh(&self) -> Self {
+                    self.unordered_mask(*self).select(*self, tanh_core(*self, false))
+                }
+
+                #[inline(always)]
+                fn tanh_fast(&self) -> Self {
+                    tanh_core(*self, true)
+                }
+
+                #[inline(always)]
+                fn erf(&self) -> Self {
+                    self.unordered_mask(*self).select(*self, erf_core(*self, false))
+                }
+
+                #[inline(always)]
+                fn erf_fast(&self) -> Self {
+                    erf_core(*self, true)
+                }
+            }
+        }
+    }
+}
+
+impl_math!(f32x4_math, f32x4, u32x4, f32, f32_consts, be_u32s, be_f32s_unchecked);
+impl_math!(f64x2_math, f64x2, u64x2, f64, f64_consts, be_u64s, be_f64s_unchecked);
+impl_math!(f32x8_math, f32x8, u32x8, f32, f32_consts, be_u32s, be_f32s_unchecked);
+impl_math!(f64x4_math, f64x4, u64x4, f64, f64_consts, be_u64s, be_f64s_unchecked);
+impl_math!(f32x16_math, f32x16, u32x16, f32, f32_consts, be_u32s, be_f32s_unchecked);
+impl_math!(f64x8_math, f64x8, u64x8, f64, f64_consts, be_u64s, be_f64s_unchecked);
+
+// The polyfill vectors are the current vectors everywhere but x86.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod polyfill {
+    use crate::arch::unknown::vecs::*;
+    use crate::vecs::*;
+    use super::{Math, PackedEq, MulAdd, PackedOrd, Round, Select, Shift, Transmute, f32_consts, f64_consts};
+
+    impl_math!(f32x4_math, f32x4, u32x4, f32, f32_consts, be_u32s, be_f32s_unchecked);
+    impl_math!(f64x2_math, f64x2, u64x2, f64, f64_consts, be_u64s, be_f64s_unchecked);
+    impl_math!(f32x8_math, f32x8, u32x8, f32, f32_consts, be_u32s, be_f32s_unchecked);
+    impl_math!(f64x4_math, f64x4, u64x4, f64, f64_consts, be_u64s, be_f64s_unchecked);
+    impl_math!(f32x16_math, f32x16, u32x16, f32, f32_consts, be_u32s, be_f32s_unchecked);
+    impl_math!(f64x8_math, f64x8, u64x8, f64, f64_consts, be_u64s, be_f64s_unchecked);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Each test checks this many vectors of random inputs against std
+    const SAMPLES: usize = 1 << 14;
+
+    struct Rng(u64);
+
+    impl Rng {
+        fn new() -> Self {
+            Rng(0x2545_F491_4F6C_DD1D)
+        }
+
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn uniform(&mut self, lo: f64, hi: f64) -> f64 {
+            lo + (hi - lo) * ((self.next() >> 11) as f64 / (1u64 << 53) as f64)
+        }
+
+        // Spreads samples evenly over the exponents between lo and hi
+        fn log_uniform(&mut self, lo: f64, hi: f64) -> f64 {
+            self.uniform(lo.ln(), hi.ln()).exp()
+        }
+
+        fn signed(&mut self, x: f64) -> f64 {
+            if self.next() & 1 == 0 { x } else { -x }
+        }
+
+        fn signed_uniform(&mut self, lo: f64, hi: f64) -> f64 {
+            let x = self.uniform(lo, hi);
+            self.signed(x)
+        }
+    }
+
+    macro_rules! impl_check {
+        ($name:ident, $vec:ident, $el:ident, $bits:ident) => {
+            // The distance between two floats in units in the last place
+            fn $name(a: $el, b: $el) -> u64 {
+                if a == b || (a.is_nan() && b.is_nan()) {
+                    return 0;
+                }
+                let ord = |x: $el| {
+                    let bits = x.to_bits() as $bits;
+                    (if bits < 0 { $bits::min_value() - bits } else { bits }) as i128
+                };
+                (ord(a) - ord(b)).abs() as u64
+            }
+
+            impl Rng {
+                fn $vec<G>(&mut self, gen: G) -> ($vec, $vec) where G : Fn(&mut Rng) -> (f64, f64) {
+                    let (mut x, mut y) = ($vec::splat(0.0), $vec::splat(0.0));
+                    for i in 0..$vec::WIDTH {
+                        let (a, b) = gen(self);
+                        x = x.replace(i, a as $el);
+                        y = y.replace(i, b as $el);
+                    }
+                    (x, y)
+                }
+            }
+
+            /// Assert that `f` is within `max` ULP of `reference`, which
+            /// is evaluated in f64, for random inputs from `gen`.
+            fn check<G, F, R>(max: u64, gen: G, f: F, reference: R)
+                where G : Fn(&mut Rng) -> (f64, f64), F : Fn($vec, $vec) -> $vec, R : Fn(f64, f64) -> f64 {
+                let mut rng = Rng::new();
+                for _ in 0..SAMPLES {
+                    let (x, y) = rng.$vec(&gen);
+                    let ret = f(x, y);
+                    for i in 0..$vec::WIDTH {
+                        let (a, b) = (x.extract(i), y.extract(i));
+                        let expected = reference(a as f64, b as f64) as $el;
+                        let err = $name(ret.extract(i), expected);
+                        assert!(err <= max, "f({:e}, {:e}) = {:e}, expected {:e} ({} ULP)",
+                                a, b, ret.extract(i), expected, err);
+                    }
+                }
+            }
+        }
+    }
+
+    // The vector types may have inherent methods with the same names, so
+    // these tests call through the trait.
+    mod f32_tests {
+        use super::*;
+
+        impl_check!(ulps, f32s, f32, i32);
+
+        // f64 results rounded to f32 can still be a rounding away from the
+        // correct result, so each bound allows one extra ULP.
+        #[test]
+        fn exp() {
+            check(2, |r| (r.uniform(-104.0, 89.0), 0.0), |x, _| Math::exp(&x), |x, _| x.exp());
+            check(6, |r| (r.uniform(-87.0, 88.0), 0.0), |x, _| Math::exp_fast(&x), |x, _| x.exp());
+        }
+
+        #[test]
+        fn ln() {
+            check(2, |r| (r.log_uniform(1e-45, 1e38), 0.0), |x, _| Math::ln(&x), |x, _| x.ln());
+            check(2, |r| (r.log_uniform(1e-37, 1e38), 0.0), |x, _| Math::ln_fast(&x), |x, _| x.ln());
+            check(3, |r| (r.log_uniform(1e-45, 1e38), 0.0), |x, _| Math::log2(&x), |x, _| x.log2());
+            check(3, |r| (r.log_uniform(1e-37, 1e38), 0.0), |x, _| Math::log2_fast(&x), |x, _| x.log2());
+        }
+
+        #[test]
+        fn trig() {
+            let gen = |r: &mut Rng| (r.uniform(-4096.0, 4096.0), 0.0);
+            check(3, gen, |x, _| Math::sin(&x), |x, _| x.sin());
+            check(3, gen, |x, _| Math::sin_fast(&x), |x, _| x.sin());
+            check(3, gen, |x, _| Math::cos(&x), |x, _| x.cos());
+            check(3, gen, |x, _| Math::cos_fast(&x), |x, _| x.cos());
+            check(5, gen, |x, _| Math::tan(&x), |x, _| x.tan());
+            check(5, gen, |x, _| Math::tan_fast(&x), |x, _| x.tan());
+        }
+
+        #[test]
+        fn atan2() {
+            let gen = |r: &mut Rng| {
+                let (y, x) = (r.log_uniform(1e-10, 1e10), r.log_uniform(1e-10, 1e10));
+                (r.signed(y), r.signed(x))
+            };
+            check(4, gen, |y, x| Math::atan2(&y, x), |y, x| y.atan2(x));
+            check(4, gen, |y, x| Math::atan2_fast(&y, x), |y, x| y.atan2(x));
+        }
+
+        #[test]
+        fn pow() {
+            let gen = |r: &mut Rng| {
+                let x = r.log_uniform(1e-30, 1e30);
+                let max = 87.0 / x.ln().abs();
+                (x, r.uniform(-max, max))
+            };
+            check(3, gen, |x, y| Math::pow(&x, y), |x, y| x.powf(y));
+            check(3, |r| (r.signed_uniform(0.1, 10.0), r.uniform(-30.0, 30.0).round()),
+                  |x, y| Math::pow(&x, y), |x, y| x.powf(y));
+            check(256, gen, |x, y| Math::pow_fast(&x, y), |x, y| x.powf(y));
+        }
+
+        #[test]
+        fn tanh() {
+            let gen = |r: &mut Rng| (r.uniform(-12.0, 12.0), 0.0);
+            check(3, gen, |x, _| Math::tanh(&x), |x, _| x.tanh());
+            check(3, gen, |x, _| Math::tanh_fast(&x), |x, _| x.tanh());
+        }
+
+        #[test]
+        fn erf() {
+            // std has no erf, so compare against the f64 implementation
+            let gen = |r: &mut Rng| (r.uniform(-6.0, 6.0), 0.0);
+            let reference = |x: f64, _: f64| Math::erf(&f64s(x)).extract(0);
+            check(4, gen, |x, _| Math::erf(&x), reference);
+            check(4, gen, |x, _| Math::erf_fast(&x), reference);
+        }
+    }
+
+    mod f64_tests {
+        use super::*;
+
+        impl_check!(ulps, f64s, f64, i64);
+
+        // std's own results may be an ULP away from the correct result, so
+        // each bound allows one extra ULP.
+        #[test]
+        fn exp() {
+            check(2, |r| (r.uniform(-746.0, 710.0), 0.0), |x, _| Math::exp(&x), |x, _| x.exp());
+            check(3, |r| (r.uniform(-708.0, 709.0), 0.0), |x, _| Math::exp_fast(&x), |x, _| x.exp());
+        }
+
+        #[test]
+        fn ln() {
+            check(2, |r| (r.log_uniform(1e-320, 1e300), 0.0), |x, _| Math::ln(&x), |x, _| x.ln());
+            check(6, |r| (r.log_uniform(1e-300, 1e300), 0.0), |x, _| Math::ln_fast(&x), |x, _| x.ln());
+            check(3, |r| (r.log_uniform(1e-320, 1e300), 0.0), |x, _| Math::log2(&x), |x, _| x.log2());
+            check(8, |r| (r.log_uniform(1e-300, 1e300), 0.0), |x, _| Math::log2_fast(&x), |x, _| x.log2());
+        }
+
+        #[test]
+        fn trig() {
+            let gen = |r: &mut Rng| (r.uniform(-67108864.0, 67108864.0), 0.0);
+            check(4, gen, |x, _| Math::sin(&x), |x, _| x.sin());
+            check(81, gen, |x, _| Math::sin_fast(&x), |x, _| x.sin());
+            check(4, gen, |x, _| Math::cos(&x), |x, _| x.cos());
+            check(81, gen, |x, _| Math::cos_fast(&x), |x, _| x.cos());
+            check(5, gen, |x, _| Math::tan(&x), |x, _| x.tan());
+            check(81, gen, |x, _| Math::tan_fast(&x), |x, _| x.tan());
+        }
+
+        #[test]
+        fn atan2() {
+            let gen = |r: &mut Rng| {
+                let (y, x) = (r.log_uniform(1e-10, 1e10), r.log_uniform(1e-10, 1e10));
+                (r.signed(y), r.signed(x))
+            };
+            check(4, gen, |y, x| Math::atan2(&y, x), |y, x| y.atan2(x));
+            check(5, gen, |y, x| Math::atan2_fast(&y, x), |y, x| y.atan2(x));
+        }
+
+        #[test]
+        fn pow() {
+            let gen = |r: &mut Rng| {
+                let x = r.log_uniform(1e-300, 1e300);
+                let max = 700.0 / x.ln().abs();
+                (x, r.uniform(-max, max))
+            };
+            check(3, gen, |x, y| Math::pow(&x, y), |x, y| x.powf(y));
+            check(3, |r| (r.signed_uniform(0.1, 10.0), r.uniform(-300.0, 300.0).round()),
+                  |x, y| Math::pow(&x, y), |x, y| x.powf(y));
+            check(8192, gen, |x, y| Math::pow_fast(&x, y), |x, y| x.powf(y));
+        }
+
+        #[test]
+        fn tanh() {
+            let gen = |r: &mut Rng| (r.uniform(-24.0, 24.0), 0.0);
+            check(2, gen, |x, _| Math::tanh(&x), |x, _| x.tanh());
+            check(3, gen, |x, _| Math::tanh_fast(&x), |x, _| x.tanh());
+        }
+
+        #[test]
+        fn erf() {
+            let expected = [(1e-10, 1.1283791670955126e-10), (0.001, 0.0011283787909692365),
+                            (0.1, 0.1124629160182849), (0.5, 0.5204998778130465),
+                            (0.7, 0.6778011938374184), (0.75, 0.7111556336535151),
+                            (0.8, 0.7421009647076605), (1.0, 0.8427007929497149),
+                            (1.5, 0.9661051464753108), (2.0, 0.9953222650189527),
+                            (2.5, 0.999593047982555), (3.0, 0.9999779095030014),
+                            (4.0, 0.9999999845827421), (5.0, 0.9999999999984626),
+                            (5.9, 0.9999999999999999)];
+            for &(x, erf) in expected.iter() {
+                assert!(ulps(Math::erf(&f64s(x)).extract(0), erf) <= 2);
+                assert!(ulps(Math::erf(&f64s(-x)).extract(0), -erf) <= 2);
+                assert!(ulps(Math::erf_fast(&f64s(x)).extract(0), erf) <= 2);
+            }
+        }
+    }
+
+    macro_rules! test_math_specials {
+        ($vec:ident, $el:ident, $name:ident) => {
+            #[test]
+            fn $name() {
+                use crate::std::$el::{INFINITY, NEG_INFINITY, NAN};
+                let v = |x: $el| $vec::splat(x);
+                let is_nan = |x: $vec| x.extract(0).is_nan();
+
+                assert_eq!(Math::exp(&v(INFINITY)), v(INFINITY));
+                assert_eq!(Math::exp(&v(NEG_INFINITY)), v(0.0));
+                assert!(is_nan(Math::exp(&v(NAN))));
+                assert_eq!(Math::exp(&v(1e5)), v(INFINITY));
+
+                assert_eq!(Math::ln(&v(0.0)), v(NEG_INFINITY));
+                assert_eq!(Math::ln(&v(-0.0)), v(NEG_INFINITY));
+                assert_eq!(Math::ln(&v(INFINITY)), v(INFINITY));
+                assert!(is_nan(Math::ln(&v(-1.0))));
+                assert!(is_nan(Math::log2(&v(NAN))));
+
+                assert_eq!(Math::sin(&v(-0.0)).extract(0).to_bits(), (-0.0 as $el).to_bits());
+                assert!(is_nan(Math::sin(&v(INFINITY))));
+                assert!(is_nan(Math::cos(&v(NAN))));
+
+                assert_eq!(Math::atan2(&v(0.0), v(-0.0)), v(crate::std::$el::consts::PI));
+                assert_eq!(Math::atan2(&v(-0.0), v(0.0)).extract(0).to_bits(), (-0.0 as $el).to_bits());
+                assert_eq!(Math::atan2(&v(INFINITY), v(INFINITY)), v(crate::std::$el::consts::FRAC_PI_4));
+                assert!(is_nan(Math::atan2(&v(NAN), v(1.0))));
+
+                assert_eq!(Math::pow(&v(NAN), v(0.0)), v(1.0));
+                assert_eq!(Math::pow(&v(1.0), v(NAN)), v(1.0));
+                assert_eq!(Math::pow(&v(-1.0), v(INFINITY)), v(1.0));
+                assert_eq!(Math::pow(&v(-0.0), v(-3.0)), v(NEG_INFINITY));
+                assert_eq!(Math::pow(&v(NEG_INFINITY), v(3.0)), v(NEG_INFINITY));
+                assert_eq!(Math::pow(&v(0.0), v(-0.5)), v(INFINITY));
+                assert_eq!(Math::pow(&v(2.0), v(-2000.0)), v(0.0));
+                assert!(is_nan(Math::pow(&v(-8.0), v(1.0 / 3.0))));
+
+                assert_eq!(Math::tanh(&v(NEG_INFINITY)), v(-1.0));
+                assert_eq!(Math::erf(&v(INFINITY)), v(1.0));
+                assert!(is_nan(Math::tanh(&v(NAN))));
+                assert!(is_nan(Math::erf(&v(NAN))));
+            }
+        }
+    }
+
+    test_math_specials!(f32s, f32, specials_f32s);
+    test_math_specials!(f64s, f64, specials_f64s);
+}
@@ -0,0 +1,220 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Detection of width-dependent kernels.
+//!
+//! A kernel which is impure, or which reduces without taking the vector width
+//! into account, may return different results on machines with different
+//! vector widths. [`check`] runs a kernel with 128-, 256-, and 512-bit vectors
+//! on the current machine and reports the first output which differs, so such
+//! bugs may be caught without access to every kind of machine.
+//!
+//! Every run uses the polyfill vectors from `arch::unknown`, which are plain
+//! arrays on every target, so the results don't depend on which instructions
+//! the host supports. Their arithmetic is checked for overflow in debug
+//! builds, like that of scalars.
+//!
+//! [`check`]: fn.check.html
+
+use crate::std::fmt::{self, Debug, Display, Formatter};
+use crate::arch::unknown::vecs::*;
+
+pub use crate::widths::Widths;
+
+/// 128-bit vectors, as used by SSE and NEON.
+#[derive(Clone, Copy, Debug)]
+pub struct W128;
+
+/// 256-bit vectors, as used by AVX2.
+#[derive(Clone, Copy, Debug)]
+pub struct W256;
+
+/// 512-bit vectors, as used by AVX-512.
+#[derive(Clone, Copy, Debug)]
+pub struct W512;
+
+impl_widths!(W128, 128, u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2);
+impl_widths!(W256, 256, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4);
+impl_widths!(W512, 512, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8);
+
+/// A computation which may be run with vectors of any width.
+///
+/// Closures can't be generic over types, so kernels are written as
+/// implementations of this trait instead. `run` should build its vectors
+/// from the types in `W` (typically via `simd_iter_as`) rather than from
+/// `f32s` and friends, and return everything it would otherwise return or
+/// store.
+pub trait Kernel {
+    type Output : PartialEq + Debug;
+
+    /// Run the kernel with vectors from the family `W`.
+    fn run<W : Widths>(&mut self) -> Vec<Self::Output>;
+}
+
+/// The first difference between the outputs of two runs of a kernel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Divergence<T> {
+    /// The width of the run whose output is considered correct, in bits.
+    pub expected_bits: usize,
+    /// The width of the run which disagreed, in bits.
+    pub actual_bits: usize,
+    /// The position of the first differing output.
+    pub index: usize,
+    /// The output of the first run at `index`, if it produced that many.
+    pub expected: Option<T>,
+    /// The output of the disagreeing run at `index`, if it produced that many.
+    pub actual: Option<T>,
+}
+
+impl<T> Display for Divergence<T> where T : Debug {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "output {} differs between {}-bit and {}-bit vectors: expected {:?}, got {:?}",
+               self.index, self.expected_bits, self.actual_bits, self.expected, self.actual)
+    }
+}
+
+fn compare<T>(expected: &[T], expected_bits: usize, actual: Vec<T>, actual_bits: usize) -> Result<(), Divergence<T>>
+    where T : PartialEq + Debug + Clone {
+    let len = expected.len().max(actual.len());
+    for index in 0..len {
+        if expected.get(index) != actual.get(index) {
+            return Err(Divergence {
+                expected_bits: expected_bits,
+                actual_bits: actual_bits,
+                index: index,
+                expected: expected.get(index).cloned(),
+                actual: actual.get(index).cloned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Run `kernel` with 128-, 256-, and 512-bit vectors, and compare the outputs
+/// of the wider runs against the 128-bit run. Returns the outputs if every
+/// run agreed, or the first difference otherwise.
+///
+/// # Examples
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+/// use faster::portability::{check, Kernel, Widths};
+///
+/// struct SumPlusOne<'a>(&'a [f32]);
+///
+/// impl<'a> Kernel for SumPlusOne<'a> {
+///     type Output = f32;
+///
+///     fn run<W : Widths>(&mut self) -> Vec<f32> {
+///         // Adding a constant inside a reduction depends on how many
+///         // vectors the data was split into.
+///         let acc = self.0.simd_iter_as(W::f32s::splat(0.0))
+///             .simd_reduce(W::f32s::splat(0.0), |acc, v| acc + v + W::f32s::splat(1.0));
+///         vec![acc.scalar_reduce(0.0, |a, x| a + x)]
+///     }
+/// }
+///
+/// # fn main() {
+/// let divergence = check(&mut SumPlusOne(&[1.0; 100])).unwrap_err();
+/// assert_eq!(divergence.actual_bits, 256);
+/// # }
+/// ```
+pub fn check<K>(kernel: &mut K) -> Result<Vec<K::Output>, Divergence<K::Output>>
+    where K : Kernel, K::Output : Clone {
+    let expected = kernel.run::<W128>();
+    compare(&expected, W128::BITS, kernel.run::<W256>(), W256::BITS)?;
+    compare(&expected, W128::BITS, kernel.run::<W512>(), W512::BITS)?;
+    Ok(expected)
+}
+
+/// Run `kernel` with 128-, 256-, and 512-bit vectors, and panic with the
+/// first difference between their outputs, if any.
+pub fn assert_portable<K>(kernel: &mut K) where K : Kernel, K::Output : Clone {
+    if let Err(divergence) = check(kernel) {
+        panic!("kernel is not portable: {}", divergence);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::*;
+
+    struct Square<'a>(&'a [i32]);
+
+    impl<'a> Kernel for Square<'a> {
+        type Output = i32;
+
+        fn run<W : Widths>(&mut self) -> Vec<i32> {
+            self.0.simd_iter_as(W::i32s::splat(0))
+                .simd_map(|v| v * v)
+                .scalar_collect()
+        }
+    }
+
+    struct SoftPlus<'a>(&'a [f32]);
+
+    impl<'a> Kernel for SoftPlus<'a> {
+        type Output = f32;
+
+        fn run<W : Widths>(&mut self) -> Vec<f32> {
+            // Uses comparisons, masks and float functions through W alone
+            self.0.simd_iter_as(W::f32s::splat(0.0))
+                .simd_map(|v| {
+                    let zero = W::f32s::splat(0.0);
+                    let pos = v.gt_mask(zero).select(v, zero);
+                    (pos + (zero - v.abs()).exp().mul_add(W::f32s::splat(1.0), W::f32s::splat(1.0)).ln())
+                        .min(W::f32s::splat(10.0)).sqrt()
+                })
+                .scalar_collect()
+        }
+    }
+
+    struct Impure<'a>(&'a [u8]);
+
+    impl<'a> Kernel for Impure<'a> {
+        type Output = u8;
+
+        fn run<W : Widths>(&mut self) -> Vec<u8> {
+            let mut flip = true;
+            self.0.simd_iter_as(W::u8s::splat(0))
+                .simd_map(|v| { flip = !flip; if flip { v + W::u8s::splat(1) } else { v } })
+                .scalar_collect()
+        }
+    }
+
+    #[test]
+    fn pure_kernel_is_portable() {
+        let data: Vec<i32> = (0..100).collect();
+        let squares: Vec<i32> = data.iter().map(|x| x * x).collect();
+        assert_eq!(check(&mut Square(&data)), Ok(squares));
+    }
+
+    #[test]
+    fn intrinsics_are_portable() {
+        let data: Vec<f32> = (0..100).map(|x| x as f32 * 0.25 - 12.0).collect();
+        assert_eq!(check(&mut SoftPlus(&data)).unwrap().len(), data.len());
+    }
+
+    #[test]
+    fn impure_kernel_diverges() {
+        let data = [1u8; 100];
+        let divergence = check(&mut Impure(&data)).unwrap_err();
+        assert_eq!(divergence.expected_bits, 128);
+        assert_eq!(divergence.actual_bits, 256);
+        assert_eq!(divergence.index, 16);
+        assert_eq!(divergence.expected, Some(2));
+        assert_eq!(divergence.actual, Some(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_portable_panics() {
+        assert_portable(&mut Impure(&[1u8; 100]));
+    }
+}
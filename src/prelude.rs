@@ -8,6 +8,9 @@
 pub use crate::iters::*;
 pub use crate::into_iters::*;
 pub use crate::vecs::{Packed, Pattern};
+pub use crate::mask::{Mask, PackedMask, m8s, m16s, m32s, m64s};
+pub use crate::math::Math;
+pub use crate::half::{f16, bf16};
 pub use crate::arch::current::vecs::{u8s, i8s, u16s, i16s, u32s, i32s, f32s, u64s, i64s, f64s};
 pub use crate::arch::current::intrin::prelude::*;
 pub use crate::intrin::prelude::*;
@@ -0,0 +1,88 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Families of vector types which share a width.
+//!
+//! Code which is generic over a [`Widths`] family, rather than written against
+//! `f32s` and friends, may be compiled for several widths. The [`dispatch`]
+//! module uses this to run wider vectors on processors which support them,
+//! and the [`portability`] module to compare the results of every width.
+//!
+//! Besides arithmetic, every vector in a family implements the comparisons,
+//! reductions and float functions which exist at every width, and comparing
+//! two vectors gives a mask which can select between them.
+//!
+//! [`Widths`]: trait.Widths.html
+//! [`dispatch`]: ../dispatch/index.html
+//! [`portability`]: ../portability/index.html
+
+#![allow(non_camel_case_types)]
+
+use crate::std::ops::{Add, Sub, Mul, Div, BitAnd, BitOr, BitXor};
+use crate::vecs::{Packed, Pattern};
+use crate::intrin::abs::Abs;
+use crate::intrin::cmp::Cmp;
+use crate::intrin::eq::Eq;
+use crate::intrin::mul_add::MulAdd;
+use crate::intrin::ord::PackedOrd;
+use crate::intrin::reduce::HMinMax;
+use crate::intrin::round::Round;
+use crate::intrin::select::Select;
+use crate::intrin::sqrt::Sqrt;
+use crate::intrin::sum::Sum;
+use crate::math::Math;
+
+/// A family of vector types which all share a width.
+pub trait Widths {
+    /// The size of every vector in this family, in bits.
+    const BITS: usize;
+
+    type u8s : Packed<Scalar = u8> + Pattern + Add<Output = Self::u8s> + Sub<Output = Self::u8s> + Mul<Output = Self::u8s> + BitAnd<Output = Self::u8s> + BitOr<Output = Self::u8s> + BitXor<Output = Self::u8s> + Eq<Out = Self::m8s> + PackedOrd + Cmp + HMinMax + Sum;
+    type i8s : Packed<Scalar = i8> + Pattern + Add<Output = Self::i8s> + Sub<Output = Self::i8s> + Mul<Output = Self::i8s> + BitAnd<Output = Self::i8s> + BitOr<Output = Self::i8s> + BitXor<Output = Self::i8s> + Eq<Out = Self::m8s> + PackedOrd + Cmp + HMinMax + Sum;
+    type u16s : Packed<Scalar = u16> + Pattern + Add<Output = Self::u16s> + Sub<Output = Self::u16s> + Mul<Output = Self::u16s> + BitAnd<Output = Self::u16s> + BitOr<Output = Self::u16s> + BitXor<Output = Self::u16s> + Eq<Out = Self::m16s> + PackedOrd + Cmp + HMinMax + Sum;
+    type i16s : Packed<Scalar = i16> + Pattern + Add<Output = Self::i16s> + Sub<Output = Self::i16s> + Mul<Output = Self::i16s> + BitAnd<Output = Self::i16s> + BitOr<Output = Self::i16s> + BitXor<Output = Self::i16s> + Eq<Out = Self::m16s> + PackedOrd + Cmp + HMinMax + Sum;
+    type u32s : Packed<Scalar = u32> + Pattern + Add<Output = Self::u32s> + Sub<Output = Self::u32s> + Mul<Output = Self::u32s> + BitAnd<Output = Self::u32s> + BitOr<Output = Self::u32s> + BitXor<Output = Self::u32s> + Eq<Out = Self::m32s> + PackedOrd + Cmp + HMinMax + Sum;
+    type i32s : Packed<Scalar = i32> + Pattern + Add<Output = Self::i32s> + Sub<Output = Self::i32s> + Mul<Output = Self::i32s> + BitAnd<Output = Self::i32s> + BitOr<Output = Self::i32s> + BitXor<Output = Self::i32s> + Eq<Out = Self::m32s> + PackedOrd + Cmp + HMinMax + Sum;
+    type u64s : Packed<Scalar = u64> + Pattern + Add<Output = Self::u64s> + Sub<Output = Self::u64s> + Mul<Output = Self::u64s> + BitAnd<Output = Self::u64s> + BitOr<Output = Self::u64s> + BitXor<Output = Self::u64s> + Eq<Out = Self::m64s> + PackedOrd + Cmp + HMinMax + Sum;
+    type i64s : Packed<Scalar = i64> + Pattern + Add<Output = Self::i64s> + Sub<Output = Self::i64s> + Mul<Output = Self::i64s> + BitAnd<Output = Self::i64s> + BitOr<Output = Self::i64s> + BitXor<Output = Self::i64s> + Eq<Out = Self::m64s> + PackedOrd + Cmp + HMinMax + Sum;
+    type f32s : Packed<Scalar = f32> + Pattern + Add<Output = Self::f32s> + Sub<Output = Self::f32s> + Mul<Output = Self::f32s> + Div<Output = Self::f32s> + Eq<Out = Self::m32s> + PackedOrd + Cmp + HMinMax + Sum + Abs<Out = Self::f32s> + Sqrt + Round + MulAdd + Math;
+    type f64s : Packed<Scalar = f64> + Pattern + Add<Output = Self::f64s> + Sub<Output = Self::f64s> + Mul<Output = Self::f64s> + Div<Output = Self::f64s> + Eq<Out = Self::m64s> + PackedOrd + Cmp + HMinMax + Sum + Abs<Out = Self::f64s> + Sqrt + Round + MulAdd + Math;
+
+    /// The masks returned by comparisons of 8-bit elements, which select
+    /// between vectors of them.
+    type m8s : Select<Self::u8s> + Select<Self::i8s>;
+    /// The masks returned by comparisons of 16-bit elements.
+    type m16s : Select<Self::u16s> + Select<Self::i16s>;
+    /// The masks returned by comparisons of 32-bit elements.
+    type m32s : Select<Self::u32s> + Select<Self::i32s> + Select<Self::f32s>;
+    /// The masks returned by comparisons of 64-bit elements.
+    type m64s : Select<Self::u64s> + Select<Self::i64s> + Select<Self::f64s>;
+}
+
+macro_rules! impl_widths {
+    ($width:ty, $bits:expr, $u8s:ty, $i8s:ty, $u16s:ty, $i16s:ty, $u32s:ty, $i32s:ty, $f32s:ty, $u64s:ty, $i64s:ty, $f64s:ty) => {
+        impl $crate::widths::Widths for $width {
+            const BITS: usize = $bits;
+
+            type u8s = $u8s;
+            type i8s = $i8s;
+            type u16s = $u16s;
+            type i16s = $i16s;
+            type u32s = $u32s;
+            type i32s = $i32s;
+            type f32s = $f32s;
+            type u64s = $u64s;
+            type i64s = $i64s;
+            type f64s = $f64s;
+
+            type m8s = $crate::mask::Mask<$u8s>;
+            type m16s = $crate::mask::Mask<$u16s>;
+            type m32s = $crate::mask::Mask<$u32s>;
+            type m64s = $crate::mask::Mask<$u64s>;
+        }
+    }
+}
@@ -36,4 +36,159 @@ mod tests {
         let sum = vec.simd_iter(u32s(0u32)).simd_reduce(u32s(0u32), |acc, x| acc + x).sum();
         assert_eq!(sum, 2 * 129);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn explicit_width() {
+        use faster::arch::current::vecs::{f32x4, f32x8, u32x4};
+
+        let data: Vec<f32> = (0..37).map(|x| x as f32).collect();
+        let doubled: Vec<f32> = data.iter().map(|x| x * 2.0).collect();
+
+        let narrow = data.simd_iter_as(f32x4::splat(0.0))
+            .simd_map(|v| v * f32x4::splat(2.0))
+            .scalar_collect();
+        assert_eq!(narrow, doubled);
+
+        let wide = data.simd_iter_as(f32x8::splat(0.0))
+            .simd_map(|v| v + v)
+            .scalar_collect();
+        assert_eq!(wide, doubled);
+
+        let ones = [1u32; 37];
+        let mut zipped = [0u32; 37];
+        (ones.simd_iter_as(u32x4::splat(0)), ones.simd_iter_as(u32x4::splat(0))).zip()
+            .simd_map(|(a, b)| a + b)
+            .scalar_fill(&mut zipped);
+        assert_eq!(&zipped[..], &[2u32; 37][..]);
+
+        let mut halved = data.clone();
+        halved.simd_iter_mut_as(f32x8::splat(0.0))
+            .simd_for_each(|v| *v = *v * f32x8::splat(0.5));
+        assert_eq!(halved, data.iter().map(|x| x * 0.5).collect::<Vec<f32>>());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn simd_filter() {
+        for n in 0..(u32s::WIDTH * 4) {
+            let data: Vec<u32> = (0..n as u32).collect();
+            let expected: Vec<u32> = data.iter().cloned().filter(|x| x % 4 == 0).collect();
+
+            // The default vector passes the filter, so this also checks that
+            // padding is dropped.
+            let collected = data.simd_iter(u32s(0))
+                .simd_filter(|v| (v & u32s(3)).eq_mask(u32s(0)))
+                .scalar_collect();
+            assert_eq!(collected, expected);
+
+            let mut filled = vec![0u32; expected.len()];
+            let count = data.simd_iter(u32s(0))
+                .simd_filter(|v| (v & u32s(3)).eq_mask(u32s(0)))
+                .scalar_fill(&mut filled);
+            assert_eq!(count, expected.len());
+            assert_eq!(filled, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn simd_scan() {
+        for n in 0..(u32s::WIDTH * 4) {
+            let data: Vec<u32> = (0..n as u32).map(|x| x % 7).collect();
+            let mut inclusive = Vec::new();
+            let mut exclusive = Vec::new();
+            let mut acc = 0;
+            for x in data.iter() {
+                exclusive.push(acc);
+                acc += x;
+                inclusive.push(acc);
+            }
+
+            assert_eq!(data.simd_iter(u32s(9)).simd_scan().scalar_collect(), inclusive);
+            assert_eq!(data.simd_iter(u32s(9)).simd_scan_exclusive().scalar_collect(), exclusive);
+
+            let floats: Vec<f32> = data.iter().map(|&x| x as f32).collect();
+            let totals = floats.simd_iter(f32s(9.0)).simd_scan().scalar_collect();
+            assert_eq!(totals, inclusive.iter().map(|&x| x as f32).collect::<Vec<f32>>());
+        }
+    }
+
+    #[test]
+    fn simd_argmin_argmax() {
+        fn scalar_arg<F : Fn(i16, i16) -> bool>(data: &[i16], better: F) -> Option<usize> {
+            let mut best: Option<usize> = None;
+            for (i, &x) in data.iter().enumerate() {
+                if best.map_or(true, |b| better(x, data[b])) {
+                    best = Some(i);
+                }
+            }
+            best
+        }
+
+        let data: Vec<i16> = (0..(i16s::WIDTH as i16 * 5)).map(|x| (x * 37) % 23 - 11).collect();
+        for n in 0..data.len() {
+            // Padding lanes would win if they were considered
+            assert_eq!(data[..n].simd_iter(i16s(-100)).simd_argmin(), scalar_arg(&data[..n], |a, b| a < b));
+            assert_eq!(data[..n].simd_iter(i16s(100)).simd_argmax(), scalar_arg(&data[..n], |a, b| a > b));
+        }
+
+        let nans = [::std::f32::NAN, 2.0, ::std::f32::NAN, 1.0, 1.0];
+        assert_eq!(nans.simd_iter(f32s(0.0)).simd_argmin(), Some(3));
+        assert_eq!(nans.simd_iter(f32s(0.0)).simd_argmax(), Some(1));
+        assert_eq!([::std::f32::NAN; 3].simd_iter(f32s(0.0)).simd_argmin(), Some(0));
+    }
+
+    #[test]
+    fn simd_argmin_argmax_ties() {
+        // Ties between lanes of different vectors, including the last and
+        // partially filled one, go to the lowest index
+        for &len in [i32s::WIDTH * 3, i32s::WIDTH * 3 + 1, i32s::WIDTH * 4 - 1].iter() {
+            for a in 0..len {
+                for b in (a + 1)..len {
+                    let mut data = vec![5i32; len];
+                    data[a] = 1;
+                    data[b] = 1;
+                    assert_eq!(data.simd_iter(i32s(0)).simd_argmin(), Some(a));
+                    data[a] = 9;
+                    data[b] = 9;
+                    assert_eq!(data.simd_iter(i32s(10)).simd_argmax(), Some(a));
+                }
+            }
+        }
+
+        // The best element is only in the partial last vector
+        let mut data = vec![5.0f32; f32s::WIDTH * 2 + 1];
+        data[f32s::WIDTH * 2] = 1.0;
+        assert_eq!(data.simd_iter(f32s(0.0)).simd_argmin(), Some(f32s::WIDTH * 2));
+        assert_eq!(data.simd_iter(f32s(9.0)).simd_argmax(), Some(0));
+
+        // Enough vectors to overflow the 8-bit vector numbers of each lane
+        let len = u8s::WIDTH * 300 + 3;
+        let mut data = vec![7u8; len];
+        data[u8s::WIDTH * 270 + 1] = 1;
+        data[u8s::WIDTH * 260 + 5] = 1;
+        data[len - 1] = 1;
+        assert_eq!(data.simd_iter(u8s(0)).simd_argmin(), Some(u8s::WIDTH * 260 + 5));
+        data[u8s::WIDTH * 3 + 2] = 1;
+        assert_eq!(data.simd_iter(u8s(0)).simd_argmin(), Some(u8s::WIDTH * 3 + 2));
+    }
+
+    #[test]
+    fn simd_predicates() {
+        let data: Vec<u16> = (0..(u16s::WIDTH as u16 * 4)).map(|x| (x * 7) % 11).collect();
+        for n in 0..data.len() {
+            let data = &data[..n];
+            for &x in [0u16, 3, 10, 11].iter() {
+                // Padding equals x, so it would change every answer if tested
+                let eq = |v: u16s| v.eq_mask(u16s(x));
+                let ne = |v: u16s| v.ne_mask(u16s(x));
+                assert_eq!(data.simd_iter(u16s(x)).simd_any(eq), data.iter().any(|&y| y == x));
+                assert_eq!(data.simd_iter(u16s(x)).simd_all(ne), data.iter().all(|&y| y != x));
+                assert_eq!(data.simd_iter(u16s(x)).simd_position(eq), data.iter().position(|&y| y == x));
+                assert_eq!(data.simd_iter(u16s(x)).simd_rposition(eq), data.iter().rposition(|&y| y == x));
+                assert_eq!(data.simd_iter(u16s(x)).simd_count(eq), data.iter().filter(|&&y| y == x).count());
+            }
+        }
+    }
 }